@@ -0,0 +1,58 @@
+//! Compares symbol lookup through the hash tables with a linear scan of .dynsym.
+//!
+//! Run with `cargo bench --bench lookup [path/to/library.so]`. The library defaults to the
+//! C library, which has thousands of dynamic symbols.
+
+use elf_parser::{
+    types::{SHN_UNDEF, SHT_GNU_HASH, SHT_HASH},
+    Elf, ElfFile, Symbol,
+};
+use std::{env, fs, hint::black_box, time::Instant};
+
+const DEFAULT_LIBRARY: &str = "/lib/x86_64-linux-gnu/libc.so.6";
+
+fn measure(label: &str, names: &[&str], mut lookup: impl FnMut(&str) -> bool) {
+    let start = Instant::now();
+    let found = names.iter().filter(|name| lookup(black_box(name))).count();
+    let elapsed = start.elapsed();
+    assert_eq!(found, names.len(), "{} missed symbols", label);
+    println!(
+        "{:<12} {:>10.1?} total, {:>8.1?} per lookup",
+        label,
+        elapsed,
+        elapsed / names.len() as u32
+    );
+}
+
+fn main() {
+    let path = env::args()
+        .skip(1)
+        .find(|arg| !arg.starts_with('-'))
+        .unwrap_or_else(|| DEFAULT_LIBRARY.into());
+    let bytes = fs::read(&path).unwrap_or_else(|err| panic!("{}: {}", path, err));
+    let elf = Elf::from_bytes(&bytes).unwrap();
+    let symtab = elf.dynsym().unwrap();
+
+    let names: Vec<&str> = symtab
+        .iter()
+        .map(Result::unwrap)
+        .filter(|(sym, name)| sym.st_shndx() != SHN_UNDEF.0 && !name.is_empty())
+        .map(|(_, name)| name)
+        .collect();
+    println!("{}: {} defined dynamic symbols", path, names.len());
+
+    measure("linear", &names, |name| {
+        symtab
+            .iter()
+            .filter_map(Result::ok)
+            .any(|(sym, sym_name)| sym.st_shndx() != SHN_UNDEF.0 && sym_name == name)
+    });
+    for (label, sh_type) in [("gnu hash", SHT_GNU_HASH), ("sysv hash", SHT_HASH)] {
+        let Some(shdr) = elf.section_by_type(sh_type) else {
+            println!("{:<12} no section", label);
+            continue;
+        };
+        let table = elf.hash_table(&shdr).unwrap();
+        measure(label, &names, |name| table.lookup(&symtab, name).is_some());
+    }
+}
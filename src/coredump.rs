@@ -0,0 +1,639 @@
+use crate::{
+    file::{FileHeader, PhdrIter, ProgramHeader},
+    types::{
+        AuxType, Class, CoreNoteType, Data, Machine, PFlag, AT_NULL, ELFCLASS64, EM_AARCH64,
+        EM_X86_64, ET_CORE, NT_AUXV, NT_FILE, NT_PRPSINFO, NT_PRSTATUS, PF_W, PT_LOAD, PT_NOTE,
+    },
+    util, ElfFile, Error, Note, NoteIter,
+};
+use core::{fmt, marker::PhantomData};
+
+/// Owner name of the notes the kernel writes into a core file.
+pub const ELF_NOTE_CORE: &[u8] = b"CORE";
+
+/// A time value of a NT_PRSTATUS note.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct TimeVal {
+    pub sec: i64,
+    pub usec: i64,
+}
+
+/// General-purpose registers of x86_64 (struct user_regs_struct), in the order they are
+/// dumped.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct X86_64Regs {
+    pub r15: u64,
+    pub r14: u64,
+    pub r13: u64,
+    pub r12: u64,
+    pub rbp: u64,
+    pub rbx: u64,
+    pub r11: u64,
+    pub r10: u64,
+    pub r9: u64,
+    pub r8: u64,
+    pub rax: u64,
+    pub rcx: u64,
+    pub rdx: u64,
+    pub rsi: u64,
+    pub rdi: u64,
+    pub orig_rax: u64,
+    pub rip: u64,
+    pub cs: u64,
+    pub eflags: u64,
+    pub rsp: u64,
+    pub ss: u64,
+    pub fs_base: u64,
+    pub gs_base: u64,
+    pub ds: u64,
+    pub es: u64,
+    pub fs: u64,
+    pub gs: u64,
+}
+
+impl X86_64Regs {
+    const SIZE: usize = 27 * 8;
+
+    fn parse(bytes: &[u8], data: Data) -> Self {
+        let word = |index: usize| util::bytes_to_u64(&bytes[index * 8..], data);
+        X86_64Regs {
+            r15: word(0),
+            r14: word(1),
+            r13: word(2),
+            r12: word(3),
+            rbp: word(4),
+            rbx: word(5),
+            r11: word(6),
+            r10: word(7),
+            r9: word(8),
+            r8: word(9),
+            rax: word(10),
+            rcx: word(11),
+            rdx: word(12),
+            rsi: word(13),
+            rdi: word(14),
+            orig_rax: word(15),
+            rip: word(16),
+            cs: word(17),
+            eflags: word(18),
+            rsp: word(19),
+            ss: word(20),
+            fs_base: word(21),
+            gs_base: word(22),
+            ds: word(23),
+            es: word(24),
+            fs: word(25),
+            gs: word(26),
+        }
+    }
+}
+
+/// General-purpose registers of AArch64 (struct user_pt_regs).
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct Aarch64Regs {
+    /// x0 to x30.
+    pub regs: [u64; 31],
+    pub sp: u64,
+    pub pc: u64,
+    pub pstate: u64,
+}
+
+impl Aarch64Regs {
+    const SIZE: usize = 34 * 8;
+
+    fn parse(bytes: &[u8], data: Data) -> Self {
+        let word = |index: usize| util::bytes_to_u64(&bytes[index * 8..], data);
+        let mut regs = [0; 31];
+        for (index, reg) in regs.iter_mut().enumerate() {
+            *reg = word(index);
+        }
+        Aarch64Regs {
+            regs,
+            sp: word(31),
+            pc: word(32),
+            pstate: word(33),
+        }
+    }
+}
+
+/// The register set of a thread, for the machine of the core file.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Registers {
+    X86_64(X86_64Regs),
+    Aarch64(Aarch64Regs),
+}
+
+impl Registers {
+    /// Get the program counter.
+    pub fn pc(&self) -> u64 {
+        match self {
+            Registers::X86_64(regs) => regs.rip,
+            Registers::Aarch64(regs) => regs.pc,
+        }
+    }
+
+    /// Get the stack pointer.
+    pub fn sp(&self) -> u64 {
+        match self {
+            Registers::X86_64(regs) => regs.rsp,
+            Registers::Aarch64(regs) => regs.sp,
+        }
+    }
+}
+
+/// The status of a thread (struct elf_prstatus) from a NT_PRSTATUS note.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PrStatus {
+    /// si_signo, si_code and si_errno of the signal that stopped the thread.
+    pub signo: i32,
+    pub code: i32,
+    pub errno: i32,
+    pub cursig: u16,
+    pub sigpend: u64,
+    pub sighold: u64,
+    pub pid: i32,
+    pub ppid: i32,
+    pub pgrp: i32,
+    pub sid: i32,
+    pub utime: TimeVal,
+    pub stime: TimeVal,
+    pub cutime: TimeVal,
+    pub cstime: TimeVal,
+    pub regs: Registers,
+    pub fpvalid: bool,
+}
+
+impl PrStatus {
+    /// Offset of pr_reg in the 64-bit layout.
+    const REGS_OFFSET: usize = 112;
+
+    /// Decode the descriptor of a NT_PRSTATUS note of a 64-bit `machine` core file.
+    pub fn parse(desc: &[u8], machine: Machine, data: Data) -> Result<Self, Error> {
+        let regs_size = match machine {
+            EM_X86_64 => X86_64Regs::SIZE,
+            EM_AARCH64 => Aarch64Regs::SIZE,
+            _ => return Err(Error::UnsupportedMachine),
+        };
+        let fpvalid_offset = Self::REGS_OFFSET + regs_size;
+        let desc = desc.get(..fpvalid_offset + 4).ok_or(Error::Corrupted)?;
+        let i32_at = |offset: usize| util::bytes_to_u32(&desc[offset..], data) as i32;
+        let u64_at = |offset: usize| util::bytes_to_u64(&desc[offset..], data);
+        let time_at = |offset: usize| TimeVal {
+            sec: u64_at(offset) as i64,
+            usec: u64_at(offset + 8) as i64,
+        };
+        let regs = &desc[Self::REGS_OFFSET..fpvalid_offset];
+        let regs = match machine {
+            EM_X86_64 => Registers::X86_64(X86_64Regs::parse(regs, data)),
+            _ => Registers::Aarch64(Aarch64Regs::parse(regs, data)),
+        };
+        Ok(PrStatus {
+            signo: i32_at(0),
+            code: i32_at(4),
+            errno: i32_at(8),
+            cursig: util::bytes_to_u16(&desc[12..], data),
+            sigpend: u64_at(16),
+            sighold: u64_at(24),
+            pid: i32_at(32),
+            ppid: i32_at(36),
+            pgrp: i32_at(40),
+            sid: i32_at(44),
+            utime: time_at(48),
+            stime: time_at(64),
+            cutime: time_at(80),
+            cstime: time_at(96),
+            regs,
+            fpvalid: i32_at(fpvalid_offset) != 0,
+        })
+    }
+}
+
+/// Get the bytes before the first NUL of a fixed-size string field.
+fn c_str(bytes: &[u8]) -> &[u8] {
+    let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    &bytes[..len]
+}
+
+/// Information about the process (struct elf_prpsinfo) from a NT_PRPSINFO note.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct PrPsInfo<'a> {
+    pub state: u8,
+    /// The state as a character of `ps`, such as b'R'.
+    pub sname: u8,
+    pub zomb: u8,
+    pub nice: i8,
+    pub flag: u64,
+    pub uid: u32,
+    pub gid: u32,
+    pub pid: i32,
+    pub ppid: i32,
+    pub pgrp: i32,
+    pub sid: i32,
+    /// The file name of the executable, truncated to 15 bytes.
+    pub fname: &'a [u8],
+    /// The start of the command line, truncated to 79 bytes.
+    pub psargs: &'a [u8],
+}
+
+impl<'a> PrPsInfo<'a> {
+    /// Decode the descriptor of a NT_PRPSINFO note of a 64-bit core file.
+    pub fn parse(desc: &'a [u8], data: Data) -> Result<Self, Error> {
+        let desc = desc.get(..136).ok_or(Error::Corrupted)?;
+        let u32_at = |offset: usize| util::bytes_to_u32(&desc[offset..], data);
+        Ok(PrPsInfo {
+            state: desc[0],
+            sname: desc[1],
+            zomb: desc[2],
+            nice: desc[3] as i8,
+            flag: util::bytes_to_u64(&desc[8..], data),
+            uid: u32_at(16),
+            gid: u32_at(20),
+            pid: u32_at(24) as i32,
+            ppid: u32_at(28) as i32,
+            pgrp: u32_at(32) as i32,
+            sid: u32_at(36) as i32,
+            fname: c_str(&desc[40..56]),
+            psargs: c_str(&desc[56..136]),
+        })
+    }
+}
+
+impl fmt::Debug for PrPsInfo<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ELFPrPsInfo:
+    state: {}
+    sname: {}
+    pid: {}
+    ppid: {}
+    uid: {}
+    gid: {}
+    fname: {}
+    psargs: {}",
+            self.state,
+            self.sname.escape_ascii(),
+            self.pid,
+            self.ppid,
+            self.uid,
+            self.gid,
+            self.fname.escape_ascii(),
+            self.psargs.escape_ascii()
+        ))
+    }
+}
+
+/// An entry of the auxiliary vector.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AuxEntry {
+    pub a_type: AuxType,
+    pub a_val: u64,
+}
+
+/// An iterator over an auxiliary vector, such as the descriptor of a NT_AUXV note. It stops
+/// at AT_NULL.
+#[derive(Clone)]
+pub struct AuxvIter<'a> {
+    bytes: &'a [u8],
+    word: usize,
+    data: Data,
+}
+
+impl<'a> AuxvIter<'a> {
+    /// Get an AuxvIter over the entries in `bytes`, which are pairs of words of `class`.
+    pub fn new(bytes: &'a [u8], class: Class, data: Data) -> Self {
+        AuxvIter {
+            bytes,
+            word: if class == ELFCLASS64 { 8 } else { 4 },
+            data,
+        }
+    }
+}
+
+impl Iterator for AuxvIter<'_> {
+    type Item = AuxEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.bytes.get(..self.word * 2)?;
+        let a_type = AuxType(util::read_uint(&entry[..self.word], self.data));
+        if a_type == AT_NULL {
+            self.bytes = &[];
+            return None;
+        }
+        self.bytes = &self.bytes[self.word * 2..];
+        Some(AuxEntry {
+            a_type,
+            a_val: util::read_uint(&entry[self.word..], self.data),
+        })
+    }
+}
+
+/// A file mapped into the process, from a NT_FILE note.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct FileMapping<'a> {
+    pub start: u64,
+    pub end: u64,
+    /// The offset in the file that `start` maps, in bytes.
+    pub offset: u64,
+    pub name: &'a [u8],
+}
+
+impl fmt::Debug for FileMapping<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ELFFileMapping:
+    start: {:#x}
+    end: {:#x}
+    offset: {:#x}
+    name: {}",
+            self.start,
+            self.end,
+            self.offset,
+            self.name.escape_ascii()
+        ))
+    }
+}
+
+/// The table of mapped files of a NT_FILE note.
+#[derive(Clone, Copy)]
+pub struct MappedFiles<'a> {
+    count: usize,
+    page_size: u64,
+    entries: &'a [u8],
+    names: &'a [u8],
+    word: usize,
+    data: Data,
+}
+
+impl<'a> MappedFiles<'a> {
+    /// Decode the descriptor of a NT_FILE note: the number of entries and the page size, the
+    /// start, end and page offset of each entry, and then the NUL-terminated file names.
+    pub fn parse(desc: &'a [u8], class: Class, data: Data) -> Result<Self, Error> {
+        let word = if class == ELFCLASS64 { 8 } else { 4 };
+        let header = desc.get(..word * 2).ok_or(Error::Corrupted)?;
+        let count = util::read_uint(&header[..word], data);
+        let page_size = util::read_uint(&header[word..], data);
+        let count = usize::try_from(count).map_err(|_| Error::Corrupted)?;
+        let entries_end = count
+            .checked_mul(word * 3)
+            .and_then(|size| size.checked_add(word * 2))
+            .ok_or(Error::Corrupted)?;
+        let entries = desc.get(word * 2..entries_end).ok_or(Error::Corrupted)?;
+        Ok(MappedFiles {
+            count,
+            page_size,
+            entries,
+            names: &desc[entries_end..],
+            word,
+            data,
+        })
+    }
+
+    /// Get the number of mappings.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Check if there are no mappings.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Get the page size the offsets are counted in.
+    pub fn page_size(&self) -> u64 {
+        self.page_size
+    }
+
+    /// Get the mapping that contains `addr`.
+    pub fn find(&self, addr: u64) -> Option<FileMapping<'a>> {
+        self.iter()
+            .find(|mapping| mapping.start <= addr && addr < mapping.end)
+    }
+
+    /// Get an iterator over the mappings.
+    pub fn iter(&self) -> FileMappingIter<'a> {
+        FileMappingIter {
+            index: 0,
+            names: self.names,
+            files: *self,
+        }
+    }
+}
+
+pub struct FileMappingIter<'a> {
+    index: usize,
+    names: &'a [u8],
+    files: MappedFiles<'a>,
+}
+
+impl<'a> Iterator for FileMappingIter<'a> {
+    type Item = FileMapping<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.files.count {
+            return None;
+        }
+        let word = self.files.word;
+        let entry = &self.files.entries[self.index * word * 3..];
+        let len = self.names.iter().position(|&b| b == 0)?;
+        let name = &self.names[..len];
+        self.names = &self.names[len + 1..];
+        self.index += 1;
+        let page_offset = util::read_uint(&entry[word * 2..word * 3], self.files.data);
+        Some(FileMapping {
+            start: util::read_uint(&entry[..word], self.files.data),
+            end: util::read_uint(&entry[word..word * 2], self.files.data),
+            offset: page_offset.wrapping_mul(self.files.page_size),
+            name,
+        })
+    }
+}
+
+/// A core file of x86_64 or AArch64 Linux, and typed access to the notes the kernel writes
+/// into it.
+pub struct Core<'b, 'a, F: ElfFile<'a>> {
+    file: &'b F,
+    machine: Machine,
+    _marker: PhantomData<&'a [u8]>,
+}
+
+impl<'b, 'a, F: ElfFile<'a>> Core<'b, 'a, F> {
+    /// Get a Core over `file`. Returns Error::InvalidFileType unless it is ET_CORE, and
+    /// Error::UnsupportedMachine unless it is a 64-bit x86_64 or AArch64 core.
+    pub fn new(file: &'b F) -> Result<Self, Error> {
+        let ehdr = file.ehdr();
+        if ehdr.e_type() != ET_CORE.0 {
+            return Err(Error::InvalidFileType);
+        }
+        let machine = Machine(ehdr.e_machine());
+        if !matches!(machine, EM_X86_64 | EM_AARCH64) || file.class() != ELFCLASS64 {
+            return Err(Error::UnsupportedMachine);
+        }
+        Ok(Core {
+            file,
+            machine,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Get the file.
+    pub fn file(&self) -> &'b F {
+        self.file
+    }
+
+    /// Get the machine the core was dumped on.
+    pub fn machine(&self) -> Machine {
+        self.machine
+    }
+
+    /// Get an iterator over the notes of every PT_NOTE segment.
+    pub fn notes(&self) -> CoreNoteIter<'b, 'a, F> {
+        CoreNoteIter {
+            phdrs: self.file.phdr_iter(),
+            notes: NoteIter::new(&[], 4, self.file.data()),
+            file: self.file,
+        }
+    }
+
+    /// Get the descriptor of the first "CORE" note of `n_type`.
+    fn core_note(&self, n_type: CoreNoteType) -> Result<&'a [u8], Error> {
+        self.notes()
+            .find(|note| note.name == ELF_NOTE_CORE && note.n_type == n_type.0)
+            .map(|note| note.desc)
+            .ok_or(Error::NotFound)
+    }
+
+    /// Get an iterator over the status of each thread (NT_PRSTATUS). The thread that caused
+    /// the dump comes first.
+    pub fn threads(&self) -> ThreadIter<'b, 'a, F> {
+        ThreadIter {
+            notes: self.notes(),
+            machine: self.machine,
+            data: self.file.data(),
+        }
+    }
+
+    /// Get the process information (NT_PRPSINFO).
+    pub fn process_info(&self) -> Result<PrPsInfo<'a>, Error> {
+        PrPsInfo::parse(self.core_note(NT_PRPSINFO)?, self.file.data())
+    }
+
+    /// Get the auxiliary vector of the process (NT_AUXV).
+    pub fn auxv(&self) -> Result<AuxvIter<'a>, Error> {
+        Ok(AuxvIter::new(
+            self.core_note(NT_AUXV)?,
+            self.file.class(),
+            self.file.data(),
+        ))
+    }
+
+    /// Get the files mapped into the process (NT_FILE).
+    pub fn mapped_files(&self) -> Result<MappedFiles<'a>, Error> {
+        MappedFiles::parse(
+            self.core_note(NT_FILE)?,
+            self.file.class(),
+            self.file.data(),
+        )
+    }
+
+    /// Read the memory of the process at virtual address `vaddr` into `buf`. The range may
+    /// span several PT_LOAD segments as long as they are contiguous.
+    ///
+    /// Error::NotFileBacked is returned when the range reaches a page that was mapped but not
+    /// dumped (p_filesz < p_memsz), and Error::NotFound when it reaches an unmapped address.
+    pub fn read_memory(&self, vaddr: u64, buf: &mut [u8]) -> Result<(), Error> {
+        self.read_memory_with(vaddr, buf, |_| None)
+    }
+
+    /// Read the memory of the process like read_memory, but read pages that were not dumped
+    /// from the original files when they belong to a read-only mapping listed in NT_FILE.
+    ///
+    /// `open` is called with such a mapping and returns the contents of the file it names, or
+    /// None if the file is not available. Writable mappings are never read from the files,
+    /// since the process may have changed them.
+    pub fn read_memory_with<'c>(
+        &self,
+        vaddr: u64,
+        buf: &mut [u8],
+        mut open: impl FnMut(&FileMapping<'a>) -> Option<&'c [u8]>,
+    ) -> Result<(), Error> {
+        let files = self.mapped_files().ok();
+        let mut addr = vaddr;
+        let mut buf = buf;
+        while !buf.is_empty() {
+            let phdr = self
+                .file
+                .phdr_iter()
+                .filter(|phdr| phdr.p_type() == PT_LOAD.0)
+                .find(|phdr| addr >= phdr.p_vaddr() && addr - phdr.p_vaddr() < phdr.p_memsz())
+                .ok_or(Error::NotFound)?;
+            let delta = addr - phdr.p_vaddr();
+            let mut len = (phdr.p_memsz() - delta).min(buf.len() as u64);
+
+            let bytes = if delta < phdr.p_filesz() {
+                len = len.min(phdr.p_filesz() - delta);
+                let offset = phdr.p_offset().checked_add(delta).ok_or(Error::Corrupted)?;
+                util::slice(self.file.bytes(), offset, len)?
+            } else if PFlag(phdr.p_flags()).contains(PF_W) {
+                return Err(Error::NotFileBacked);
+            } else {
+                let mapping = files
+                    .and_then(|files| files.find(addr))
+                    .ok_or(Error::NotFileBacked)?;
+                len = len.min(mapping.end - addr);
+                let contents = open(&mapping).ok_or(Error::NotFileBacked)?;
+                mapping
+                    .offset
+                    .checked_add(addr - mapping.start)
+                    .and_then(|offset| util::slice(contents, offset, len).ok())
+                    .ok_or(Error::NotFileBacked)?
+            };
+
+            let (head, tail) = buf.split_at_mut(len as usize);
+            head.copy_from_slice(bytes);
+            buf = tail;
+            addr = addr.wrapping_add(len);
+        }
+        Ok(())
+    }
+}
+
+pub struct CoreNoteIter<'b, 'a, F: ElfFile<'a>> {
+    phdrs: PhdrIter<'b, 'a, F>,
+    notes: NoteIter<'a>,
+    file: &'b F,
+}
+
+impl<'a, F: ElfFile<'a>> Iterator for CoreNoteIter<'_, 'a, F> {
+    type Item = Note<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(note) = self.notes.next() {
+                return Some(note);
+            }
+            let phdr = self.phdrs.next()?;
+            if phdr.p_type() == PT_NOTE.0 {
+                if let Ok(notes) = self.file.segment_notes(&phdr) {
+                    self.notes = notes;
+                }
+            }
+        }
+    }
+}
+
+pub struct ThreadIter<'b, 'a, F: ElfFile<'a>> {
+    notes: CoreNoteIter<'b, 'a, F>,
+    machine: Machine,
+    data: Data,
+}
+
+impl<'a, F: ElfFile<'a>> Iterator for ThreadIter<'_, 'a, F> {
+    type Item = Result<PrStatus, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let note = self
+            .notes
+            .find(|note| note.name == ELF_NOTE_CORE && note.n_type == NT_PRSTATUS.0)?;
+        Some(PrStatus::parse(note.desc, self.machine, self.data))
+    }
+}
@@ -0,0 +1,87 @@
+//! Lookup of separate debug files, following the conventions of GDB. Requires the `std`
+//! feature.
+
+use crate::{Elf, ElfFile};
+use std::{
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+    string::String,
+};
+
+/// Finds the separate debug file of an ELF file under a debug root such as /usr/lib/debug.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DebugFileLocator {
+    root: PathBuf,
+}
+
+impl Default for DebugFileLocator {
+    fn default() -> Self {
+        DebugFileLocator::new("/usr/lib/debug")
+    }
+}
+
+impl DebugFileLocator {
+    /// Get a DebugFileLocator searching under `root`.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        DebugFileLocator { root: root.into() }
+    }
+
+    /// Get the debug root.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Get the path of the debug file for `build_id` in the `.build-id/xx/yyyy.debug` layout,
+    /// where xx is the first byte of the build ID in hex and yyyy the rest.
+    pub fn build_id_path(&self, build_id: &[u8]) -> Option<PathBuf> {
+        let (first, rest) = build_id.split_first()?;
+        if rest.is_empty() {
+            return None;
+        }
+        let mut dir = String::new();
+        write!(dir, "{:02x}", first).ok()?;
+        let mut name = String::new();
+        for byte in rest {
+            write!(name, "{:02x}", byte).ok()?;
+        }
+        name.push_str(".debug");
+        Some(self.root.join(".build-id").join(dir).join(name))
+    }
+
+    /// Get the debug file of `file`, which was read from `path`.
+    ///
+    /// The build ID is tried first, and a candidate is accepted only if its own build ID
+    /// matches. Otherwise the .gnu_debuglink name is looked up next to `path`, in its .debug
+    /// subdirectory and under the debug root, and a candidate is accepted only if its CRC32
+    /// matches.
+    pub fn locate<'a, F: ElfFile<'a>>(&self, file: &F, path: &Path) -> Option<PathBuf> {
+        if let Some(candidate) = file.build_id().and_then(|id| self.build_id_path(id)) {
+            if let Ok(bytes) = fs::read(&candidate) {
+                let elf = Elf::from_bytes(&bytes).ok();
+                if elf.and_then(|elf| elf.build_id()) == file.build_id() {
+                    return Some(candidate);
+                }
+            }
+        }
+
+        let link = file.debuglink().ok()?;
+        let dir = path.parent().unwrap_or(Path::new(""));
+        let global = self
+            .root
+            .join(dir.strip_prefix("/").unwrap_or(dir))
+            .join(link.name);
+        [
+            dir.join(link.name),
+            dir.join(".debug").join(link.name),
+            global,
+        ]
+        .into_iter()
+        .filter(|candidate| candidate != path)
+        .find(|candidate| {
+            fs::read(candidate)
+                .map(|bytes| link.matches(&bytes))
+                .unwrap_or(false)
+        })
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{types::Data, util, Error};
+use core::{fmt, str};
+
+/// The contents of a .gnu_debuglink section: the file name of the separate debug file and the
+/// CRC32 of its contents.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct DebugLink<'a> {
+    pub name: &'a str,
+    pub crc: u32,
+}
+
+impl<'a> DebugLink<'a> {
+    /// Decode the contents of a .gnu_debuglink section: a NUL-terminated file name padded to
+    /// 4 bytes, followed by the CRC32 in the byte order of the file.
+    pub fn parse(bytes: &'a [u8], data: Data) -> Result<Self, Error> {
+        let len = bytes.iter().position(|&b| b == 0).ok_or(Error::Corrupted)?;
+        let name = str::from_utf8(&bytes[..len]).map_err(|_| Error::InvalidUtf8)?;
+        let crc_offset = (len + 4) & !3;
+        let crc = bytes
+            .get(crc_offset..crc_offset + 4)
+            .ok_or(Error::Corrupted)?;
+        Ok(DebugLink {
+            name,
+            crc: util::bytes_to_u32(crc, data),
+        })
+    }
+
+    /// Check if `bytes`, the contents of a candidate debug file, match the CRC32.
+    pub fn matches(&self, bytes: &[u8]) -> bool {
+        crc32(bytes) == self.crc
+    }
+}
+
+impl fmt::Debug for DebugLink<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ELFDebugLink:
+    name: {}
+    crc: {:#010x}",
+            self.name, self.crc
+        ))
+    }
+}
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Get the CRC32 (ISO 3309, as used by .gnu_debuglink) of `bytes`.
+pub fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, &byte| {
+        CRC32_TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
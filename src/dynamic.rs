@@ -0,0 +1,272 @@
+use crate::{
+    table::{Entry, Table, TableIter},
+    types::{
+        Class, Data, DynFlags, DynFlags1, DynTag, DT_FLAGS, DT_FLAGS_1, DT_NEEDED, DT_NULL,
+        DT_RPATH, DT_RUNPATH, DT_SONAME, ELFCLASS32, ELFCLASS64,
+    },
+    util, Error, StrTab,
+};
+use core::fmt;
+
+/// Accessors shared by the dynamic entries of both classes. d_val and d_ptr share storage, so
+/// both are read through d_val, widened to u64.
+pub trait DynamicEntry: Entry {
+    fn d_tag(&self) -> DynTag;
+    fn d_val(&self) -> u64;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+/// Dynamic entry of ELF32.
+pub struct Elf32Dyn {
+    pub d_tag: i32,
+    pub d_val: u32,
+}
+
+impl Entry for Elf32Dyn {
+    fn size(_class: Class) -> usize {
+        8
+    }
+
+    fn parse(bytes: &[u8], _class: Class, data: Data) -> Result<Self, Error> {
+        let bytes = bytes.get(..8).ok_or(Error::Corrupted)?;
+        Ok(Elf32Dyn {
+            d_tag: util::bytes_to_u32(&bytes[0..4], data) as i32,
+            d_val: util::bytes_to_u32(&bytes[4..8], data),
+        })
+    }
+}
+
+impl DynamicEntry for Elf32Dyn {
+    fn d_tag(&self) -> DynTag {
+        DynTag(self.d_tag as i64)
+    }
+    fn d_val(&self) -> u64 {
+        self.d_val as u64
+    }
+}
+
+impl fmt::Debug for Elf32Dyn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ELF32Dyn:
+    tag: {:?}
+    val: {:#x}",
+            self.d_tag(),
+            self.d_val
+        ))
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+/// Dynamic entry of ELF64.
+pub struct Elf64Dyn {
+    pub d_tag: i64,
+    pub d_val: u64,
+}
+
+impl Entry for Elf64Dyn {
+    fn size(_class: Class) -> usize {
+        16
+    }
+
+    fn parse(bytes: &[u8], _class: Class, data: Data) -> Result<Self, Error> {
+        let bytes = bytes.get(..16).ok_or(Error::Corrupted)?;
+        Ok(Elf64Dyn {
+            d_tag: util::bytes_to_u64(&bytes[0..8], data) as i64,
+            d_val: util::bytes_to_u64(&bytes[8..16], data),
+        })
+    }
+}
+
+impl DynamicEntry for Elf64Dyn {
+    fn d_tag(&self) -> DynTag {
+        DynTag(self.d_tag)
+    }
+    fn d_val(&self) -> u64 {
+        self.d_val
+    }
+}
+
+impl fmt::Debug for Elf64Dyn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ELF64Dyn:
+    tag: {:?}
+    val: {:#x}",
+            self.d_tag(),
+            self.d_val
+        ))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+/// Dynamic entry of either class, widened to ELF64 field sizes.
+pub struct Dyn {
+    pub d_tag: i64,
+    pub d_val: u64,
+}
+
+impl From<Elf32Dyn> for Dyn {
+    fn from(dyn_: Elf32Dyn) -> Self {
+        Dyn {
+            d_tag: dyn_.d_tag as i64,
+            d_val: dyn_.d_val as u64,
+        }
+    }
+}
+
+impl From<Elf64Dyn> for Dyn {
+    fn from(dyn_: Elf64Dyn) -> Self {
+        Dyn {
+            d_tag: dyn_.d_tag,
+            d_val: dyn_.d_val,
+        }
+    }
+}
+
+impl Entry for Dyn {
+    fn size(class: Class) -> usize {
+        if class == ELFCLASS64 {
+            Elf64Dyn::size(class)
+        } else {
+            Elf32Dyn::size(class)
+        }
+    }
+
+    fn parse(bytes: &[u8], class: Class, data: Data) -> Result<Self, Error> {
+        match class {
+            ELFCLASS32 => Elf32Dyn::parse(bytes, class, data).map(Dyn::from),
+            ELFCLASS64 => Elf64Dyn::parse(bytes, class, data).map(Dyn::from),
+            _ => Err(Error::InvalidClass),
+        }
+    }
+}
+
+impl DynamicEntry for Dyn {
+    fn d_tag(&self) -> DynTag {
+        DynTag(self.d_tag)
+    }
+    fn d_val(&self) -> u64 {
+        self.d_val
+    }
+}
+
+impl fmt::Debug for Dyn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ELFDyn:
+    tag: {:?}
+    val: {:#x}",
+            self.d_tag(),
+            self.d_val
+        ))
+    }
+}
+
+/// The dynamic section (PT_DYNAMIC or SHT_DYNAMIC) together with the string table at
+/// DT_STRTAB, if the file has one.
+#[derive(Clone, Copy)]
+pub struct Dynamic<'a, D: DynamicEntry> {
+    table: Table<'a, D>,
+    strtab: Option<StrTab<'a>>,
+}
+
+impl<'a, D: DynamicEntry> Dynamic<'a, D> {
+    /// Get a Dynamic from its entries and the string table DT_STRTAB points to.
+    pub fn new(table: Table<'a, D>, strtab: Option<StrTab<'a>>) -> Self {
+        Dynamic { table, strtab }
+    }
+
+    /// Get an iterator over the entries before DT_NULL.
+    pub fn iter(&self) -> DynamicIter<'a, D> {
+        DynamicIter {
+            entries: self.table.iter(),
+            done: false,
+        }
+    }
+
+    /// Get the value of the first entry tagged `tag`.
+    pub fn get(&self, tag: DynTag) -> Option<u64> {
+        self.iter()
+            .find(|entry| entry.d_tag() == tag)
+            .map(|entry| entry.d_val())
+    }
+
+    /// Get the string table at DT_STRTAB.
+    pub fn strtab(&self) -> Option<StrTab<'a>> {
+        self.strtab
+    }
+
+    /// Get the string at `offset` in DT_STRTAB.
+    pub fn string(&self, offset: u64) -> Result<&'a str, Error> {
+        let strtab = self.strtab.ok_or(Error::NotFound)?;
+        strtab.get_str(u32::try_from(offset).map_err(|_| Error::Corrupted)?)
+    }
+
+    /// Get an iterator over the names of the needed libraries (DT_NEEDED).
+    pub fn needed(&self) -> NeededIter<'a, D> {
+        NeededIter {
+            entries: self.iter(),
+            dynamic: *self,
+        }
+    }
+
+    /// Get the shared object name (DT_SONAME).
+    pub fn soname(&self) -> Result<&'a str, Error> {
+        self.string(self.get(DT_SONAME).ok_or(Error::NotFound)?)
+    }
+
+    /// Get the library search path (DT_RPATH).
+    pub fn rpath(&self) -> Result<&'a str, Error> {
+        self.string(self.get(DT_RPATH).ok_or(Error::NotFound)?)
+    }
+
+    /// Get the library search path (DT_RUNPATH).
+    pub fn runpath(&self) -> Result<&'a str, Error> {
+        self.string(self.get(DT_RUNPATH).ok_or(Error::NotFound)?)
+    }
+
+    /// Get the DT_FLAGS flags. A missing entry means no flags.
+    pub fn flags(&self) -> DynFlags {
+        DynFlags(self.get(DT_FLAGS).unwrap_or(0))
+    }
+
+    /// Get the DT_FLAGS_1 flags. A missing entry means no flags.
+    pub fn flags_1(&self) -> DynFlags1 {
+        DynFlags1(self.get(DT_FLAGS_1).unwrap_or(0))
+    }
+}
+
+pub struct DynamicIter<'a, D: DynamicEntry> {
+    entries: TableIter<'a, D>,
+    done: bool,
+}
+
+impl<D: DynamicEntry> Iterator for DynamicIter<'_, D> {
+    type Item = D;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let entry = self.entries.next().filter(|entry| entry.d_tag() != DT_NULL);
+        self.done = entry.is_none();
+        entry
+    }
+}
+
+pub struct NeededIter<'a, D: DynamicEntry> {
+    entries: DynamicIter<'a, D>,
+    dynamic: Dynamic<'a, D>,
+}
+
+impl<'a, D: DynamicEntry> Iterator for NeededIter<'a, D> {
+    type Item = Result<&'a str, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.entries.find(|entry| entry.d_tag() == DT_NEEDED)?;
+        Some(self.dynamic.string(entry.d_val()))
+    }
+}
@@ -0,0 +1,512 @@
+use crate::{
+    file::{ElfFile, FileHeader, PhdrIter, ProgramHeader, SectionHeader, ShdrIter},
+    types::{
+        Class, Data, FileType, FileVersion, Machine, PFlag, PType, ShFlag, ShType, EI_CLASS,
+        EI_NIDENT, ELFCLASS32, ELFCLASS64, MAGIC_NUM,
+    },
+    Dyn, Elf32, Elf32Ehdr, Elf32Phdr, Elf32Shdr, Elf64, Elf64Ehdr, Elf64Phdr, Elf64Shdr, Error,
+    Sym,
+};
+use core::fmt;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+/// ELF header of either class, widened to ELF64 field sizes.
+pub struct Ehdr {
+    pub e_ident: [u8; EI_NIDENT],
+    pub e_type: u16,
+    pub e_machine: u16,
+    pub e_version: u32,
+    pub e_entry: u64,
+    pub e_phoff: u64,
+    pub e_shoff: u64,
+    pub e_flags: u32,
+    pub e_ehsize: u16,
+    pub e_phentsize: u16,
+    pub e_phnum: u16,
+    pub e_shentsize: u16,
+    pub e_shnum: u16,
+    pub e_shstrndx: u16,
+}
+
+impl From<Elf32Ehdr> for Ehdr {
+    fn from(ehdr: Elf32Ehdr) -> Self {
+        Ehdr {
+            e_ident: ehdr.e_ident,
+            e_type: ehdr.e_type,
+            e_machine: ehdr.e_machine,
+            e_version: ehdr.e_version,
+            e_entry: ehdr.e_entry as u64,
+            e_phoff: ehdr.e_phoff as u64,
+            e_shoff: ehdr.e_shoff as u64,
+            e_flags: ehdr.e_flags,
+            e_ehsize: ehdr.e_ehsize,
+            e_phentsize: ehdr.e_phentsize,
+            e_phnum: ehdr.e_phnum,
+            e_shentsize: ehdr.e_shentsize,
+            e_shnum: ehdr.e_shnum,
+            e_shstrndx: ehdr.e_shstrndx,
+        }
+    }
+}
+
+impl From<Elf64Ehdr> for Ehdr {
+    fn from(ehdr: Elf64Ehdr) -> Self {
+        Ehdr {
+            e_ident: ehdr.e_ident,
+            e_type: ehdr.e_type,
+            e_machine: ehdr.e_machine,
+            e_version: ehdr.e_version,
+            e_entry: ehdr.e_entry,
+            e_phoff: ehdr.e_phoff,
+            e_shoff: ehdr.e_shoff,
+            e_flags: ehdr.e_flags,
+            e_ehsize: ehdr.e_ehsize,
+            e_phentsize: ehdr.e_phentsize,
+            e_phnum: ehdr.e_phnum,
+            e_shentsize: ehdr.e_shentsize,
+            e_shnum: ehdr.e_shnum,
+            e_shstrndx: ehdr.e_shstrndx,
+        }
+    }
+}
+
+impl fmt::Debug for Ehdr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ELFHeader: 
+    type: {:?}
+    machine: {:?}
+    version: {:?}
+    entry: {:#x}
+    phoff: {:#x}
+    shoff: {:#x}
+    flags: {:#x}
+    ehsize: {:#x}
+    phentsize: {:#x}
+    phnum: {:#x}
+    shentsize: {:#x}
+    shnum: {:#x}
+    shstrndx: {:#x}",
+            FileType(self.e_type),
+            Machine(self.e_machine),
+            FileVersion(self.e_version),
+            self.e_entry,
+            self.e_phoff,
+            self.e_shoff,
+            self.e_flags,
+            self.e_ehsize,
+            self.e_phentsize,
+            self.e_phnum,
+            self.e_shentsize,
+            self.e_shnum,
+            self.e_shstrndx
+        ))
+    }
+}
+
+impl FileHeader for Ehdr {
+    fn e_ident(&self) -> [u8; EI_NIDENT] {
+        self.e_ident
+    }
+    fn e_type(&self) -> u16 {
+        self.e_type
+    }
+    fn e_machine(&self) -> u16 {
+        self.e_machine
+    }
+    fn e_version(&self) -> u32 {
+        self.e_version
+    }
+    fn e_entry(&self) -> u64 {
+        self.e_entry
+    }
+    fn e_phoff(&self) -> u64 {
+        self.e_phoff
+    }
+    fn e_shoff(&self) -> u64 {
+        self.e_shoff
+    }
+    fn e_flags(&self) -> u32 {
+        self.e_flags
+    }
+    fn e_ehsize(&self) -> u16 {
+        self.e_ehsize
+    }
+    fn e_phentsize(&self) -> u16 {
+        self.e_phentsize
+    }
+    fn e_phnum(&self) -> u16 {
+        self.e_phnum
+    }
+    fn e_shentsize(&self) -> u16 {
+        self.e_shentsize
+    }
+    fn e_shnum(&self) -> u16 {
+        self.e_shnum
+    }
+    fn e_shstrndx(&self) -> u16 {
+        self.e_shstrndx
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+/// Program header of either class, widened to ELF64 field sizes.
+pub struct Phdr {
+    pub p_type: u32,
+    pub p_flags: u32,
+    pub p_offset: u64,
+    pub p_vaddr: u64,
+    pub p_paddr: u64,
+    pub p_filesz: u64,
+    pub p_memsz: u64,
+    pub p_align: u64,
+}
+
+impl From<Elf32Phdr> for Phdr {
+    fn from(phdr: Elf32Phdr) -> Self {
+        Phdr {
+            p_type: phdr.p_type,
+            p_flags: phdr.p_flags,
+            p_offset: phdr.p_offset as u64,
+            p_vaddr: phdr.p_vaddr as u64,
+            p_paddr: phdr.p_paddr as u64,
+            p_filesz: phdr.p_filesz as u64,
+            p_memsz: phdr.p_memsz as u64,
+            p_align: phdr.p_align as u64,
+        }
+    }
+}
+
+impl From<Elf64Phdr> for Phdr {
+    fn from(phdr: Elf64Phdr) -> Self {
+        Phdr {
+            p_type: phdr.p_type,
+            p_flags: phdr.p_flags,
+            p_offset: phdr.p_offset,
+            p_vaddr: phdr.p_vaddr,
+            p_paddr: phdr.p_paddr,
+            p_filesz: phdr.p_filesz,
+            p_memsz: phdr.p_memsz,
+            p_align: phdr.p_align,
+        }
+    }
+}
+
+impl fmt::Debug for Phdr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ELFProgramHeader: 
+    type: {:?}
+    flags: {:?}
+    offset: {:#x}
+    vaddr: {:#x}
+    paddr: {:#x}
+    filesz: {:#x}
+    memsz: {:#x}
+    align: {:#x}",
+            PType(self.p_type),
+            PFlag(self.p_flags),
+            self.p_offset,
+            self.p_vaddr,
+            self.p_paddr,
+            self.p_filesz,
+            self.p_memsz,
+            self.p_align
+        ))
+    }
+}
+
+impl ProgramHeader for Phdr {
+    fn p_type(&self) -> u32 {
+        self.p_type
+    }
+    fn p_flags(&self) -> u32 {
+        self.p_flags
+    }
+    fn p_offset(&self) -> u64 {
+        self.p_offset
+    }
+    fn p_vaddr(&self) -> u64 {
+        self.p_vaddr
+    }
+    fn p_paddr(&self) -> u64 {
+        self.p_paddr
+    }
+    fn p_filesz(&self) -> u64 {
+        self.p_filesz
+    }
+    fn p_memsz(&self) -> u64 {
+        self.p_memsz
+    }
+    fn p_align(&self) -> u64 {
+        self.p_align
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+/// Section header of either class, widened to ELF64 field sizes.
+pub struct Shdr {
+    pub sh_name: u32,
+    pub sh_type: u32,
+    pub sh_flags: u64,
+    pub sh_addr: u64,
+    pub sh_offset: u64,
+    pub sh_size: u64,
+    pub sh_link: u32,
+    pub sh_info: u32,
+    pub sh_addralign: u64,
+    pub sh_entsize: u64,
+}
+
+impl From<Elf32Shdr> for Shdr {
+    fn from(shdr: Elf32Shdr) -> Self {
+        Shdr {
+            sh_name: shdr.sh_name,
+            sh_type: shdr.sh_type,
+            sh_flags: shdr.sh_flags as u64,
+            sh_addr: shdr.sh_addr as u64,
+            sh_offset: shdr.sh_offset as u64,
+            sh_size: shdr.sh_size as u64,
+            sh_link: shdr.sh_link,
+            sh_info: shdr.sh_info,
+            sh_addralign: shdr.sh_addralign as u64,
+            sh_entsize: shdr.sh_entsize as u64,
+        }
+    }
+}
+
+impl From<Elf64Shdr> for Shdr {
+    fn from(shdr: Elf64Shdr) -> Self {
+        Shdr {
+            sh_name: shdr.sh_name,
+            sh_type: shdr.sh_type,
+            sh_flags: shdr.sh_flags,
+            sh_addr: shdr.sh_addr,
+            sh_offset: shdr.sh_offset,
+            sh_size: shdr.sh_size,
+            sh_link: shdr.sh_link,
+            sh_info: shdr.sh_info,
+            sh_addralign: shdr.sh_addralign,
+            sh_entsize: shdr.sh_entsize,
+        }
+    }
+}
+
+impl fmt::Debug for Shdr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ELFSectionHeader: 
+    name: {:#x}
+    type: {:?}
+    flags: {:?}
+    addr: {:#x}
+    offset: {:#x}
+    size: {:#x}
+    link: {:#x}
+    info: {:#x}
+    addralign: {:#x}
+    entsize: {:#x}",
+            self.sh_name,
+            ShType(self.sh_type),
+            ShFlag(self.sh_flags),
+            self.sh_addr,
+            self.sh_offset,
+            self.sh_size,
+            self.sh_link,
+            self.sh_info,
+            self.sh_addralign,
+            self.sh_entsize
+        ))
+    }
+}
+
+impl SectionHeader for Shdr {
+    fn sh_name(&self) -> u32 {
+        self.sh_name
+    }
+    fn sh_type(&self) -> u32 {
+        self.sh_type
+    }
+    fn sh_flags(&self) -> u64 {
+        self.sh_flags
+    }
+    fn sh_addr(&self) -> u64 {
+        self.sh_addr
+    }
+    fn sh_offset(&self) -> u64 {
+        self.sh_offset
+    }
+    fn sh_size(&self) -> u64 {
+        self.sh_size
+    }
+    fn sh_link(&self) -> u32 {
+        self.sh_link
+    }
+    fn sh_info(&self) -> u32 {
+        self.sh_info
+    }
+    fn sh_addralign(&self) -> u64 {
+        self.sh_addralign
+    }
+    fn sh_entsize(&self) -> u64 {
+        self.sh_entsize
+    }
+}
+
+/// An ELF file of either class, detected from EI_CLASS.
+pub enum Elf<'a> {
+    Elf32(Elf32<'a>),
+    Elf64(Elf64<'a>),
+}
+
+impl<'a> Elf<'a> {
+    /// Get a Result<Elf enum, Error> from bytes.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Elf<'a>, Error> {
+        if !bytes.starts_with(&MAGIC_NUM) {
+            return Err(Error::InvalidMagicNumber);
+        }
+
+        if bytes.len() < EI_NIDENT {
+            return Err(Error::Corrupted);
+        }
+
+        match Class(bytes[EI_CLASS]) {
+            ELFCLASS32 => Ok(Elf::Elf32(Elf32::from_bytes(bytes)?)),
+            ELFCLASS64 => Ok(Elf::Elf64(Elf64::from_bytes(bytes)?)),
+            _ => Err(Error::InvalidClass),
+        }
+    }
+
+    /// Get the class of the file.
+    pub fn class(&self) -> Class {
+        match self {
+            Elf::Elf32(_) => ELFCLASS32,
+            Elf::Elf64(_) => ELFCLASS64,
+        }
+    }
+
+    /// Get the data encoding (byte order) of the file.
+    pub fn data(&self) -> Data {
+        match self {
+            Elf::Elf32(elf32) => elf32.data(),
+            Elf::Elf64(elf64) => elf64.data(),
+        }
+    }
+
+    /// Get a widened ELF header.
+    pub fn ehdr(&self) -> Ehdr {
+        match self {
+            Elf::Elf32(elf32) => (*elf32.ehdr()).into(),
+            Elf::Elf64(elf64) => (*elf64.ehdr()).into(),
+        }
+    }
+
+    /// Get a entry point.
+    pub fn entry_point(&self) -> u64 {
+        match self {
+            Elf::Elf32(elf32) => elf32.entry_point() as u64,
+            Elf::Elf64(elf64) => elf64.entry_point(),
+        }
+    }
+
+    /// Get the number of program headers, resolving PN_XNUM.
+    pub fn phdr_num(&self) -> u32 {
+        match self {
+            Elf::Elf32(elf32) => elf32.phdr_num(),
+            Elf::Elf64(elf64) => elf64.phdr_num(),
+        }
+    }
+
+    /// Get a Result<nth widened program header, Error>.
+    pub fn phdr_nth(&self, index: u32) -> Result<Phdr, Error> {
+        match self {
+            Elf::Elf32(elf32) => elf32.phdr_nth(index).map(Phdr::from),
+            Elf::Elf64(elf64) => elf64.phdr_nth(index).map(Phdr::from),
+        }
+    }
+
+    /// Get a widened program header iterator.
+    pub fn phdr_iter(&self) -> ElfPhdrIter<'_> {
+        ElfFile::phdr_iter(self)
+    }
+
+    /// Get the number of section headers, resolving an extended section count.
+    pub fn shdr_num(&self) -> u32 {
+        match self {
+            Elf::Elf32(elf32) => elf32.shdr_num(),
+            Elf::Elf64(elf64) => elf64.shdr_num(),
+        }
+    }
+
+    /// Get a Result<nth widened section header, Error>.
+    pub fn shdr_nth(&self, index: u32) -> Result<Shdr, Error> {
+        match self {
+            Elf::Elf32(elf32) => elf32.shdr_nth(index).map(Shdr::from),
+            Elf::Elf64(elf64) => elf64.shdr_nth(index).map(Shdr::from),
+        }
+    }
+
+    /// Get the index of the section header string table, resolving SHN_XINDEX.
+    pub fn shstrndx(&self) -> u32 {
+        match self {
+            Elf::Elf32(elf32) => elf32.shstrndx(),
+            Elf::Elf64(elf64) => elf64.shstrndx(),
+        }
+    }
+
+    /// Get a widened section header iterator.
+    pub fn shdr_iter(&self) -> ElfShdrIter<'_> {
+        ElfFile::shdr_iter(self)
+    }
+}
+
+impl<'a> ElfFile<'a> for Elf<'a> {
+    type Ehdr = Ehdr;
+    type Phdr = Phdr;
+    type Shdr = Shdr;
+    type Sym = Sym;
+    type Dyn = Dyn;
+
+    fn bytes(&self) -> &'a [u8] {
+        match self {
+            Elf::Elf32(elf32) => elf32.bytes(),
+            Elf::Elf64(elf64) => elf64.bytes(),
+        }
+    }
+
+    fn class(&self) -> Class {
+        Elf::class(self)
+    }
+
+    fn data(&self) -> Data {
+        Elf::data(self)
+    }
+
+    fn ehdr(&self) -> Ehdr {
+        Elf::ehdr(self)
+    }
+
+    fn phdr_num(&self) -> u32 {
+        Elf::phdr_num(self)
+    }
+
+    fn phdr_nth(&self, index: u32) -> Result<Phdr, Error> {
+        Elf::phdr_nth(self, index)
+    }
+
+    fn shdr_num(&self) -> u32 {
+        Elf::shdr_num(self)
+    }
+
+    fn shdr_nth(&self, index: u32) -> Result<Shdr, Error> {
+        Elf::shdr_nth(self, index)
+    }
+
+    fn shstrndx(&self) -> u32 {
+        Elf::shstrndx(self)
+    }
+}
+
+pub type ElfPhdrIter<'a> = PhdrIter<'a, 'a, Elf<'a>>;
+
+pub type ElfShdrIter<'a> = ShdrIter<'a, 'a, Elf<'a>>;
@@ -1,9 +1,12 @@
 use crate::{
+    file::{
+        self, ElfFile, FileHeader, HeaderCounts, PhdrIter, ProgramHeader, SectionHeader, ShdrIter,
+    },
     types::{
-        Class, FileType, FileVersion, Machine, PFlag, PType, ShFlag, ShType, EI_NIDENT, ELFCLASS32,
-        MAGIC_NUM,
+        Class, Data, FileType, FileVersion, Machine, PFlag, PType, ShFlag, ShType, EI_DATA,
+        EI_NIDENT, ELFCLASS32,
     },
-    util, Error,
+    util, Elf32Dyn, Elf32Sym, Error,
 };
 use core::{fmt, mem};
 
@@ -27,22 +30,23 @@ pub struct Elf32Ehdr {
     pub e_shstrndx: u16,
 }
 
-impl<'a> Elf32Ehdr {
-    fn new(bytes: &'a [u8]) -> Self {
+impl Elf32Ehdr {
+    fn new(bytes: &[u8]) -> Self {
+        let data = Data(bytes[EI_DATA]);
         let e_ident = bytes[0..16].try_into().unwrap();
-        let e_type = util::bytes_to_u16(&bytes[16..18]);
-        let e_machine = util::bytes_to_u16(&bytes[18..20]);
-        let e_version = util::bytes_to_u32(&bytes[20..24]);
-        let e_entry = util::bytes_to_u32(&bytes[24..28]);
-        let e_phoff = util::bytes_to_u32(&bytes[28..32]);
-        let e_shoff = util::bytes_to_u32(&bytes[32..36]);
-        let e_flags = util::bytes_to_u32(&bytes[36..40]);
-        let e_ehsize = util::bytes_to_u16(&bytes[40..42]);
-        let e_phentsize = util::bytes_to_u16(&bytes[42..44]);
-        let e_phnum = util::bytes_to_u16(&bytes[44..46]);
-        let e_shentsize = util::bytes_to_u16(&bytes[46..48]);
-        let e_shnum = util::bytes_to_u16(&bytes[48..50]);
-        let e_shstrndx = util::bytes_to_u16(&bytes[50..52]);
+        let e_type = util::bytes_to_u16(&bytes[16..18], data);
+        let e_machine = util::bytes_to_u16(&bytes[18..20], data);
+        let e_version = util::bytes_to_u32(&bytes[20..24], data);
+        let e_entry = util::bytes_to_u32(&bytes[24..28], data);
+        let e_phoff = util::bytes_to_u32(&bytes[28..32], data);
+        let e_shoff = util::bytes_to_u32(&bytes[32..36], data);
+        let e_flags = util::bytes_to_u32(&bytes[36..40], data);
+        let e_ehsize = util::bytes_to_u16(&bytes[40..42], data);
+        let e_phentsize = util::bytes_to_u16(&bytes[42..44], data);
+        let e_phnum = util::bytes_to_u16(&bytes[44..46], data);
+        let e_shentsize = util::bytes_to_u16(&bytes[46..48], data);
+        let e_shnum = util::bytes_to_u16(&bytes[48..50], data);
+        let e_shstrndx = util::bytes_to_u16(&bytes[50..52], data);
         Elf32Ehdr {
             e_ident,
             e_type,
@@ -96,6 +100,51 @@ impl fmt::Debug for Elf32Ehdr {
     }
 }
 
+impl FileHeader for Elf32Ehdr {
+    fn e_ident(&self) -> [u8; EI_NIDENT] {
+        self.e_ident
+    }
+    fn e_type(&self) -> u16 {
+        self.e_type
+    }
+    fn e_machine(&self) -> u16 {
+        self.e_machine
+    }
+    fn e_version(&self) -> u32 {
+        self.e_version
+    }
+    fn e_entry(&self) -> u64 {
+        self.e_entry as u64
+    }
+    fn e_phoff(&self) -> u64 {
+        self.e_phoff as u64
+    }
+    fn e_shoff(&self) -> u64 {
+        self.e_shoff as u64
+    }
+    fn e_flags(&self) -> u32 {
+        self.e_flags
+    }
+    fn e_ehsize(&self) -> u16 {
+        self.e_ehsize
+    }
+    fn e_phentsize(&self) -> u16 {
+        self.e_phentsize
+    }
+    fn e_phnum(&self) -> u16 {
+        self.e_phnum
+    }
+    fn e_shentsize(&self) -> u16 {
+        self.e_shentsize
+    }
+    fn e_shnum(&self) -> u16 {
+        self.e_shnum
+    }
+    fn e_shstrndx(&self) -> u16 {
+        self.e_shstrndx
+    }
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, PartialEq, Eq)]
 /// Program header of ELF32.
@@ -110,17 +159,16 @@ pub struct Elf32Phdr {
     pub p_align: u32,
 }
 
-impl<'a> Elf32Phdr {
-    fn new(bytes: &'a [u8], offset: u32) -> Self {
-        let offset_bytes = &bytes[offset as usize..];
-        let p_type = util::bytes_to_u32(&offset_bytes[0..4]);
-        let p_offset = util::bytes_to_u32(&offset_bytes[4..8]);
-        let p_vaddr = util::bytes_to_u32(&offset_bytes[8..12]);
-        let p_paddr = util::bytes_to_u32(&offset_bytes[12..16]);
-        let p_filesz = util::bytes_to_u32(&offset_bytes[16..20]);
-        let p_memsz = util::bytes_to_u32(&offset_bytes[20..24]);
-        let p_flags = util::bytes_to_u32(&offset_bytes[24..28]);
-        let p_align = util::bytes_to_u32(&offset_bytes[28..32]);
+impl Elf32Phdr {
+    fn new(offset_bytes: &[u8], data: Data) -> Self {
+        let p_type = util::bytes_to_u32(&offset_bytes[0..4], data);
+        let p_offset = util::bytes_to_u32(&offset_bytes[4..8], data);
+        let p_vaddr = util::bytes_to_u32(&offset_bytes[8..12], data);
+        let p_paddr = util::bytes_to_u32(&offset_bytes[12..16], data);
+        let p_filesz = util::bytes_to_u32(&offset_bytes[16..20], data);
+        let p_memsz = util::bytes_to_u32(&offset_bytes[20..24], data);
+        let p_flags = util::bytes_to_u32(&offset_bytes[24..28], data);
+        let p_align = util::bytes_to_u32(&offset_bytes[28..32], data);
         Elf32Phdr {
             p_type,
             p_offset,
@@ -158,6 +206,33 @@ impl fmt::Debug for Elf32Phdr {
     }
 }
 
+impl ProgramHeader for Elf32Phdr {
+    fn p_type(&self) -> u32 {
+        self.p_type
+    }
+    fn p_flags(&self) -> u32 {
+        self.p_flags
+    }
+    fn p_offset(&self) -> u64 {
+        self.p_offset as u64
+    }
+    fn p_vaddr(&self) -> u64 {
+        self.p_vaddr as u64
+    }
+    fn p_paddr(&self) -> u64 {
+        self.p_paddr as u64
+    }
+    fn p_filesz(&self) -> u64 {
+        self.p_filesz as u64
+    }
+    fn p_memsz(&self) -> u64 {
+        self.p_memsz as u64
+    }
+    fn p_align(&self) -> u64 {
+        self.p_align as u64
+    }
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, PartialEq, Eq)]
 /// Section header of ELF32.
@@ -174,19 +249,18 @@ pub struct Elf32Shdr {
     pub sh_entsize: u32,
 }
 
-impl<'a> Elf32Shdr {
-    fn new(bytes: &'a [u8], offset: u32) -> Self {
-        let offset_bytes = &bytes[offset as usize..];
-        let sh_name = util::bytes_to_u32(&offset_bytes[0..4]);
-        let sh_type = util::bytes_to_u32(&offset_bytes[4..8]);
-        let sh_flags = util::bytes_to_u32(&offset_bytes[8..12]);
-        let sh_addr = util::bytes_to_u32(&offset_bytes[12..16]);
-        let sh_offset = util::bytes_to_u32(&offset_bytes[16..20]);
-        let sh_size = util::bytes_to_u32(&offset_bytes[20..24]);
-        let sh_link = util::bytes_to_u32(&offset_bytes[24..28]);
-        let sh_info = util::bytes_to_u32(&offset_bytes[28..32]);
-        let sh_addralign = util::bytes_to_u32(&offset_bytes[32..36]);
-        let sh_entsize = util::bytes_to_u32(&offset_bytes[36..40]);
+impl Elf32Shdr {
+    fn new(offset_bytes: &[u8], data: Data) -> Self {
+        let sh_name = util::bytes_to_u32(&offset_bytes[0..4], data);
+        let sh_type = util::bytes_to_u32(&offset_bytes[4..8], data);
+        let sh_flags = util::bytes_to_u32(&offset_bytes[8..12], data);
+        let sh_addr = util::bytes_to_u32(&offset_bytes[12..16], data);
+        let sh_offset = util::bytes_to_u32(&offset_bytes[16..20], data);
+        let sh_size = util::bytes_to_u32(&offset_bytes[20..24], data);
+        let sh_link = util::bytes_to_u32(&offset_bytes[24..28], data);
+        let sh_info = util::bytes_to_u32(&offset_bytes[28..32], data);
+        let sh_addralign = util::bytes_to_u32(&offset_bytes[32..36], data);
+        let sh_entsize = util::bytes_to_u32(&offset_bytes[36..40], data);
 
         Elf32Shdr {
             sh_name,
@@ -206,7 +280,7 @@ impl<'a> Elf32Shdr {
 impl fmt::Debug for Elf32Shdr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_fmt(format_args!(
-            "ELF64SectionHeader: 
+            "ELF32SectionHeader: 
     name: {:#x}
     type: {:?}
     flags: {:?}
@@ -231,35 +305,77 @@ impl fmt::Debug for Elf32Shdr {
     }
 }
 
+impl SectionHeader for Elf32Shdr {
+    fn sh_name(&self) -> u32 {
+        self.sh_name
+    }
+    fn sh_type(&self) -> u32 {
+        self.sh_type
+    }
+    fn sh_flags(&self) -> u64 {
+        self.sh_flags as u64
+    }
+    fn sh_addr(&self) -> u64 {
+        self.sh_addr as u64
+    }
+    fn sh_offset(&self) -> u64 {
+        self.sh_offset as u64
+    }
+    fn sh_size(&self) -> u64 {
+        self.sh_size as u64
+    }
+    fn sh_link(&self) -> u32 {
+        self.sh_link
+    }
+    fn sh_info(&self) -> u32 {
+        self.sh_info
+    }
+    fn sh_addralign(&self) -> u64 {
+        self.sh_addralign as u64
+    }
+    fn sh_entsize(&self) -> u64 {
+        self.sh_entsize as u64
+    }
+}
+
 pub struct Elf32<'a> {
     bytes: &'a [u8],
     ehdr: Elf32Ehdr,
+    counts: HeaderCounts,
 }
 
-impl<'a> Elf32<'_> {
+impl<'a> Elf32<'a> {
     /// Get a Result<Elf32 struct, Error> from bytes.
-    pub fn from_bytes(bytes: &'a [u8]) -> Result<Elf32, Error> {
-        let length = bytes.len();
-
-        if !bytes.starts_with(&MAGIC_NUM) {
-            return Err(Error::InvalidMagicNumber);
-        }
-
-        if length < mem::size_of::<Elf32Ehdr>() {
-            return Err(Error::Corrupted);
-        }
-
-        if Class(bytes[4] as u8) != ELFCLASS32 {
-            return Err(Error::InvalidClass);
-        }
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Elf32<'a>, Error> {
+        file::ident_check(bytes, ELFCLASS32, mem::size_of::<Elf32Ehdr>())?;
 
         let ehdr = Elf32Ehdr::new(bytes);
-
-        if !phdr_shdr_check(&ehdr, length) {
+        let counts = file::header_counts(&ehdr, || {
+            let entry = util::table_entry(
+                bytes,
+                ehdr.e_shoff as u64,
+                0,
+                0,
+                mem::size_of::<Elf32Shdr>(),
+            )?;
+            Ok(Elf32Shdr::new(entry, Data(bytes[EI_DATA])))
+        })?;
+
+        if !file::phdr_shdr_check(
+            &ehdr,
+            &counts,
+            bytes.len(),
+            mem::size_of::<Elf32Phdr>(),
+            mem::size_of::<Elf32Shdr>(),
+        ) {
             return Err(Error::Corrupted);
         }
 
-        Ok(Elf32 { bytes, ehdr })
+        Ok(Elf32 {
+            bytes,
+            ehdr,
+            counts,
+        })
     }
 
     /// Get a ELF header reference.
@@ -267,141 +383,128 @@ impl<'a> Elf32<'_> {
         &self.ehdr
     }
 
+    /// Get the data encoding (byte order) of the file.
+    pub fn data(&self) -> Data {
+        Data(self.ehdr.e_ident[EI_DATA])
+    }
+
     /// Get a entry point.
     pub fn entry_point(&self) -> u32 {
         self.ehdr.e_entry
     }
 
-    /// Get the number of program headers.
-    pub fn phdr_num(&self) -> u16 {
-        self.ehdr.e_phnum
+    /// Get the number of program headers, resolving PN_XNUM.
+    pub fn phdr_num(&self) -> u32 {
+        self.counts.phnum
     }
 
     /// Get a Result<nth program header, Error>.
-    pub fn phdr_nth(&self, index: u16) -> Result<Elf32Phdr, Error> {
-        let e_phoff = self.ehdr.e_phoff;
-        let e_phentsize = self.ehdr.e_phentsize;
-        let e_phnum = self.ehdr.e_phnum;
-
-        let offset = e_phoff + index as u32 * e_phentsize as u32;
-
-        if index >= e_phnum {
+    pub fn phdr_nth(&self, index: u32) -> Result<Elf32Phdr, Error> {
+        if index >= self.counts.phnum {
             return Err(Error::InvalidIndex);
         }
 
-        Ok(Elf32Phdr::new(self.bytes, offset))
-    }
-
-    /// Get a nth program header, but not check bounds.
-    pub fn phdr_nth_uncheck(&self, index: u16) -> Elf32Phdr {
-        let e_phoff = self.ehdr.e_phoff;
-        let e_phentsize = self.ehdr.e_phentsize;
-
-        let offset = e_phoff + index as u32 * e_phentsize as u32;
-
-        Elf32Phdr::new(self.bytes, offset)
+        let entry = util::table_entry(
+            self.bytes,
+            self.ehdr.e_phoff as u64,
+            self.ehdr.e_phentsize as u64,
+            index as u64,
+            mem::size_of::<Elf32Phdr>(),
+        )?;
+        Ok(Elf32Phdr::new(entry, self.data()))
     }
 
     /// Get a program header iterator.
-    pub fn phdr_iter(&self) -> Elf32PhdrIter {
-        Elf32PhdrIter {
-            index: 0,
-            elf32: self,
-        }
+    pub fn phdr_iter(&self) -> Elf32PhdrIter<'_> {
+        ElfFile::phdr_iter(self)
     }
 
-    /// Get the number of section headers.
-    pub fn shdr_num(&self) -> u16 {
-        self.ehdr.e_shnum
+    /// Get the number of section headers, resolving an extended section count.
+    pub fn shdr_num(&self) -> u32 {
+        self.counts.shnum
     }
 
     /// Get a Result<nth section header, Error>.
-    pub fn shdr_nth(&self, index: u16) -> Result<Elf32Shdr, Error> {
-        let e_shoff = self.ehdr.e_shoff;
-        let e_shentsize = self.ehdr.e_shentsize;
-        let e_shnum = self.ehdr.e_shnum;
-
-        let offset = e_shoff + index as u32 * e_shentsize as u32;
-
-        if index >= e_shnum {
+    pub fn shdr_nth(&self, index: u32) -> Result<Elf32Shdr, Error> {
+        if index >= self.counts.shnum {
             return Err(Error::InvalidIndex);
         }
 
-        Ok(Elf32Shdr::new(self.bytes, offset))
+        let entry = util::table_entry(
+            self.bytes,
+            self.ehdr.e_shoff as u64,
+            self.ehdr.e_shentsize as u64,
+            index as u64,
+            mem::size_of::<Elf32Shdr>(),
+        )?;
+        Ok(Elf32Shdr::new(entry, self.data()))
     }
 
-    /// Get a nth section header, but not check bounds.
-    pub fn shdr_nth_uncheck(&self, index: u16) -> Elf32Shdr {
-        let e_shoff = self.ehdr.e_shoff;
-        let e_shentsize = self.ehdr.e_shentsize;
-
-        let offset = e_shoff + index as u32 * e_shentsize as u32;
-
-        Elf32Shdr::new(self.bytes, offset)
+    /// Get the index of the section header string table, resolving SHN_XINDEX.
+    pub fn shstrndx(&self) -> u32 {
+        self.counts.shstrndx
     }
 
     /// Get a section header iterator.
-    pub fn shdr_iter(&self) -> Elf32ShdrIter {
-        Elf32ShdrIter {
-            index: 0,
-            elf32: self,
-        }
+    pub fn shdr_iter(&self) -> Elf32ShdrIter<'_> {
+        ElfFile::shdr_iter(self)
     }
-}
 
-fn phdr_shdr_check<'a>(ehdr: &Elf32Ehdr, length: usize) -> bool {
-    let phnum = ehdr.e_phnum;
-    let phoff = ehdr.e_phoff;
-    let phsize = ehdr.e_phentsize;
-    let phdr_end = phoff + phsize as u32 * phnum as u32;
-    if phdr_end > length as u32 {
-        return false;
+    /// Get the name of a section from the section header string table.
+    pub fn section_name(&self, shdr: &Elf32Shdr) -> Result<&'a str, Error> {
+        ElfFile::section_name(self, shdr)
     }
 
-    let shnum = ehdr.e_shnum;
-    let shoff = ehdr.e_shoff;
-    let shsize = ehdr.e_shentsize;
-    let shdr_end = shoff + shsize as u32 * shnum as u32;
-    if shdr_end as usize > length {
-        return false;
+    /// Get the first section called `name`.
+    pub fn section_by_name(&self, name: &str) -> Option<Elf32Shdr> {
+        ElfFile::section_by_name(self, name)
     }
-    true
 }
 
-pub struct Elf32PhdrIter<'a> {
-    index: u16,
-    elf32: &'a Elf32<'a>,
-}
+impl<'a> ElfFile<'a> for Elf32<'a> {
+    type Ehdr = Elf32Ehdr;
+    type Phdr = Elf32Phdr;
+    type Shdr = Elf32Shdr;
+    type Sym = Elf32Sym;
+    type Dyn = Elf32Dyn;
 
-impl Iterator for Elf32PhdrIter<'_> {
-    type Item = Result<Elf32Phdr, Error>;
+    fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.index >= self.elf32.ehdr.e_phnum {
-            return None;
-        }
+    fn class(&self) -> Class {
+        ELFCLASS32
+    }
 
-        let phdr = self.elf32.phdr_nth(self.index);
-        self.index += 1;
-        Some(phdr)
+    fn data(&self) -> Data {
+        Elf32::data(self)
     }
-}
 
-pub struct Elf32ShdrIter<'a> {
-    index: u16,
-    elf32: &'a Elf32<'a>,
-}
+    fn ehdr(&self) -> Elf32Ehdr {
+        self.ehdr
+    }
 
-impl Iterator for Elf32ShdrIter<'_> {
-    type Item = Result<Elf32Shdr, Error>;
+    fn phdr_num(&self) -> u32 {
+        Elf32::phdr_num(self)
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.index >= self.elf32.ehdr.e_shnum {
-            return None;
-        }
+    fn phdr_nth(&self, index: u32) -> Result<Elf32Phdr, Error> {
+        Elf32::phdr_nth(self, index)
+    }
+
+    fn shdr_num(&self) -> u32 {
+        Elf32::shdr_num(self)
+    }
 
-        let shdr = self.elf32.shdr_nth(self.index);
-        self.index += 1;
-        Some(shdr)
+    fn shdr_nth(&self, index: u32) -> Result<Elf32Shdr, Error> {
+        Elf32::shdr_nth(self, index)
+    }
+
+    fn shstrndx(&self) -> u32 {
+        Elf32::shstrndx(self)
     }
 }
+
+pub type Elf32PhdrIter<'a> = PhdrIter<'a, 'a, Elf32<'a>>;
+
+pub type Elf32ShdrIter<'a> = ShdrIter<'a, 'a, Elf32<'a>>;
@@ -1,9 +1,12 @@
 use crate::{
+    file::{
+        self, ElfFile, FileHeader, HeaderCounts, PhdrIter, ProgramHeader, SectionHeader, ShdrIter,
+    },
     types::{
-        Class, FileType, FileVersion, Machine, PFlag, PType, ShFlag, ShType, EI_NIDENT, ELFCLASS64,
-        MAGIC_NUM,
+        Class, Data, FileType, FileVersion, Machine, PFlag, PType, ShFlag, ShType, EI_DATA,
+        EI_NIDENT, ELFCLASS64,
     },
-    util, Error,
+    util, Elf64Dyn, Elf64Sym, Error,
 };
 use core::{fmt, mem};
 
@@ -27,22 +30,23 @@ pub struct Elf64Ehdr {
     pub e_shstrndx: u16,
 }
 
-impl<'a> Elf64Ehdr {
-    fn new(bytes: &'a [u8]) -> Self {
+impl Elf64Ehdr {
+    fn new(bytes: &[u8]) -> Self {
+        let data = Data(bytes[EI_DATA]);
         let e_ident = bytes[0..16].try_into().unwrap();
-        let e_type = util::bytes_to_u16(&bytes[16..18]);
-        let e_machine = util::bytes_to_u16(&bytes[18..20]);
-        let e_version = util::bytes_to_u32(&bytes[20..24]);
-        let e_entry = util::bytes_to_u64(&bytes[24..32]);
-        let e_phoff = util::bytes_to_u64(&bytes[32..40]);
-        let e_shoff = util::bytes_to_u64(&bytes[40..48]);
-        let e_flags = util::bytes_to_u32(&bytes[48..52]);
-        let e_ehsize = util::bytes_to_u16(&bytes[52..54]);
-        let e_phentsize = util::bytes_to_u16(&bytes[54..56]);
-        let e_phnum = util::bytes_to_u16(&bytes[56..58]);
-        let e_shentsize = util::bytes_to_u16(&bytes[58..60]);
-        let e_shnum = util::bytes_to_u16(&bytes[60..62]);
-        let e_shstrndx = util::bytes_to_u16(&bytes[62..64]);
+        let e_type = util::bytes_to_u16(&bytes[16..18], data);
+        let e_machine = util::bytes_to_u16(&bytes[18..20], data);
+        let e_version = util::bytes_to_u32(&bytes[20..24], data);
+        let e_entry = util::bytes_to_u64(&bytes[24..32], data);
+        let e_phoff = util::bytes_to_u64(&bytes[32..40], data);
+        let e_shoff = util::bytes_to_u64(&bytes[40..48], data);
+        let e_flags = util::bytes_to_u32(&bytes[48..52], data);
+        let e_ehsize = util::bytes_to_u16(&bytes[52..54], data);
+        let e_phentsize = util::bytes_to_u16(&bytes[54..56], data);
+        let e_phnum = util::bytes_to_u16(&bytes[56..58], data);
+        let e_shentsize = util::bytes_to_u16(&bytes[58..60], data);
+        let e_shnum = util::bytes_to_u16(&bytes[60..62], data);
+        let e_shstrndx = util::bytes_to_u16(&bytes[62..64], data);
         Elf64Ehdr {
             e_ident,
             e_type,
@@ -96,6 +100,51 @@ impl fmt::Debug for Elf64Ehdr {
     }
 }
 
+impl FileHeader for Elf64Ehdr {
+    fn e_ident(&self) -> [u8; EI_NIDENT] {
+        self.e_ident
+    }
+    fn e_type(&self) -> u16 {
+        self.e_type
+    }
+    fn e_machine(&self) -> u16 {
+        self.e_machine
+    }
+    fn e_version(&self) -> u32 {
+        self.e_version
+    }
+    fn e_entry(&self) -> u64 {
+        self.e_entry
+    }
+    fn e_phoff(&self) -> u64 {
+        self.e_phoff
+    }
+    fn e_shoff(&self) -> u64 {
+        self.e_shoff
+    }
+    fn e_flags(&self) -> u32 {
+        self.e_flags
+    }
+    fn e_ehsize(&self) -> u16 {
+        self.e_ehsize
+    }
+    fn e_phentsize(&self) -> u16 {
+        self.e_phentsize
+    }
+    fn e_phnum(&self) -> u16 {
+        self.e_phnum
+    }
+    fn e_shentsize(&self) -> u16 {
+        self.e_shentsize
+    }
+    fn e_shnum(&self) -> u16 {
+        self.e_shnum
+    }
+    fn e_shstrndx(&self) -> u16 {
+        self.e_shstrndx
+    }
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, PartialEq, Eq)]
 /// Program header of ELF64.
@@ -110,17 +159,16 @@ pub struct Elf64Phdr {
     pub p_align: u64,
 }
 
-impl<'a> Elf64Phdr {
-    fn new(bytes: &'a [u8], offset: u64) -> Self {
-        let offset_bytes = &bytes[offset as usize..];
-        let p_type = util::bytes_to_u32(&offset_bytes[0..4]);
-        let p_flags = util::bytes_to_u32(&offset_bytes[4..8]);
-        let p_offset = util::bytes_to_u64(&offset_bytes[8..16]);
-        let p_vaddr = util::bytes_to_u64(&offset_bytes[16..24]);
-        let p_paddr = util::bytes_to_u64(&offset_bytes[24..32]);
-        let p_filesz = util::bytes_to_u64(&offset_bytes[32..40]);
-        let p_memsz = util::bytes_to_u64(&offset_bytes[40..48]);
-        let p_align = util::bytes_to_u64(&offset_bytes[48..56]);
+impl Elf64Phdr {
+    fn new(offset_bytes: &[u8], data: Data) -> Self {
+        let p_type = util::bytes_to_u32(&offset_bytes[0..4], data);
+        let p_flags = util::bytes_to_u32(&offset_bytes[4..8], data);
+        let p_offset = util::bytes_to_u64(&offset_bytes[8..16], data);
+        let p_vaddr = util::bytes_to_u64(&offset_bytes[16..24], data);
+        let p_paddr = util::bytes_to_u64(&offset_bytes[24..32], data);
+        let p_filesz = util::bytes_to_u64(&offset_bytes[32..40], data);
+        let p_memsz = util::bytes_to_u64(&offset_bytes[40..48], data);
+        let p_align = util::bytes_to_u64(&offset_bytes[48..56], data);
         Elf64Phdr {
             p_type,
             p_flags,
@@ -158,6 +206,33 @@ impl fmt::Debug for Elf64Phdr {
     }
 }
 
+impl ProgramHeader for Elf64Phdr {
+    fn p_type(&self) -> u32 {
+        self.p_type
+    }
+    fn p_flags(&self) -> u32 {
+        self.p_flags
+    }
+    fn p_offset(&self) -> u64 {
+        self.p_offset
+    }
+    fn p_vaddr(&self) -> u64 {
+        self.p_vaddr
+    }
+    fn p_paddr(&self) -> u64 {
+        self.p_paddr
+    }
+    fn p_filesz(&self) -> u64 {
+        self.p_filesz
+    }
+    fn p_memsz(&self) -> u64 {
+        self.p_memsz
+    }
+    fn p_align(&self) -> u64 {
+        self.p_align
+    }
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, PartialEq, Eq)]
 /// Section Header of ELF64.
@@ -174,19 +249,18 @@ pub struct Elf64Shdr {
     pub sh_entsize: u64,
 }
 
-impl<'a> Elf64Shdr {
-    fn new(bytes: &'a [u8], offset: u64) -> Self {
-        let offset_bytes = &bytes[offset as usize..];
-        let sh_name = util::bytes_to_u32(&offset_bytes[0..4]);
-        let sh_type = util::bytes_to_u32(&offset_bytes[4..8]);
-        let sh_flags = util::bytes_to_u64(&offset_bytes[8..16]);
-        let sh_addr = util::bytes_to_u64(&offset_bytes[16..24]);
-        let sh_offset = util::bytes_to_u64(&offset_bytes[24..32]);
-        let sh_size = util::bytes_to_u64(&offset_bytes[32..40]);
-        let sh_link = util::bytes_to_u32(&offset_bytes[40..44]);
-        let sh_info = util::bytes_to_u32(&offset_bytes[44..48]);
-        let sh_addralign = util::bytes_to_u64(&offset_bytes[48..56]);
-        let sh_entsize = util::bytes_to_u64(&offset_bytes[56..64]);
+impl Elf64Shdr {
+    fn new(offset_bytes: &[u8], data: Data) -> Self {
+        let sh_name = util::bytes_to_u32(&offset_bytes[0..4], data);
+        let sh_type = util::bytes_to_u32(&offset_bytes[4..8], data);
+        let sh_flags = util::bytes_to_u64(&offset_bytes[8..16], data);
+        let sh_addr = util::bytes_to_u64(&offset_bytes[16..24], data);
+        let sh_offset = util::bytes_to_u64(&offset_bytes[24..32], data);
+        let sh_size = util::bytes_to_u64(&offset_bytes[32..40], data);
+        let sh_link = util::bytes_to_u32(&offset_bytes[40..44], data);
+        let sh_info = util::bytes_to_u32(&offset_bytes[44..48], data);
+        let sh_addralign = util::bytes_to_u64(&offset_bytes[48..56], data);
+        let sh_entsize = util::bytes_to_u64(&offset_bytes[56..64], data);
 
         Elf64Shdr {
             sh_name,
@@ -231,35 +305,71 @@ impl fmt::Debug for Elf64Shdr {
     }
 }
 
+impl SectionHeader for Elf64Shdr {
+    fn sh_name(&self) -> u32 {
+        self.sh_name
+    }
+    fn sh_type(&self) -> u32 {
+        self.sh_type
+    }
+    fn sh_flags(&self) -> u64 {
+        self.sh_flags
+    }
+    fn sh_addr(&self) -> u64 {
+        self.sh_addr
+    }
+    fn sh_offset(&self) -> u64 {
+        self.sh_offset
+    }
+    fn sh_size(&self) -> u64 {
+        self.sh_size
+    }
+    fn sh_link(&self) -> u32 {
+        self.sh_link
+    }
+    fn sh_info(&self) -> u32 {
+        self.sh_info
+    }
+    fn sh_addralign(&self) -> u64 {
+        self.sh_addralign
+    }
+    fn sh_entsize(&self) -> u64 {
+        self.sh_entsize
+    }
+}
+
 pub struct Elf64<'a> {
     bytes: &'a [u8],
     ehdr: Elf64Ehdr,
+    counts: HeaderCounts,
 }
 
-impl<'a> Elf64<'_> {
+impl<'a> Elf64<'a> {
     /// Get a Result<Elf64 struct, Error> from bytes.
-    pub fn from_bytes(bytes: &'a [u8]) -> Result<Elf64, Error> {
-        let length = bytes.len();
-
-        if !bytes.starts_with(&MAGIC_NUM) {
-            return Err(Error::InvalidMagicNumber);
-        }
-
-        if length < mem::size_of::<Elf64Ehdr>() {
-            return Err(Error::Corrupted);
-        }
-
-        if Class(bytes[4] as u8) != ELFCLASS64 {
-            return Err(Error::InvalidClass);
-        }
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Elf64<'a>, Error> {
+        file::ident_check(bytes, ELFCLASS64, mem::size_of::<Elf64Ehdr>())?;
 
         let ehdr = Elf64Ehdr::new(bytes);
-
-        if !phdr_shdr_check(&ehdr, length) {
+        let counts = file::header_counts(&ehdr, || {
+            let entry = util::table_entry(bytes, ehdr.e_shoff, 0, 0, mem::size_of::<Elf64Shdr>())?;
+            Ok(Elf64Shdr::new(entry, Data(bytes[EI_DATA])))
+        })?;
+
+        if !file::phdr_shdr_check(
+            &ehdr,
+            &counts,
+            bytes.len(),
+            mem::size_of::<Elf64Phdr>(),
+            mem::size_of::<Elf64Shdr>(),
+        ) {
             return Err(Error::Corrupted);
         }
 
-        Ok(Elf64 { bytes, ehdr })
+        Ok(Elf64 {
+            bytes,
+            ehdr,
+            counts,
+        })
     }
 
     /// Get a ELF header reference.
@@ -267,141 +377,128 @@ impl<'a> Elf64<'_> {
         &self.ehdr
     }
 
+    /// Get the data encoding (byte order) of the file.
+    pub fn data(&self) -> Data {
+        Data(self.ehdr.e_ident[EI_DATA])
+    }
+
     /// Get a entry point.
     pub fn entry_point(&self) -> u64 {
         self.ehdr.e_entry
     }
 
-    /// Get the number of program headers.
-    pub fn phdr_num(&self) -> u16 {
-        self.ehdr.e_phnum
+    /// Get the number of program headers, resolving PN_XNUM.
+    pub fn phdr_num(&self) -> u32 {
+        self.counts.phnum
     }
 
     /// Get a Result<nth program header, Error>.
-    pub fn phdr_nth(&self, index: u16) -> Result<Elf64Phdr, Error> {
-        let e_phoff = self.ehdr.e_phoff;
-        let e_phentsize = self.ehdr.e_phentsize;
-        let e_phnum = self.ehdr.e_phnum;
-
-        let offset = e_phoff + index as u64 * e_phentsize as u64;
-
-        if index >= e_phnum {
+    pub fn phdr_nth(&self, index: u32) -> Result<Elf64Phdr, Error> {
+        if index >= self.counts.phnum {
             return Err(Error::InvalidIndex);
         }
 
-        Ok(Elf64Phdr::new(self.bytes, offset))
-    }
-
-    /// Get a nth program header, but not check bounds.
-    pub fn phdr_nth_uncheck(&self, index: u16) -> Elf64Phdr {
-        let e_phoff = self.ehdr.e_phoff;
-        let e_phentsize = self.ehdr.e_phentsize;
-
-        let offset = e_phoff + index as u64 * e_phentsize as u64;
-
-        Elf64Phdr::new(self.bytes, offset)
+        let entry = util::table_entry(
+            self.bytes,
+            self.ehdr.e_phoff,
+            self.ehdr.e_phentsize as u64,
+            index as u64,
+            mem::size_of::<Elf64Phdr>(),
+        )?;
+        Ok(Elf64Phdr::new(entry, self.data()))
     }
 
     /// Get a program header iterator.
-    pub fn phdr_iter(&self) -> Elf64PhdrIter {
-        Elf64PhdrIter {
-            index: 0,
-            elf64: self,
-        }
+    pub fn phdr_iter(&self) -> Elf64PhdrIter<'_> {
+        ElfFile::phdr_iter(self)
     }
 
-    /// Get the number of section headers.
-    pub fn shdr_num(&self) -> u16 {
-        self.ehdr.e_shnum
+    /// Get the number of section headers, resolving an extended section count.
+    pub fn shdr_num(&self) -> u32 {
+        self.counts.shnum
     }
 
     /// Get a Result<nth section header, Error>.
-    pub fn shdr_nth(&self, index: u16) -> Result<Elf64Shdr, Error> {
-        let e_shoff = self.ehdr.e_shoff;
-        let e_shentsize = self.ehdr.e_shentsize;
-        let e_shnum = self.ehdr.e_shnum;
-
-        let offset = e_shoff + index as u64 * e_shentsize as u64;
-
-        if index >= e_shnum {
+    pub fn shdr_nth(&self, index: u32) -> Result<Elf64Shdr, Error> {
+        if index >= self.counts.shnum {
             return Err(Error::InvalidIndex);
         }
 
-        Ok(Elf64Shdr::new(self.bytes, offset))
+        let entry = util::table_entry(
+            self.bytes,
+            self.ehdr.e_shoff,
+            self.ehdr.e_shentsize as u64,
+            index as u64,
+            mem::size_of::<Elf64Shdr>(),
+        )?;
+        Ok(Elf64Shdr::new(entry, self.data()))
     }
 
-    /// Get a nth section header, but not check bounds.
-    pub fn shdr_nth_uncheck(&self, index: u16) -> Elf64Shdr {
-        let e_shoff = self.ehdr.e_shoff;
-        let e_shentsize = self.ehdr.e_shentsize;
-
-        let offset = e_shoff + index as u64 * e_shentsize as u64;
-
-        Elf64Shdr::new(self.bytes, offset)
+    /// Get the index of the section header string table, resolving SHN_XINDEX.
+    pub fn shstrndx(&self) -> u32 {
+        self.counts.shstrndx
     }
 
     /// Get a section header iterator.
-    pub fn shdr_iter(&self) -> Elf64ShdrIter {
-        Elf64ShdrIter {
-            index: 0,
-            elf64: self,
-        }
+    pub fn shdr_iter(&self) -> Elf64ShdrIter<'_> {
+        ElfFile::shdr_iter(self)
     }
-}
 
-fn phdr_shdr_check<'a>(ehdr: &Elf64Ehdr, length: usize) -> bool {
-    let phnum = ehdr.e_phnum;
-    let phoff = ehdr.e_phoff;
-    let phsize = ehdr.e_phentsize;
-    let phdr_end = phoff + phsize as u64 * phnum as u64;
-    if phdr_end > length as u64 {
-        return false;
+    /// Get the name of a section from the section header string table.
+    pub fn section_name(&self, shdr: &Elf64Shdr) -> Result<&'a str, Error> {
+        ElfFile::section_name(self, shdr)
     }
 
-    let shnum = ehdr.e_shnum;
-    let shoff = ehdr.e_shoff;
-    let shsize = ehdr.e_shentsize;
-    let shdr_end = shoff + shsize as u64 * shnum as u64;
-    if shdr_end > length as u64 {
-        return false;
+    /// Get the first section called `name`.
+    pub fn section_by_name(&self, name: &str) -> Option<Elf64Shdr> {
+        ElfFile::section_by_name(self, name)
     }
-    true
 }
 
-pub struct Elf64PhdrIter<'a> {
-    index: u16,
-    elf64: &'a Elf64<'a>,
-}
+impl<'a> ElfFile<'a> for Elf64<'a> {
+    type Ehdr = Elf64Ehdr;
+    type Phdr = Elf64Phdr;
+    type Shdr = Elf64Shdr;
+    type Sym = Elf64Sym;
+    type Dyn = Elf64Dyn;
 
-impl Iterator for Elf64PhdrIter<'_> {
-    type Item = Elf64Phdr;
+    fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.index >= self.elf64.ehdr.e_phnum {
-            return None;
-        }
+    fn class(&self) -> Class {
+        ELFCLASS64
+    }
 
-        let phdr = self.elf64.phdr_nth_uncheck(self.index);
-        self.index += 1;
-        Some(phdr)
+    fn data(&self) -> Data {
+        Elf64::data(self)
     }
-}
 
-pub struct Elf64ShdrIter<'a> {
-    index: u16,
-    elf64: &'a Elf64<'a>,
-}
+    fn ehdr(&self) -> Elf64Ehdr {
+        self.ehdr
+    }
 
-impl Iterator for Elf64ShdrIter<'_> {
-    type Item = Elf64Shdr;
+    fn phdr_num(&self) -> u32 {
+        Elf64::phdr_num(self)
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.index >= self.elf64.ehdr.e_shnum {
-            return None;
-        }
+    fn phdr_nth(&self, index: u32) -> Result<Elf64Phdr, Error> {
+        Elf64::phdr_nth(self, index)
+    }
+
+    fn shdr_num(&self) -> u32 {
+        Elf64::shdr_num(self)
+    }
 
-        let shdr = self.elf64.shdr_nth_uncheck(self.index);
-        self.index += 1;
-        Some(shdr)
+    fn shdr_nth(&self, index: u32) -> Result<Elf64Shdr, Error> {
+        Elf64::shdr_nth(self, index)
+    }
+
+    fn shstrndx(&self) -> u32 {
+        Elf64::shstrndx(self)
     }
 }
+
+pub type Elf64PhdrIter<'a> = PhdrIter<'a, 'a, Elf64<'a>>;
+
+pub type Elf64ShdrIter<'a> = ShdrIter<'a, 'a, Elf64<'a>>;
@@ -0,0 +1,644 @@
+use crate::{
+    types::{
+        Class, Data, Machine, ShType, DT_STRSZ, DT_STRTAB, EI_CLASS, EI_DATA, EI_NIDENT,
+        ELFDATA2LSB, ELFDATA2MSB, MAGIC_NUM, NT_GNU_PROPERTY_TYPE_0, PN_XNUM, PT_DYNAMIC,
+        PT_GNU_PROPERTY, PT_LOAD, PT_NOTE, PT_TLS, SHN_UNDEF, SHN_XINDEX, SHT_DYNAMIC, SHT_DYNSYM,
+        SHT_GNU_HASH, SHT_GNU_VERDEF, SHT_GNU_VERNEED, SHT_GNU_VERSYM, SHT_HASH, SHT_NOBITS,
+        SHT_NOTE, SHT_REL, SHT_RELA, SHT_SYMTAB,
+    },
+    util, DebugLink, Dynamic, DynamicEntry, Error, GnuHashTable, GnuPropertyIter, HashTable,
+    NoteIter, RelocationTable, StrTab, Symbol, SymbolTable, SymbolVersions, SysvHashTable, Table,
+    TlsTemplate, VerdefIter, VerneedIter,
+};
+use core::marker::PhantomData;
+
+/// Accessors shared by the ELF headers of both classes. Addresses, offsets and sizes are
+/// widened to u64.
+pub trait FileHeader: Copy {
+    fn e_ident(&self) -> [u8; EI_NIDENT];
+    fn e_type(&self) -> u16;
+    fn e_machine(&self) -> u16;
+    fn e_version(&self) -> u32;
+    fn e_entry(&self) -> u64;
+    fn e_phoff(&self) -> u64;
+    fn e_shoff(&self) -> u64;
+    fn e_flags(&self) -> u32;
+    fn e_ehsize(&self) -> u16;
+    fn e_phentsize(&self) -> u16;
+    fn e_phnum(&self) -> u16;
+    fn e_shentsize(&self) -> u16;
+    fn e_shnum(&self) -> u16;
+    fn e_shstrndx(&self) -> u16;
+}
+
+/// Accessors shared by the program headers of both classes. Addresses, offsets and sizes are
+/// widened to u64.
+pub trait ProgramHeader: Copy {
+    fn p_type(&self) -> u32;
+    fn p_flags(&self) -> u32;
+    fn p_offset(&self) -> u64;
+    fn p_vaddr(&self) -> u64;
+    fn p_paddr(&self) -> u64;
+    fn p_filesz(&self) -> u64;
+    fn p_memsz(&self) -> u64;
+    fn p_align(&self) -> u64;
+}
+
+/// Accessors shared by the section headers of both classes. Addresses, offsets and sizes are
+/// widened to u64.
+pub trait SectionHeader: Copy {
+    fn sh_name(&self) -> u32;
+    fn sh_type(&self) -> u32;
+    fn sh_flags(&self) -> u64;
+    fn sh_addr(&self) -> u64;
+    fn sh_offset(&self) -> u64;
+    fn sh_size(&self) -> u64;
+    fn sh_link(&self) -> u32;
+    fn sh_info(&self) -> u32;
+    fn sh_addralign(&self) -> u64;
+    fn sh_entsize(&self) -> u64;
+}
+
+/// A parsed ELF file borrowing its bytes for `'a`. Implemented by `Elf32`, `Elf64` and `Elf`,
+/// so features built on top of it work for both classes.
+pub trait ElfFile<'a> {
+    type Ehdr: FileHeader;
+    type Phdr: ProgramHeader;
+    type Shdr: SectionHeader;
+    type Sym: Symbol;
+    type Dyn: DynamicEntry;
+
+    /// Get the whole file.
+    fn bytes(&self) -> &'a [u8];
+
+    /// Get the class of the file.
+    fn class(&self) -> Class;
+
+    /// Get the data encoding (byte order) of the file.
+    fn data(&self) -> Data;
+
+    /// Get a ELF header.
+    fn ehdr(&self) -> Self::Ehdr;
+
+    /// Get the number of program headers, resolving PN_XNUM.
+    fn phdr_num(&self) -> u32;
+
+    /// Get a Result<nth program header, Error>.
+    fn phdr_nth(&self, index: u32) -> Result<Self::Phdr, Error>;
+
+    /// Get the number of section headers, resolving an extended section count.
+    fn shdr_num(&self) -> u32;
+
+    /// Get a Result<nth section header, Error>.
+    fn shdr_nth(&self, index: u32) -> Result<Self::Shdr, Error>;
+
+    /// Get the index of the section header string table, resolving SHN_XINDEX.
+    fn shstrndx(&self) -> u32;
+
+    /// Get a entry point.
+    fn entry_point(&self) -> u64 {
+        self.ehdr().e_entry()
+    }
+
+    /// Get a program header iterator.
+    fn phdr_iter(&self) -> PhdrIter<'_, 'a, Self>
+    where
+        Self: Sized,
+    {
+        PhdrIter {
+            index: 0,
+            file: self,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get the contents of a section. SHT_NOBITS sections occupy no space in the file, so
+    /// their contents are empty.
+    fn section_data(&self, shdr: &Self::Shdr) -> Result<&'a [u8], Error> {
+        if shdr.sh_type() == SHT_NOBITS.0 {
+            return Ok(&[]);
+        }
+        util::slice(self.bytes(), shdr.sh_offset(), shdr.sh_size())
+    }
+
+    /// Get the p_filesz bytes of a segment stored in the file.
+    fn segment_data(&self, phdr: &Self::Phdr) -> Result<&'a [u8], Error> {
+        util::slice(self.bytes(), phdr.p_offset(), phdr.p_filesz())
+    }
+
+    /// Get the file offset that virtual address `vaddr` is loaded from, if it lies in the
+    /// file-backed part of a PT_LOAD segment.
+    fn vaddr_to_offset(&self, vaddr: u64) -> Option<u64>
+    where
+        Self: Sized,
+    {
+        self.phdr_iter()
+            .filter(|phdr| phdr.p_type() == PT_LOAD.0)
+            .find(|phdr| vaddr >= phdr.p_vaddr() && vaddr - phdr.p_vaddr() < phdr.p_filesz())
+            .and_then(|phdr| phdr.p_offset().checked_add(vaddr - phdr.p_vaddr()))
+    }
+
+    /// Get the virtual address that file offset `offset` is loaded at, if a PT_LOAD segment
+    /// maps it.
+    fn offset_to_vaddr(&self, offset: u64) -> Option<u64>
+    where
+        Self: Sized,
+    {
+        self.phdr_iter()
+            .filter(|phdr| phdr.p_type() == PT_LOAD.0)
+            .find(|phdr| offset >= phdr.p_offset() && offset - phdr.p_offset() < phdr.p_filesz())
+            .and_then(|phdr| phdr.p_vaddr().checked_add(offset - phdr.p_offset()))
+    }
+
+    /// Get the `len` bytes loaded at virtual address `vaddr`.
+    ///
+    /// The range must lie in a single PT_LOAD segment. Error::NotFileBacked is returned when
+    /// it reaches into the zero-filled tail between p_filesz and p_memsz (e.g. .bss), and
+    /// Error::NotFound when no segment covers it.
+    fn read_at_vaddr(&self, vaddr: u64, len: u64) -> Result<&'a [u8], Error>
+    where
+        Self: Sized,
+    {
+        let phdr = self
+            .phdr_iter()
+            .filter(|phdr| phdr.p_type() == PT_LOAD.0)
+            .find(|phdr| vaddr >= phdr.p_vaddr() && vaddr - phdr.p_vaddr() < phdr.p_memsz())
+            .ok_or(Error::NotFound)?;
+        let delta = vaddr - phdr.p_vaddr();
+        if len > phdr.p_memsz() - delta {
+            return Err(Error::NotFound);
+        }
+        if delta > phdr.p_filesz() || len > phdr.p_filesz() - delta {
+            return Err(Error::NotFileBacked);
+        }
+        let offset = phdr.p_offset().checked_add(delta).ok_or(Error::Corrupted)?;
+        util::slice(self.bytes(), offset, len)
+    }
+
+    /// Get the notes of a PT_NOTE segment.
+    fn segment_notes(&self, phdr: &Self::Phdr) -> Result<NoteIter<'a>, Error> {
+        if phdr.p_type() != PT_NOTE.0 {
+            return Err(Error::NotFound);
+        }
+        Ok(NoteIter::new(
+            self.segment_data(phdr)?,
+            phdr.p_align(),
+            self.data(),
+        ))
+    }
+
+    /// Get the notes of a SHT_NOTE section.
+    fn section_notes(&self, shdr: &Self::Shdr) -> Result<NoteIter<'a>, Error> {
+        if shdr.sh_type() != SHT_NOTE.0 {
+            return Err(Error::NotFound);
+        }
+        Ok(NoteIter::new(
+            self.section_data(shdr)?,
+            shdr.sh_addralign(),
+            self.data(),
+        ))
+    }
+
+    /// Get the build ID of a NT_GNU_BUILD_ID note, looked up in the PT_NOTE segments and then
+    /// in the SHT_NOTE sections.
+    fn build_id(&self) -> Option<&'a [u8]>
+    where
+        Self: Sized,
+    {
+        let segments = self
+            .phdr_iter()
+            .filter_map(|phdr| self.segment_notes(&phdr).ok())
+            .flatten();
+        let sections = self
+            .shdr_iter()
+            .filter_map(|shdr| self.section_notes(&shdr).ok())
+            .flatten();
+        segments
+            .chain(sections)
+            .find_map(|note| note.build_id().ok())
+    }
+
+    /// Get the properties of the NT_GNU_PROPERTY_TYPE_0 note, found through PT_GNU_PROPERTY or,
+    /// in relocatable files, the .note.gnu.property section.
+    fn gnu_properties(&self) -> Result<GnuPropertyIter<'a>, Error>
+    where
+        Self: Sized,
+    {
+        let mut notes = match self
+            .phdr_iter()
+            .find(|phdr| phdr.p_type() == PT_GNU_PROPERTY.0)
+        {
+            Some(phdr) => NoteIter::new(self.segment_data(&phdr)?, phdr.p_align(), self.data()),
+            None => {
+                let shdr = self
+                    .section_by_name(".note.gnu.property")
+                    .ok_or(Error::NotFound)?;
+                self.section_notes(&shdr)?
+            }
+        };
+        let note = notes
+            .find(|note| note.gnu_type() == Some(NT_GNU_PROPERTY_TYPE_0))
+            .ok_or(Error::NotFound)?;
+        Ok(GnuPropertyIter::new(
+            note.desc,
+            Machine(self.ehdr().e_machine()),
+            self.class(),
+            self.data(),
+        ))
+    }
+
+    /// Get the name and CRC32 of the separate debug file from the .gnu_debuglink section.
+    fn debuglink(&self) -> Result<DebugLink<'a>, Error>
+    where
+        Self: Sized,
+    {
+        let shdr = self
+            .section_by_name(".gnu_debuglink")
+            .ok_or(Error::NotFound)?;
+        DebugLink::parse(self.section_data(&shdr)?, self.data())
+    }
+
+    /// Get the string table held by a section.
+    fn strtab(&self, shdr: &Self::Shdr) -> Result<StrTab<'a>, Error> {
+        Ok(StrTab::new(self.section_data(shdr)?))
+    }
+
+    /// Get the section header string table.
+    fn shstrtab(&self) -> Result<StrTab<'a>, Error> {
+        let shstrndx = self.shstrndx();
+        if shstrndx == SHN_UNDEF.0 as u32 {
+            return Err(Error::NotFound);
+        }
+        self.strtab(&self.shdr_nth(shstrndx)?)
+    }
+
+    /// Get the name of a section from the section header string table.
+    fn section_name(&self, shdr: &Self::Shdr) -> Result<&'a str, Error> {
+        self.shstrtab()?.get_str(shdr.sh_name())
+    }
+
+    /// Get the first section called `name`.
+    fn section_by_name(&self, name: &str) -> Option<Self::Shdr>
+    where
+        Self: Sized,
+    {
+        let shstrtab = self.shstrtab().ok()?;
+        self.shdr_iter()
+            .find(|shdr| shstrtab.get(shdr.sh_name()) == Ok(name.as_bytes()))
+    }
+
+    /// Get the first section of type `sh_type`.
+    fn section_by_type(&self, sh_type: ShType) -> Option<Self::Shdr>
+    where
+        Self: Sized,
+    {
+        self.shdr_iter().find(|shdr| shdr.sh_type() == sh_type.0)
+    }
+
+    /// Get a symbol table held by a SHT_SYMTAB or SHT_DYNSYM section, with names resolved
+    /// through the string table at sh_link.
+    fn symbol_table(&self, shdr: &Self::Shdr) -> Result<SymbolTable<'a, Self::Sym>, Error> {
+        let table = Table::new(
+            self.section_data(shdr)?,
+            shdr.sh_entsize(),
+            self.class(),
+            self.data(),
+        )?;
+        let strtab = self.strtab(&self.shdr_nth(shdr.sh_link())?)?;
+        Ok(SymbolTable::new(table, strtab))
+    }
+
+    /// Get the static symbol table (.symtab).
+    fn symtab(&self) -> Result<SymbolTable<'a, Self::Sym>, Error>
+    where
+        Self: Sized,
+    {
+        let shdr = self.section_by_type(SHT_SYMTAB).ok_or(Error::NotFound)?;
+        self.symbol_table(&shdr)
+    }
+
+    /// Get the dynamic symbol table (.dynsym).
+    fn dynsym(&self) -> Result<SymbolTable<'a, Self::Sym>, Error>
+    where
+        Self: Sized,
+    {
+        let shdr = self.section_by_type(SHT_DYNSYM).ok_or(Error::NotFound)?;
+        self.symbol_table(&shdr)
+    }
+
+    /// Get the hash table held by a SHT_HASH or SHT_GNU_HASH section. Other sections hold no
+    /// hash table, so they are NotFound.
+    fn hash_table(&self, shdr: &Self::Shdr) -> Result<HashTable<'a>, Error> {
+        let bytes = self.section_data(shdr)?;
+        match ShType(shdr.sh_type()) {
+            SHT_HASH => Ok(HashTable::Sysv(SysvHashTable::parse(bytes, self.data())?)),
+            SHT_GNU_HASH => Ok(HashTable::Gnu(GnuHashTable::parse(
+                bytes,
+                self.class(),
+                self.data(),
+            )?)),
+            _ => Err(Error::NotFound),
+        }
+    }
+
+    /// Get the defined dynamic symbol called `name` through the hash table, as the dynamic
+    /// linker does. The SHT_GNU_HASH section is preferred over the SHT_HASH section.
+    fn lookup_dynsym(&self, name: &str) -> Option<Self::Sym>
+    where
+        Self: Sized,
+    {
+        let shdr = self
+            .section_by_type(SHT_GNU_HASH)
+            .or_else(|| self.section_by_type(SHT_HASH))?;
+        let symtab = self
+            .symbol_table(&self.shdr_nth(shdr.sh_link()).ok()?)
+            .ok()?;
+        self.hash_table(&shdr).ok()?.lookup(&symtab, name)
+    }
+
+    /// Get the versions of the dynamic symbols from the SHT_GNU_VERSYM section, and the
+    /// SHT_GNU_VERDEF and SHT_GNU_VERNEED sections if there are any.
+    fn symbol_versions(&self) -> Result<SymbolVersions<'a>, Error>
+    where
+        Self: Sized,
+    {
+        let shdr = self
+            .section_by_type(SHT_GNU_VERSYM)
+            .ok_or(Error::NotFound)?;
+        let versym = Table::new(
+            self.section_data(&shdr)?,
+            shdr.sh_entsize(),
+            self.class(),
+            self.data(),
+        )?;
+        let verdefs = match self.section_by_type(SHT_GNU_VERDEF) {
+            Some(shdr) => Some(VerdefIter::new(
+                self.section_data(&shdr)?,
+                shdr.sh_info(),
+                self.strtab(&self.shdr_nth(shdr.sh_link())?)?,
+                self.data(),
+            )),
+            None => None,
+        };
+        let verneeds = match self.section_by_type(SHT_GNU_VERNEED) {
+            Some(shdr) => Some(VerneedIter::new(
+                self.section_data(&shdr)?,
+                shdr.sh_info(),
+                self.strtab(&self.shdr_nth(shdr.sh_link())?)?,
+                self.data(),
+            )),
+            None => None,
+        };
+        Ok(SymbolVersions::new(versym, verdefs, verneeds))
+    }
+
+    /// Get the relocations held by a SHT_REL or SHT_RELA section. Other sections hold no
+    /// relocations, so they are NotFound.
+    fn relocation_table(&self, shdr: &Self::Shdr) -> Result<RelocationTable<'a>, Error> {
+        let rela = match ShType(shdr.sh_type()) {
+            SHT_REL => false,
+            SHT_RELA => true,
+            _ => return Err(Error::NotFound),
+        };
+        RelocationTable::new(
+            self.section_data(shdr)?,
+            shdr.sh_entsize(),
+            rela,
+            self.class(),
+            self.data(),
+        )
+    }
+
+    /// Get the section a relocation section applies to (sh_info). Dynamic relocation sections
+    /// apply to the whole image and have no such section.
+    fn relocation_target(&self, shdr: &Self::Shdr) -> Result<Self::Shdr, Error> {
+        if shdr.sh_info() == SHN_UNDEF.0 as u32 {
+            return Err(Error::NotFound);
+        }
+        self.shdr_nth(shdr.sh_info())
+    }
+
+    /// Get the symbol table the relocations of a relocation section refer to (sh_link).
+    fn relocation_symtab(&self, shdr: &Self::Shdr) -> Result<SymbolTable<'a, Self::Sym>, Error> {
+        if shdr.sh_link() == SHN_UNDEF.0 as u32 {
+            return Err(Error::NotFound);
+        }
+        self.symbol_table(&self.shdr_nth(shdr.sh_link())?)
+    }
+
+    /// Get the dynamic section, with strings resolved through DT_STRTAB.
+    ///
+    /// The PT_DYNAMIC segment is used when there is one, so that stripped files without section
+    /// headers work too. Otherwise the SHT_DYNAMIC section and its sh_link string table are used.
+    fn dynamic(&self) -> Result<Dynamic<'a, Self::Dyn>, Error>
+    where
+        Self: Sized,
+    {
+        if let Some(phdr) = self.phdr_iter().find(|phdr| phdr.p_type() == PT_DYNAMIC.0) {
+            let table = Table::new(self.segment_data(&phdr)?, 0, self.class(), self.data())?;
+            let dynamic = Dynamic::new(table, None);
+            let strtab = match (dynamic.get(DT_STRTAB), dynamic.get(DT_STRSZ)) {
+                (Some(addr), Some(size)) => Some(StrTab::new(self.read_at_vaddr(addr, size)?)),
+                _ => None,
+            };
+            return Ok(Dynamic::new(table, strtab));
+        }
+
+        let shdr = self.section_by_type(SHT_DYNAMIC).ok_or(Error::NotFound)?;
+        let table = Table::new(
+            self.section_data(&shdr)?,
+            shdr.sh_entsize(),
+            self.class(),
+            self.data(),
+        )?;
+        let strtab = self.strtab(&self.shdr_nth(shdr.sh_link())?)?;
+        Ok(Dynamic::new(table, Some(strtab)))
+    }
+
+    /// Get the TLS initialization image from the PT_TLS segment.
+    fn tls_template(&self) -> Result<TlsTemplate<'a>, Error>
+    where
+        Self: Sized,
+    {
+        let phdr = self
+            .phdr_iter()
+            .find(|phdr| phdr.p_type() == PT_TLS.0)
+            .ok_or(Error::NotFound)?;
+        TlsTemplate::new(
+            self.segment_data(&phdr)?,
+            phdr.p_memsz(),
+            phdr.p_align(),
+            Machine(self.ehdr().e_machine()),
+            self.class(),
+            self.data(),
+        )
+    }
+
+    /// Get a section header iterator.
+    fn shdr_iter(&self) -> ShdrIter<'_, 'a, Self>
+    where
+        Self: Sized,
+    {
+        ShdrIter {
+            index: 0,
+            file: self,
+            _marker: PhantomData,
+        }
+    }
+}
+
+pub struct PhdrIter<'b, 'a, F: ElfFile<'a>> {
+    index: u32,
+    file: &'b F,
+    _marker: PhantomData<&'a [u8]>,
+}
+
+impl<'a, F: ElfFile<'a>> Iterator for PhdrIter<'_, 'a, F> {
+    type Item = F::Phdr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.file.phdr_num() {
+            return None;
+        }
+
+        let phdr = self.file.phdr_nth(self.index).ok()?;
+        self.index += 1;
+        Some(phdr)
+    }
+}
+
+pub struct ShdrIter<'b, 'a, F: ElfFile<'a>> {
+    index: u32,
+    file: &'b F,
+    _marker: PhantomData<&'a [u8]>,
+}
+
+impl<'a, F: ElfFile<'a>> Iterator for ShdrIter<'_, 'a, F> {
+    type Item = F::Shdr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.file.shdr_num() {
+            return None;
+        }
+
+        let shdr = self.file.shdr_nth(self.index).ok()?;
+        self.index += 1;
+        Some(shdr)
+    }
+}
+
+/// Check the identification bytes before an ELF header of `ehdr_size` bytes is decoded.
+pub(crate) fn ident_check(bytes: &[u8], class: Class, ehdr_size: usize) -> Result<(), Error> {
+    if !bytes.starts_with(&MAGIC_NUM) {
+        return Err(Error::InvalidMagicNumber);
+    }
+
+    if bytes.len() < ehdr_size {
+        return Err(Error::Corrupted);
+    }
+
+    if Class(bytes[EI_CLASS]) != class {
+        return Err(Error::InvalidClass);
+    }
+
+    let data = Data(bytes[EI_DATA]);
+    if data != ELFDATA2LSB && data != ELFDATA2MSB {
+        return Err(Error::InvalidData);
+    }
+    Ok(())
+}
+
+/// Header table sizes after resolving the PN_XNUM/SHN_XINDEX escape values.
+#[derive(Clone, Copy)]
+pub(crate) struct HeaderCounts {
+    pub phnum: u32,
+    pub shnum: u32,
+    pub shstrndx: u32,
+}
+
+/// Resolve the real e_phnum, e_shnum and e_shstrndx. Files with too many program headers or
+/// sections keep them in section 0, which is only read through `shdr0` when it is needed.
+pub(crate) fn header_counts<H, S, F>(ehdr: &H, shdr0: F) -> Result<HeaderCounts, Error>
+where
+    H: FileHeader,
+    S: SectionHeader,
+    F: FnOnce() -> Result<S, Error>,
+{
+    let e_phnum = ehdr.e_phnum();
+    let e_shnum = ehdr.e_shnum();
+    let e_shstrndx = ehdr.e_shstrndx();
+
+    let extended =
+        ehdr.e_shoff() != 0 && (e_shnum == 0 || e_shstrndx == SHN_XINDEX.0 || e_phnum == PN_XNUM);
+    if !extended {
+        return Ok(HeaderCounts {
+            phnum: e_phnum as u32,
+            shnum: e_shnum as u32,
+            shstrndx: e_shstrndx as u32,
+        });
+    }
+
+    let shdr0 = shdr0()?;
+    let shnum = if e_shnum == 0 {
+        u32::try_from(shdr0.sh_size()).map_err(|_| Error::Corrupted)?
+    } else {
+        e_shnum as u32
+    };
+    let shstrndx = if e_shstrndx == SHN_XINDEX.0 {
+        shdr0.sh_link()
+    } else {
+        e_shstrndx as u32
+    };
+    let phnum = if e_phnum == PN_XNUM {
+        shdr0.sh_info()
+    } else {
+        e_phnum as u32
+    };
+    Ok(HeaderCounts {
+        phnum,
+        shnum,
+        shstrndx,
+    })
+}
+
+/// Check that the program/section header tables fit in a file of `length` bytes and that their
+/// entries are large enough to hold a `phdr_size`/`shdr_size` byte header.
+pub(crate) fn phdr_shdr_check<H: FileHeader>(
+    ehdr: &H,
+    counts: &HeaderCounts,
+    length: usize,
+    phdr_size: usize,
+    shdr_size: usize,
+) -> bool {
+    table_check(
+        ehdr.e_phoff(),
+        ehdr.e_phentsize(),
+        counts.phnum as u64,
+        phdr_size,
+        length,
+    ) && table_check(
+        ehdr.e_shoff(),
+        ehdr.e_shentsize(),
+        counts.shnum as u64,
+        shdr_size,
+        length,
+    )
+}
+
+fn table_check(offset: u64, entsize: u16, num: u64, size: usize, length: usize) -> bool {
+    if num == 0 {
+        return true;
+    }
+
+    if (entsize as usize) < size {
+        return false;
+    }
+
+    match (entsize as u64)
+        .checked_mul(num)
+        .and_then(|table_size| table_size.checked_add(offset))
+    {
+        Some(end) => end <= length as u64,
+        None => false,
+    }
+}
@@ -0,0 +1,200 @@
+use crate::{
+    sym::{Symbol, SymbolTable},
+    types::{Class, Data, ELFCLASS64, SHN_UNDEF},
+    util, Error,
+};
+
+/// Get the hash of `name` used by SHT_HASH sections (the System V ELF hash).
+pub fn sysv_hash(name: &[u8]) -> u32 {
+    name.iter().fold(0, |hash: u32, &byte| {
+        let hash = (hash << 4).wrapping_add(byte as u32);
+        let high = hash & 0xf000_0000;
+        (hash ^ (high >> 24)) & !high
+    })
+}
+
+/// Get the hash of `name` used by SHT_GNU_HASH sections (the DJB hash).
+pub fn gnu_hash(name: &[u8]) -> u32 {
+    name.iter().fold(5381, |hash: u32, &byte| {
+        hash.wrapping_mul(33).wrapping_add(byte as u32)
+    })
+}
+
+/// Check if `sym` is defined and called `name`.
+fn matches<S: Symbol>(symtab: &SymbolTable<'_, S>, sym: &S, name: &str) -> bool {
+    sym.st_shndx() != SHN_UNDEF.0 && symtab.strtab().get(sym.st_name()) == Ok(name.as_bytes())
+}
+
+/// A System V hash table (SHT_HASH, DT_HASH): nbucket and nchain, followed by the buckets
+/// and the chains, all 32-bit words.
+#[derive(Clone, Copy)]
+pub struct SysvHashTable<'a> {
+    nbucket: u32,
+    nchain: u32,
+    buckets: &'a [u8],
+    chains: &'a [u8],
+    data: Data,
+}
+
+impl<'a> SysvHashTable<'a> {
+    /// Decode the contents of a SHT_HASH section.
+    pub fn parse(bytes: &'a [u8], data: Data) -> Result<Self, Error> {
+        let header = bytes.get(..8).ok_or(Error::Corrupted)?;
+        let nbucket = util::bytes_to_u32(&header[0..4], data);
+        let nchain = util::bytes_to_u32(&header[4..8], data);
+        let buckets = util::slice(bytes, 8, nbucket as u64 * 4)?;
+        let chains = util::slice(bytes, 8 + nbucket as u64 * 4, nchain as u64 * 4)?;
+        Ok(SysvHashTable {
+            nbucket,
+            nchain,
+            buckets,
+            chains,
+            data,
+        })
+    }
+
+    /// Get the number of buckets.
+    pub fn nbucket(&self) -> u32 {
+        self.nbucket
+    }
+
+    /// Get the number of chain entries, which is the number of symbols in the symbol table.
+    pub fn nchain(&self) -> u32 {
+        self.nchain
+    }
+
+    fn word(&self, words: &[u8], index: u32) -> u32 {
+        util::bytes_to_u32(&words[index as usize * 4..], self.data)
+    }
+
+    /// Get the defined symbol called `name` from `symtab`, the symbol table the hash table
+    /// belongs to.
+    pub fn lookup<S: Symbol>(&self, symtab: &SymbolTable<'a, S>, name: &str) -> Option<S> {
+        if self.nbucket == 0 {
+            return None;
+        }
+        let mut index = self.word(self.buckets, sysv_hash(name.as_bytes()) % self.nbucket);
+        // A chain visits each symbol at most once, unless the table is corrupted.
+        for _ in 0..self.nchain {
+            if index == 0 || index >= self.nchain {
+                return None;
+            }
+            let sym = symtab.get(index as usize).ok()?;
+            if matches(symtab, &sym, name) {
+                return Some(sym);
+            }
+            index = self.word(self.chains, index);
+        }
+        None
+    }
+}
+
+/// A GNU hash table (SHT_GNU_HASH, DT_GNU_HASH): a header, a bloom filter of words of the
+/// class, the buckets, and the hash values of the symbols from symoffset on.
+#[derive(Clone, Copy)]
+pub struct GnuHashTable<'a> {
+    nbuckets: u32,
+    symoffset: u32,
+    bloom_shift: u32,
+    bloom: &'a [u8],
+    bloom_word: usize,
+    buckets: &'a [u8],
+    chains: &'a [u8],
+    data: Data,
+}
+
+impl<'a> GnuHashTable<'a> {
+    /// Decode the contents of a SHT_GNU_HASH section of a file of `class`.
+    pub fn parse(bytes: &'a [u8], class: Class, data: Data) -> Result<Self, Error> {
+        let header = bytes.get(..16).ok_or(Error::Corrupted)?;
+        let nbuckets = util::bytes_to_u32(&header[0..4], data);
+        let symoffset = util::bytes_to_u32(&header[4..8], data);
+        let bloom_size = util::bytes_to_u32(&header[8..12], data);
+        let bloom_shift = util::bytes_to_u32(&header[12..16], data);
+        if bloom_size == 0 || bloom_shift >= 32 {
+            return Err(Error::Corrupted);
+        }
+        let bloom_word = if class == ELFCLASS64 { 8 } else { 4 };
+        let bloom = util::slice(bytes, 16, bloom_size as u64 * bloom_word as u64)?;
+        let buckets_offset = 16 + bloom.len();
+        let buckets = util::slice(bytes, buckets_offset as u64, nbuckets as u64 * 4)?;
+        Ok(GnuHashTable {
+            nbuckets,
+            symoffset,
+            bloom_shift,
+            bloom,
+            bloom_word,
+            buckets,
+            chains: &bytes[buckets_offset + buckets.len()..],
+            data,
+        })
+    }
+
+    /// Get the number of buckets.
+    pub fn nbuckets(&self) -> u32 {
+        self.nbuckets
+    }
+
+    /// Get the index of the first symbol in the table. The symbols before it, such as the
+    /// undefined ones, cannot be looked up.
+    pub fn symoffset(&self) -> u32 {
+        self.symoffset
+    }
+
+    /// Check the bloom filter for `hash`. False means no symbol has the hash.
+    pub fn may_contain(&self, hash: u32) -> bool {
+        let bits = self.bloom_word as u32 * 8;
+        let count = (self.bloom.len() / self.bloom_word) as u32;
+        let offset = ((hash / bits) % count) as usize * self.bloom_word;
+        let word = util::read_uint(&self.bloom[offset..offset + self.bloom_word], self.data);
+        let mask = (1 << (hash % bits)) | (1 << ((hash >> self.bloom_shift) % bits));
+        word & mask == mask
+    }
+
+    /// Get the defined symbol called `name` from `symtab`, the symbol table the hash table
+    /// belongs to.
+    pub fn lookup<S: Symbol>(&self, symtab: &SymbolTable<'a, S>, name: &str) -> Option<S> {
+        let hash = gnu_hash(name.as_bytes());
+        if self.nbuckets == 0 || !self.may_contain(hash) {
+            return None;
+        }
+        let bucket = (hash % self.nbuckets) as usize * 4;
+        let mut index = util::bytes_to_u32(&self.buckets[bucket..], self.data);
+        if index < self.symoffset {
+            return None;
+        }
+        loop {
+            let offset = (index - self.symoffset) as usize * 4;
+            let chain_hash = util::bytes_to_u32(self.chains.get(offset..offset + 4)?, self.data);
+            // The low bit marks the end of the chain.
+            if chain_hash | 1 == hash | 1 {
+                let sym = symtab.get(index as usize).ok()?;
+                if matches(symtab, &sym, name) {
+                    return Some(sym);
+                }
+            }
+            if chain_hash & 1 != 0 {
+                return None;
+            }
+            index = index.checked_add(1)?;
+        }
+    }
+}
+
+/// The hash table of a dynamic symbol table, in either format.
+#[derive(Clone, Copy)]
+pub enum HashTable<'a> {
+    Sysv(SysvHashTable<'a>),
+    Gnu(GnuHashTable<'a>),
+}
+
+impl<'a> HashTable<'a> {
+    /// Get the defined symbol called `name` from `symtab`, the symbol table the hash table
+    /// belongs to.
+    pub fn lookup<S: Symbol>(&self, symtab: &SymbolTable<'a, S>, name: &str) -> Option<S> {
+        match self {
+            HashTable::Sysv(table) => table.lookup(symtab, name),
+            HashTable::Gnu(table) => table.lookup(symtab, name),
+        }
+    }
+}
@@ -19,13 +19,65 @@
 #![no_std]
 use core::fmt;
 
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod coredump;
+#[cfg(feature = "std")]
+pub mod debug_file;
+pub mod debuglink;
+pub mod dynamic;
+pub mod elf;
 pub mod elf32;
 pub mod elf64;
+pub mod file;
+pub mod hash;
+pub mod loader;
+pub mod note;
+pub mod property;
+pub mod rel;
+pub mod relative;
+pub mod relocate;
+pub mod reltype;
+pub mod stack;
+pub mod strtab;
+pub mod sym;
+pub mod symbolize;
+pub mod table;
+pub mod tls;
 pub mod types;
 mod util;
+pub mod version;
 
+pub use coredump::{
+    AuxEntry, AuxvIter, Core, FileMapping, MappedFiles, PrPsInfo, PrStatus, Registers,
+};
+#[cfg(feature = "std")]
+pub use debug_file::DebugFileLocator;
+pub use debuglink::{crc32, DebugLink};
+pub use dynamic::{Dyn, Dynamic, DynamicEntry, Elf32Dyn, Elf64Dyn};
+pub use elf::{Ehdr, Elf, Phdr, Shdr};
 pub use elf32::{Elf32, Elf32Ehdr, Elf32Phdr, Elf32Shdr};
 pub use elf64::{Elf64, Elf64Ehdr, Elf64Phdr, Elf64Shdr};
+pub use file::{ElfFile, FileHeader, ProgramHeader, SectionHeader};
+pub use hash::{gnu_hash, sysv_hash, GnuHashTable, HashTable, SysvHashTable};
+pub use loader::{LoadedImage, Loader, Region};
+pub use note::{AbiTag, Note, NoteIter};
+pub use property::{GnuProperty, GnuPropertyIter};
+pub use rel::{Elf32Rel, Elf32Rela, Elf64Rel, Elf64Rela, Reloc, Relocation, RelocationTable};
+pub use relative::RelativeRelocator;
+pub use relocate::{RelocationError, Relocator};
+pub use reltype::relocation_type_name;
+pub use stack::{AuxvBuilder, InitialStack, AUXV_CAPACITY};
+pub use strtab::StrTab;
+pub use sym::{Elf32Sym, Elf64Sym, Sym, Symbol, SymbolTable};
+pub use symbolize::{SymbolRange, Symbolizer};
+pub use table::{Entry, Table};
+pub use tls::{TlsLayout, TlsTemplate, TlsVariant};
+pub use version::{
+    compare_versions, SymbolVersion, SymbolVersions, Verdef, VerdefIter, Vernaux, Verneed,
+    VerneedIter, Versym,
+};
 
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub enum Error {
@@ -35,8 +87,34 @@ pub enum Error {
     InvalidIndex,
     /// The ELF file is loadead as a ELF32 although it is ELF64, and vice versa.
     InvalidClass,
+    /// EI_DATA is neither ELFDATA2LSB nor ELFDATA2MSB.
+    InvalidData,
     /// Failed to get header info. Probably, the file includes invalid value.
     Corrupted,
+    /// A string is not valid UTF-8.
+    InvalidUtf8,
+    /// The file does not contain the requested section, segment or entry.
+    NotFound,
+    /// A caller-provided buffer is too small.
+    BufferTooSmall,
+    /// The relocation type (or the machine) is not supported.
+    UnsupportedRelocation,
+    /// A relocated value does not fit in its field.
+    RelocationOverflow,
+    /// A relocated value does not have the alignment its field requires.
+    MisalignedRelocation,
+    /// A symbol could not be resolved.
+    UndefinedSymbol,
+    /// The address lies in the part of a segment that is not stored in the file (p_filesz to
+    /// p_memsz), which is zero-filled at load time.
+    NotFileBacked,
+    /// The file is not of the type (e_type) the operation needs, e.g. not ET_CORE.
+    InvalidFileType,
+    /// The machine (e_machine) or its class is not supported by the operation.
+    UnsupportedMachine,
+    /// An address, offset or size does not have the alignment it needs, e.g. p_vaddr and
+    /// p_offset disagree modulo p_align.
+    InvalidAlignment,
 }
 
 impl fmt::Debug for Error {
@@ -45,7 +123,19 @@ impl fmt::Debug for Error {
             Error::InvalidMagicNumber => "InvalidMagicNumber",
             Error::InvalidIndex => "InvalidIndex",
             Error::InvalidClass => "InvalidClass",
+            Error::InvalidData => "InvalidData",
             Error::Corrupted => "Corrupted",
+            Error::InvalidUtf8 => "InvalidUtf8",
+            Error::NotFound => "NotFound",
+            Error::BufferTooSmall => "BufferTooSmall",
+            Error::UnsupportedRelocation => "UnsupportedRelocation",
+            Error::RelocationOverflow => "RelocationOverflow",
+            Error::MisalignedRelocation => "MisalignedRelocation",
+            Error::UndefinedSymbol => "UndefinedSymbol",
+            Error::NotFileBacked => "NotFileBacked",
+            Error::InvalidFileType => "InvalidFileType",
+            Error::UnsupportedMachine => "UnsupportedMachine",
+            Error::InvalidAlignment => "InvalidAlignment",
         };
         f.write_fmt(format_args!("{}", name))
     }
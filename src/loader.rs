@@ -0,0 +1,207 @@
+use crate::{
+    file::{FileHeader, PhdrIter, ProgramHeader},
+    types::{FileType, PFlag, ET_DYN, ET_EXEC, PT_INTERP, PT_LOAD, PT_PHDR},
+    ElfFile, Error,
+};
+use core::{iter::Filter, marker::PhantomData, str};
+
+/// A page-aligned region of memory that holds a PT_LOAD segment.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Region {
+    /// The address the region is loaded at, with the load bias applied.
+    pub vaddr: u64,
+    pub size: u64,
+    /// The permissions of the segment.
+    pub flags: PFlag,
+}
+
+/// A loaded program image, and what is needed to start it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LoadedImage<'a> {
+    /// The difference between the loaded and the linked addresses.
+    pub bias: u64,
+    pub entry: u64,
+    /// The address of the program headers (AT_PHDR).
+    pub phdr: Option<u64>,
+    /// The path of the program interpreter (PT_INTERP), which has to be loaded as well.
+    pub interp: Option<&'a str>,
+}
+
+type LoadIter<'b, 'a, F> = Filter<PhdrIter<'b, 'a, F>, fn(&<F as ElfFile<'a>>::Phdr) -> bool>;
+
+/// Loads the PT_LOAD segments of an executable (ET_EXEC), or a position-independent
+/// executable or shared object (ET_DYN), into memory mapped by the caller.
+pub struct Loader<'b, 'a, F: ElfFile<'a>> {
+    file: &'b F,
+    page_size: u64,
+    _marker: PhantomData<&'a [u8]>,
+}
+
+impl<'b, 'a, F: ElfFile<'a>> Loader<'b, 'a, F> {
+    /// Get a Loader for `file` that maps memory in pages of `page_size` bytes, a power of two.
+    /// Returns Error::InvalidFileType unless the file is ET_EXEC or ET_DYN.
+    pub fn new(file: &'b F, page_size: u64) -> Result<Self, Error> {
+        if !matches!(FileType(file.ehdr().e_type()), ET_EXEC | ET_DYN) {
+            return Err(Error::InvalidFileType);
+        }
+        if !page_size.is_power_of_two() {
+            return Err(Error::InvalidAlignment);
+        }
+        Ok(Loader {
+            file,
+            page_size,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Get an iterator over the PT_LOAD segments that occupy memory.
+    fn loads(&self) -> LoadIter<'b, 'a, F> {
+        self.file
+            .phdr_iter()
+            .filter(|phdr| phdr.p_type() == PT_LOAD.0 && phdr.p_memsz() != 0)
+    }
+
+    /// Get the alignment the load bias of an ET_DYN file needs: the largest p_align of the
+    /// PT_LOAD segments, and at least the page size.
+    pub fn align(&self) -> u64 {
+        self.loads()
+            .map(|phdr| phdr.p_align())
+            .fold(self.page_size, u64::max)
+    }
+
+    /// Get the range of linked addresses the PT_LOAD segments occupy, rounded out to pages.
+    /// Adding the load bias gives the range of addresses to reserve.
+    ///
+    /// Returns Error::InvalidAlignment if the regions of two segments overlap, i.e. they share
+    /// a page because the file was linked for pages smaller than `page_size`, or the segments
+    /// are not sorted by address.
+    pub fn extent(&self) -> Result<(u64, u64), Error> {
+        self.loads()
+            .map(|phdr| self.region(&phdr, 0))
+            .try_fold(None, |extent: Option<(u64, u64)>, region| {
+                let region = region?;
+                let end = region.vaddr + region.size;
+                Ok(Some(match extent {
+                    Some((_, old_end)) if region.vaddr < old_end => {
+                        return Err(Error::InvalidAlignment)
+                    }
+                    Some((start, _)) => (start, end),
+                    None => (region.vaddr, end),
+                }))
+            })?
+            .ok_or(Error::NotFound)
+    }
+
+    /// Get the region of a PT_LOAD segment loaded at `bias`, after checking that the segment
+    /// is consistent with its p_align.
+    fn region(&self, phdr: &F::Phdr, bias: u64) -> Result<Region, Error> {
+        let align = phdr.p_align();
+        if align > 1 {
+            if !align.is_power_of_two() {
+                return Err(Error::InvalidAlignment);
+            }
+            if phdr.p_vaddr() % align != phdr.p_offset() % align {
+                return Err(Error::InvalidAlignment);
+            }
+        }
+        if phdr.p_filesz() > phdr.p_memsz() {
+            return Err(Error::Corrupted);
+        }
+        let mask = self.page_size - 1;
+        let start = phdr.p_vaddr().checked_add(bias).ok_or(Error::Corrupted)?;
+        let end = start
+            .checked_add(phdr.p_memsz())
+            .and_then(|end| end.checked_add(mask))
+            .ok_or(Error::Corrupted)?
+            & !mask;
+        Ok(Region {
+            vaddr: start & !mask,
+            size: end - (start & !mask),
+            flags: PFlag(phdr.p_flags()),
+        })
+    }
+
+    /// Load the PT_LOAD segments at `bias`, which must be 0 for ET_EXEC and a multiple of
+    /// align() for ET_DYN. The segments are checked as in extent() before anything is mapped.
+    ///
+    /// `map` is called for each segment, in the order of the program headers, with the region
+    /// the segment occupies. It returns the memory backing the region, at least region.size
+    /// bytes, which is then filled with the p_filesz bytes of the segment and zeroed
+    /// elsewhere. The memory has to be writable until load returns; the caller applies
+    /// region.flags afterwards.
+    pub fn load<'m, M>(&self, bias: u64, mut map: M) -> Result<LoadedImage<'a>, Error>
+    where
+        M: FnMut(&Region) -> Result<&'m mut [u8], Error>,
+    {
+        self.check_bias(bias)?;
+        // Segments that share a page would zero each other's contents.
+        self.extent()?;
+
+        for phdr in self.loads() {
+            let region = self.region(&phdr, bias)?;
+            let bytes = self.file.segment_data(&phdr)?;
+            let size = usize::try_from(region.size).map_err(|_| Error::Corrupted)?;
+            let memory = map(&region)?.get_mut(..size).ok_or(Error::BufferTooSmall)?;
+            let start = (phdr.p_vaddr() + bias - region.vaddr) as usize;
+            memory[..start].fill(0);
+            memory[start..start + bytes.len()].copy_from_slice(bytes);
+            memory[start + bytes.len()..].fill(0);
+        }
+
+        let ehdr = self.file.ehdr();
+        Ok(LoadedImage {
+            bias,
+            entry: ehdr.e_entry().wrapping_add(bias),
+            phdr: self.phdr_vaddr()?.map(|vaddr| vaddr.wrapping_add(bias)),
+            interp: self.interp()?,
+        })
+    }
+
+    /// Check that the file can be loaded at `bias`.
+    pub(crate) fn check_bias(&self, bias: u64) -> Result<(), Error> {
+        if FileType(self.file.ehdr().e_type()) == ET_EXEC && bias != 0 {
+            return Err(Error::InvalidFileType);
+        }
+        if !bias.is_multiple_of(self.align()) {
+            return Err(Error::InvalidAlignment);
+        }
+        Ok(())
+    }
+
+    /// Get the linked address of the program headers: PT_PHDR, or else the address the
+    /// PT_LOAD segment that contains e_phoff loads them at.
+    pub(crate) fn phdr_vaddr(&self) -> Result<Option<u64>, Error> {
+        if let Some(phdr) = self
+            .file
+            .phdr_iter()
+            .find(|phdr| phdr.p_type() == PT_PHDR.0)
+        {
+            return Ok(Some(phdr.p_vaddr()));
+        }
+        let phoff = self.file.ehdr().e_phoff();
+        self.loads()
+            .find(|phdr| phoff >= phdr.p_offset() && phoff - phdr.p_offset() < phdr.p_filesz())
+            .map(|phdr| {
+                phdr.p_vaddr()
+                    .checked_add(phoff - phdr.p_offset())
+                    .ok_or(Error::Corrupted)
+            })
+            .transpose()
+    }
+
+    /// Get the path of the program interpreter, up to the first NUL.
+    fn interp(&self) -> Result<Option<&'a str>, Error> {
+        let Some(phdr) = self
+            .file
+            .phdr_iter()
+            .find(|phdr| phdr.p_type() == PT_INTERP.0)
+        else {
+            return Ok(None);
+        };
+        let bytes = self.file.segment_data(&phdr)?;
+        let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+        str::from_utf8(&bytes[..len])
+            .map(Some)
+            .map_err(|_| Error::InvalidUtf8)
+    }
+}
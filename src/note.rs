@@ -0,0 +1,148 @@
+use crate::{
+    types::{Data, GnuNoteType, NoteOs, NT_GNU_ABI_TAG, NT_GNU_BUILD_ID, NT_GNU_GOLD_VERSION},
+    util, Error,
+};
+use core::{fmt, str};
+
+/// Owner name of the notes produced by the GNU toolchain.
+pub const ELF_NOTE_GNU: &[u8] = b"GNU";
+
+/// A note of a PT_NOTE segment or a SHT_NOTE section.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Note<'a> {
+    /// The owner of the note, without its terminating NUL.
+    pub name: &'a [u8],
+    /// The type of the note. Its meaning depends on `name`.
+    pub n_type: u32,
+    pub desc: &'a [u8],
+}
+
+impl<'a> Note<'a> {
+    /// Check if the note is owned by "GNU".
+    pub fn is_gnu(&self) -> bool {
+        self.name == ELF_NOTE_GNU
+    }
+
+    /// Get the type of a "GNU" note.
+    pub fn gnu_type(&self) -> Option<GnuNoteType> {
+        self.is_gnu().then_some(GnuNoteType(self.n_type))
+    }
+
+    /// Get the build ID bytes of a NT_GNU_BUILD_ID note.
+    pub fn build_id(&self) -> Result<&'a [u8], Error> {
+        if self.gnu_type() != Some(NT_GNU_BUILD_ID) {
+            return Err(Error::NotFound);
+        }
+        Ok(self.desc)
+    }
+
+    /// Get the ABI a NT_GNU_ABI_TAG note requires. The words of the descriptor are in the byte
+    /// order of the file, `data`.
+    pub fn abi_tag(&self, data: Data) -> Result<AbiTag, Error> {
+        if self.gnu_type() != Some(NT_GNU_ABI_TAG) {
+            return Err(Error::NotFound);
+        }
+        let desc = self.desc.get(..16).ok_or(Error::Corrupted)?;
+        Ok(AbiTag {
+            os: NoteOs(util::bytes_to_u32(&desc[0..4], data)),
+            major: util::bytes_to_u32(&desc[4..8], data),
+            minor: util::bytes_to_u32(&desc[8..12], data),
+            patch: util::bytes_to_u32(&desc[12..16], data),
+        })
+    }
+
+    /// Get the version string of gold held by a NT_GNU_GOLD_VERSION note.
+    pub fn gold_version(&self) -> Result<&'a str, Error> {
+        if self.gnu_type() != Some(NT_GNU_GOLD_VERSION) {
+            return Err(Error::NotFound);
+        }
+        let len = self
+            .desc
+            .iter()
+            .position(|&b| b == 0)
+            .unwrap_or(self.desc.len());
+        str::from_utf8(&self.desc[..len]).map_err(|_| Error::InvalidUtf8)
+    }
+}
+
+impl fmt::Debug for Note<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ELFNote:
+    name: {:?}
+    type: {:#x}
+    descsz: {:#x}",
+            self.name.escape_ascii(),
+            self.n_type,
+            self.desc.len()
+        ))
+    }
+}
+
+/// The contents of a NT_GNU_ABI_TAG note: the oldest kernel ABI the file runs on.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AbiTag {
+    pub os: NoteOs,
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+/// An iterator over the notes of a PT_NOTE segment or a SHT_NOTE section. It stops at the
+/// first note that does not fit in the remaining bytes.
+#[derive(Clone)]
+pub struct NoteIter<'a> {
+    bytes: &'a [u8],
+    align: usize,
+    data: Data,
+}
+
+impl<'a> NoteIter<'a> {
+    /// Get a NoteIter over `bytes`. `align` is the p_align or sh_addralign of the notes:
+    /// names and descriptors are padded to 8 bytes when it is 8, and to 4 bytes otherwise.
+    pub fn new(bytes: &'a [u8], align: u64, data: Data) -> Self {
+        NoteIter {
+            bytes,
+            align: if align == 8 { 8 } else { 4 },
+            data,
+        }
+    }
+
+    fn padded(&self, len: usize) -> Option<usize> {
+        len.checked_add(self.align - 1)
+            .map(|len| len & !(self.align - 1))
+    }
+
+    fn parse(&self) -> Option<(Note<'a>, usize)> {
+        let header = self.bytes.get(..12)?;
+        let namesz = util::bytes_to_u32(&header[0..4], self.data) as usize;
+        let descsz = util::bytes_to_u32(&header[4..8], self.data) as usize;
+        let n_type = util::bytes_to_u32(&header[8..12], self.data);
+
+        let name = self.bytes.get(12..12usize.checked_add(namesz)?)?;
+        let desc_start = self.padded(12 + namesz)?;
+        let desc_end = desc_start.checked_add(descsz)?;
+        let desc = self.bytes.get(desc_start..desc_end)?;
+        let next = self.padded(desc_end)?.min(self.bytes.len());
+
+        let name = name.strip_suffix(&[0]).unwrap_or(name);
+        Some((Note { name, n_type, desc }, next))
+    }
+}
+
+impl<'a> Iterator for NoteIter<'a> {
+    type Item = Note<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.parse() {
+            Some((note, next)) => {
+                self.bytes = &self.bytes[next..];
+                Some(note)
+            }
+            None => {
+                self.bytes = &[];
+                None
+            }
+        }
+    }
+}
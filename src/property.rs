@@ -0,0 +1,110 @@
+use crate::{
+    types::{
+        Aarch64Feature1, Class, Data, GnuPropertyType, Machine, X86Feature1, X86Isa1, ELFCLASS64,
+        EM_386, EM_AARCH64, EM_X86_64, GNU_PROPERTY_AARCH64_FEATURE_1_AND,
+        GNU_PROPERTY_NO_COPY_ON_PROTECTED, GNU_PROPERTY_STACK_SIZE, GNU_PROPERTY_X86_FEATURE_1_AND,
+        GNU_PROPERTY_X86_FEATURE_2_NEEDED, GNU_PROPERTY_X86_FEATURE_2_USED,
+        GNU_PROPERTY_X86_ISA_1_NEEDED, GNU_PROPERTY_X86_ISA_1_USED,
+    },
+    util,
+};
+
+/// A property of a NT_GNU_PROPERTY_TYPE_0 note. Processor-specific properties are decoded
+/// only for the machine that defines them, and are Unknown otherwise.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GnuProperty<'a> {
+    /// The stack size the program needs.
+    StackSize(u64),
+    NoCopyOnProtected,
+    /// Features every input object supports (the linker ANDs them).
+    X86Feature1And(X86Feature1),
+    /// Raw GNU_PROPERTY_X86_FEATURE_2_NEEDED bits.
+    X86Feature2Needed(u32),
+    /// Raw GNU_PROPERTY_X86_FEATURE_2_USED bits.
+    X86Feature2Used(u32),
+    X86Isa1Needed(X86Isa1),
+    X86Isa1Used(X86Isa1),
+    /// Features every input object supports (the linker ANDs them).
+    Aarch64Feature1And(Aarch64Feature1),
+    Unknown {
+        pr_type: GnuPropertyType,
+        data: &'a [u8],
+    },
+}
+
+/// An iterator over the properties in the descriptor of a NT_GNU_PROPERTY_TYPE_0 note. It
+/// stops at the first property that does not fit in the remaining bytes.
+#[derive(Clone)]
+pub struct GnuPropertyIter<'a> {
+    bytes: &'a [u8],
+    machine: Machine,
+    class: Class,
+    data: Data,
+}
+
+impl<'a> GnuPropertyIter<'a> {
+    /// Get a GnuPropertyIter over the descriptor `desc` of a note in a file of `machine`,
+    /// `class` and `data`. Property data are padded to 8 bytes in ELF64 and to 4 bytes in
+    /// ELF32.
+    pub fn new(desc: &'a [u8], machine: Machine, class: Class, data: Data) -> Self {
+        GnuPropertyIter {
+            bytes: desc,
+            machine,
+            class,
+            data,
+        }
+    }
+
+    fn parse(&self) -> Option<(GnuProperty<'a>, usize)> {
+        let header = self.bytes.get(..8)?;
+        let pr_type = GnuPropertyType(util::bytes_to_u32(&header[0..4], self.data));
+        let datasz = util::bytes_to_u32(&header[4..8], self.data) as usize;
+        let end = datasz.checked_add(8)?;
+        let data = self.bytes.get(8..end)?;
+
+        let align = if self.class == ELFCLASS64 { 8 } else { 4 };
+        let next = (end.checked_add(align - 1)? & !(align - 1)).min(self.bytes.len());
+        Some((self.decode(pr_type, data), next))
+    }
+
+    fn decode(&self, pr_type: GnuPropertyType, data: &'a [u8]) -> GnuProperty<'a> {
+        let word = || util::bytes_to_u32(data, self.data);
+        let x86 = self.machine == EM_X86_64 || self.machine == EM_386;
+        match (pr_type, data.len()) {
+            (GNU_PROPERTY_STACK_SIZE, 4 | 8) => {
+                GnuProperty::StackSize(util::read_uint(data, self.data))
+            }
+            (GNU_PROPERTY_NO_COPY_ON_PROTECTED, 0) => GnuProperty::NoCopyOnProtected,
+            (GNU_PROPERTY_X86_FEATURE_1_AND, 4) if x86 => {
+                GnuProperty::X86Feature1And(X86Feature1(word()))
+            }
+            (GNU_PROPERTY_X86_FEATURE_2_NEEDED, 4) if x86 => GnuProperty::X86Feature2Needed(word()),
+            (GNU_PROPERTY_X86_FEATURE_2_USED, 4) if x86 => GnuProperty::X86Feature2Used(word()),
+            (GNU_PROPERTY_X86_ISA_1_NEEDED, 4) if x86 => {
+                GnuProperty::X86Isa1Needed(X86Isa1(word()))
+            }
+            (GNU_PROPERTY_X86_ISA_1_USED, 4) if x86 => GnuProperty::X86Isa1Used(X86Isa1(word())),
+            (GNU_PROPERTY_AARCH64_FEATURE_1_AND, 4) if self.machine == EM_AARCH64 => {
+                GnuProperty::Aarch64Feature1And(Aarch64Feature1(word()))
+            }
+            _ => GnuProperty::Unknown { pr_type, data },
+        }
+    }
+}
+
+impl<'a> Iterator for GnuPropertyIter<'a> {
+    type Item = GnuProperty<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.parse() {
+            Some((property, next)) => {
+                self.bytes = &self.bytes[next..];
+                Some(property)
+            }
+            None => {
+                self.bytes = &[];
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,369 @@
+use crate::{
+    table::{Entry, Table},
+    types::{Class, Data, ELFCLASS32, ELFCLASS64},
+    util, Error,
+};
+use core::fmt;
+
+/// Accessors shared by the relocation entries of both classes. Offsets and r_info are widened
+/// to u64 and r_sym/r_type are decoded with the layout of the entry's class.
+pub trait Relocation: Entry {
+    fn r_offset(&self) -> u64;
+    fn r_info(&self) -> u64;
+
+    /// Get the explicit addend, or None for SHT_REL entries whose addend is stored at the
+    /// relocated location.
+    fn r_addend(&self) -> Option<i64>;
+
+    /// Get the index of the referenced symbol from r_info.
+    fn r_sym(&self) -> u32;
+
+    /// Get the processor-specific relocation type from r_info.
+    fn r_type(&self) -> u32;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+/// Relocation entry without an addend (SHT_REL) of ELF32.
+pub struct Elf32Rel {
+    pub r_offset: u32,
+    pub r_info: u32,
+}
+
+impl Entry for Elf32Rel {
+    fn size(_class: Class) -> usize {
+        8
+    }
+
+    fn parse(bytes: &[u8], _class: Class, data: Data) -> Result<Self, Error> {
+        let bytes = bytes.get(..8).ok_or(Error::Corrupted)?;
+        Ok(Elf32Rel {
+            r_offset: util::bytes_to_u32(&bytes[0..4], data),
+            r_info: util::bytes_to_u32(&bytes[4..8], data),
+        })
+    }
+}
+
+impl Relocation for Elf32Rel {
+    fn r_offset(&self) -> u64 {
+        self.r_offset as u64
+    }
+    fn r_info(&self) -> u64 {
+        self.r_info as u64
+    }
+    fn r_addend(&self) -> Option<i64> {
+        None
+    }
+    fn r_sym(&self) -> u32 {
+        self.r_info >> 8
+    }
+    fn r_type(&self) -> u32 {
+        self.r_info & 0xff
+    }
+}
+
+impl fmt::Debug for Elf32Rel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ELF32Rel:
+    offset: {:#x}
+    sym: {}
+    type: {:#x}",
+            self.r_offset,
+            self.r_sym(),
+            self.r_type()
+        ))
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+/// Relocation entry with an addend (SHT_RELA) of ELF32.
+pub struct Elf32Rela {
+    pub r_offset: u32,
+    pub r_info: u32,
+    pub r_addend: i32,
+}
+
+impl Entry for Elf32Rela {
+    fn size(_class: Class) -> usize {
+        12
+    }
+
+    fn parse(bytes: &[u8], _class: Class, data: Data) -> Result<Self, Error> {
+        let bytes = bytes.get(..12).ok_or(Error::Corrupted)?;
+        Ok(Elf32Rela {
+            r_offset: util::bytes_to_u32(&bytes[0..4], data),
+            r_info: util::bytes_to_u32(&bytes[4..8], data),
+            r_addend: util::bytes_to_u32(&bytes[8..12], data) as i32,
+        })
+    }
+}
+
+impl Relocation for Elf32Rela {
+    fn r_offset(&self) -> u64 {
+        self.r_offset as u64
+    }
+    fn r_info(&self) -> u64 {
+        self.r_info as u64
+    }
+    fn r_addend(&self) -> Option<i64> {
+        Some(self.r_addend as i64)
+    }
+    fn r_sym(&self) -> u32 {
+        self.r_info >> 8
+    }
+    fn r_type(&self) -> u32 {
+        self.r_info & 0xff
+    }
+}
+
+impl fmt::Debug for Elf32Rela {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ELF32Rela:
+    offset: {:#x}
+    sym: {}
+    type: {:#x}
+    addend: {:#x}",
+            self.r_offset,
+            self.r_sym(),
+            self.r_type(),
+            self.r_addend
+        ))
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+/// Relocation entry without an addend (SHT_REL) of ELF64.
+pub struct Elf64Rel {
+    pub r_offset: u64,
+    pub r_info: u64,
+}
+
+impl Entry for Elf64Rel {
+    fn size(_class: Class) -> usize {
+        16
+    }
+
+    fn parse(bytes: &[u8], _class: Class, data: Data) -> Result<Self, Error> {
+        let bytes = bytes.get(..16).ok_or(Error::Corrupted)?;
+        Ok(Elf64Rel {
+            r_offset: util::bytes_to_u64(&bytes[0..8], data),
+            r_info: util::bytes_to_u64(&bytes[8..16], data),
+        })
+    }
+}
+
+impl Relocation for Elf64Rel {
+    fn r_offset(&self) -> u64 {
+        self.r_offset
+    }
+    fn r_info(&self) -> u64 {
+        self.r_info
+    }
+    fn r_addend(&self) -> Option<i64> {
+        None
+    }
+    fn r_sym(&self) -> u32 {
+        (self.r_info >> 32) as u32
+    }
+    fn r_type(&self) -> u32 {
+        self.r_info as u32
+    }
+}
+
+impl fmt::Debug for Elf64Rel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ELF64Rel:
+    offset: {:#x}
+    sym: {}
+    type: {:#x}",
+            self.r_offset,
+            self.r_sym(),
+            self.r_type()
+        ))
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+/// Relocation entry with an addend (SHT_RELA) of ELF64.
+pub struct Elf64Rela {
+    pub r_offset: u64,
+    pub r_info: u64,
+    pub r_addend: i64,
+}
+
+impl Entry for Elf64Rela {
+    fn size(_class: Class) -> usize {
+        24
+    }
+
+    fn parse(bytes: &[u8], _class: Class, data: Data) -> Result<Self, Error> {
+        let bytes = bytes.get(..24).ok_or(Error::Corrupted)?;
+        Ok(Elf64Rela {
+            r_offset: util::bytes_to_u64(&bytes[0..8], data),
+            r_info: util::bytes_to_u64(&bytes[8..16], data),
+            r_addend: util::bytes_to_u64(&bytes[16..24], data) as i64,
+        })
+    }
+}
+
+impl Relocation for Elf64Rela {
+    fn r_offset(&self) -> u64 {
+        self.r_offset
+    }
+    fn r_info(&self) -> u64 {
+        self.r_info
+    }
+    fn r_addend(&self) -> Option<i64> {
+        Some(self.r_addend)
+    }
+    fn r_sym(&self) -> u32 {
+        (self.r_info >> 32) as u32
+    }
+    fn r_type(&self) -> u32 {
+        self.r_info as u32
+    }
+}
+
+impl fmt::Debug for Elf64Rela {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ELF64Rela:
+    offset: {:#x}
+    sym: {}
+    type: {:#x}
+    addend: {:#x}",
+            self.r_offset,
+            self.r_sym(),
+            self.r_type(),
+            self.r_addend
+        ))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+/// Relocation of either class and either section type, with r_info already decoded.
+pub struct Reloc {
+    pub r_offset: u64,
+    pub r_sym: u32,
+    pub r_type: u32,
+    /// The explicit addend of a SHT_RELA entry, or None for a SHT_REL entry.
+    pub r_addend: Option<i64>,
+}
+
+impl<R: Relocation> From<R> for Reloc {
+    fn from(rel: R) -> Self {
+        Reloc {
+            r_offset: rel.r_offset(),
+            r_sym: rel.r_sym(),
+            r_type: rel.r_type(),
+            r_addend: rel.r_addend(),
+        }
+    }
+}
+
+impl fmt::Debug for Reloc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ELFRelocation:
+    offset: {:#x}
+    sym: {}
+    type: {:#x}
+    addend: {:?}",
+            self.r_offset, self.r_sym, self.r_type, self.r_addend
+        ))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Entries<'a> {
+    Elf32Rel(Table<'a, Elf32Rel>),
+    Elf32Rela(Table<'a, Elf32Rela>),
+    Elf64Rel(Table<'a, Elf64Rel>),
+    Elf64Rela(Table<'a, Elf64Rela>),
+}
+
+/// The entries of a SHT_REL or SHT_RELA section, decoded for the class of the file.
+#[derive(Clone, Copy)]
+pub struct RelocationTable<'a> {
+    entries: Entries<'a>,
+}
+
+impl<'a> RelocationTable<'a> {
+    /// Get a RelocationTable over the contents of a relocation section. `rela` selects
+    /// SHT_RELA entries, which carry an explicit addend.
+    pub fn new(
+        bytes: &'a [u8],
+        entsize: u64,
+        rela: bool,
+        class: Class,
+        data: Data,
+    ) -> Result<Self, Error> {
+        let entries = match (class, rela) {
+            (ELFCLASS32, false) => Entries::Elf32Rel(Table::new(bytes, entsize, class, data)?),
+            (ELFCLASS32, true) => Entries::Elf32Rela(Table::new(bytes, entsize, class, data)?),
+            (ELFCLASS64, false) => Entries::Elf64Rel(Table::new(bytes, entsize, class, data)?),
+            (ELFCLASS64, true) => Entries::Elf64Rela(Table::new(bytes, entsize, class, data)?),
+            _ => return Err(Error::InvalidClass),
+        };
+        Ok(RelocationTable { entries })
+    }
+
+    /// Check if the entries carry an explicit addend.
+    pub fn is_rela(&self) -> bool {
+        matches!(self.entries, Entries::Elf32Rela(_) | Entries::Elf64Rela(_))
+    }
+
+    /// Get the number of relocations.
+    pub fn len(&self) -> usize {
+        match &self.entries {
+            Entries::Elf32Rel(table) => table.len(),
+            Entries::Elf32Rela(table) => table.len(),
+            Entries::Elf64Rel(table) => table.len(),
+            Entries::Elf64Rela(table) => table.len(),
+        }
+    }
+
+    /// Check if the table has no relocations.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get a Result<nth relocation, Error>.
+    pub fn get(&self, index: usize) -> Result<Reloc, Error> {
+        match &self.entries {
+            Entries::Elf32Rel(table) => table.get(index).map(Reloc::from),
+            Entries::Elf32Rela(table) => table.get(index).map(Reloc::from),
+            Entries::Elf64Rel(table) => table.get(index).map(Reloc::from),
+            Entries::Elf64Rela(table) => table.get(index).map(Reloc::from),
+        }
+    }
+
+    /// Get a relocation iterator.
+    pub fn iter(&self) -> RelocationIter<'a> {
+        RelocationIter {
+            index: 0,
+            table: *self,
+        }
+    }
+}
+
+pub struct RelocationIter<'a> {
+    index: usize,
+    table: RelocationTable<'a>,
+}
+
+impl Iterator for RelocationIter<'_> {
+    type Item = Reloc;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rel = self.table.get(self.index).ok()?;
+        self.index += 1;
+        Some(rel)
+    }
+}
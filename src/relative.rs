@@ -0,0 +1,232 @@
+use crate::{
+    file::ProgramHeader,
+    reltype::{R_386_RELATIVE, R_AARCH64_RELATIVE, R_RISCV_RELATIVE, R_X86_64_RELATIVE},
+    types::{
+        Class, Data, DynTag, Machine, DT_NULL, DT_REL, DT_RELA, DT_RELAENT, DT_RELASZ, DT_RELENT,
+        DT_RELR, DT_RELRENT, DT_RELRSZ, DT_RELSZ, ELFCLASS32, ELFCLASS64, EM_386, EM_AARCH64,
+        EM_RISCV, EM_X86_64, PT_DYNAMIC,
+    },
+    util, Error,
+};
+
+/// Applies the relative relocations of a position-independent image that is already in
+/// memory, the way a static-pie program or a PIE kernel relocates itself before anything else
+/// runs. It reads everything from the image and allocates nothing.
+#[derive(Clone, Copy)]
+pub struct RelativeRelocator {
+    relative: u32,
+    word: u64,
+    data: Data,
+}
+
+/// A table of relocations in the image: its address, size and entry size.
+#[derive(Clone, Copy)]
+struct RelocTable {
+    addr: u64,
+    size: u64,
+    entsize: u64,
+}
+
+impl RelativeRelocator {
+    /// Get a RelativeRelocator for images of `machine`, `class` and `data`. Returns
+    /// Error::UnsupportedRelocation unless the machine is x86_64, i386, AArch64 or RISC-V.
+    #[inline]
+    pub fn new(machine: Machine, class: Class, data: Data) -> Result<Self, Error> {
+        let word = match class {
+            ELFCLASS32 => 4,
+            ELFCLASS64 => 8,
+            _ => return Err(Error::InvalidClass),
+        };
+        let relative = match machine {
+            EM_X86_64 => R_X86_64_RELATIVE.0,
+            EM_386 => R_386_RELATIVE.0,
+            EM_AARCH64 => R_AARCH64_RELATIVE.0,
+            EM_RISCV => R_RISCV_RELATIVE.0,
+            _ => return Err(Error::UnsupportedRelocation),
+        };
+        Ok(RelativeRelocator {
+            relative,
+            word,
+            data,
+        })
+    }
+
+    /// Apply the DT_RELA, DT_REL and DT_RELR relocations of an image linked at `link_base`
+    /// and loaded at `base`, as listed by the dynamic array that the PT_DYNAMIC header of
+    /// `phdrs` points to.
+    ///
+    /// `image` holds the memory of the image from the address `link_base` on, e.g. the extent
+    /// of its PT_LOAD segments, so that the addresses in the dynamic array and the relocations
+    /// less `link_base` are offsets into it. Each relative relocation stores its addend plus
+    /// the load bias, `base - link_base`. Relocations of type 0 (R_*_NONE) are skipped, and
+    /// any other type is Error::UnsupportedRelocation, since it needs symbols. Returns
+    /// Error::NotFound if there is no PT_DYNAMIC, and Error::Corrupted if an address is
+    /// outside of `image`.
+    #[inline]
+    pub fn relocate<P, I>(
+        &self,
+        image: &mut [u8],
+        phdrs: I,
+        link_base: u64,
+        base: u64,
+    ) -> Result<(), Error>
+    where
+        P: ProgramHeader,
+        I: IntoIterator<Item = P>,
+    {
+        let dynamic = phdrs
+            .into_iter()
+            .find(|phdr| phdr.p_type() == PT_DYNAMIC.0)
+            .ok_or(Error::NotFound)?
+            .p_vaddr();
+        let mut image = Image {
+            bytes: image,
+            base: link_base,
+        };
+        let bias = base.wrapping_sub(link_base);
+
+        let mut rela = RelocTable {
+            addr: 0,
+            size: 0,
+            entsize: self.word * 3,
+        };
+        let mut rel = RelocTable {
+            entsize: self.word * 2,
+            ..rela
+        };
+        let mut relr = RelocTable {
+            entsize: self.word,
+            ..rela
+        };
+
+        let mut addr = dynamic;
+        loop {
+            let tag = self.read(&image, addr)?;
+            let val = self.read(&image, addr.wrapping_add(self.word))?;
+            // d_tag is signed, so sign-extend it from 32 bits.
+            let tag = if self.word == 4 {
+                DynTag(tag as u32 as i32 as i64)
+            } else {
+                DynTag(tag as i64)
+            };
+            match tag {
+                DT_NULL => break,
+                DT_RELA => rela.addr = val,
+                DT_RELASZ => rela.size = val,
+                DT_RELAENT => rela.entsize = val,
+                DT_REL => rel.addr = val,
+                DT_RELSZ => rel.size = val,
+                DT_RELENT => rel.entsize = val,
+                DT_RELR => relr.addr = val,
+                DT_RELRSZ => relr.size = val,
+                DT_RELRENT => relr.entsize = val,
+                _ => {}
+            }
+            addr = addr.checked_add(self.word * 2).ok_or(Error::Corrupted)?;
+        }
+
+        for entry in Self::entries(rela)? {
+            if self.is_relative(self.read(&image, entry.wrapping_add(self.word))?)? {
+                let place = self.read(&image, entry)?;
+                let addend = self.read(&image, entry.wrapping_add(self.word * 2))?;
+                self.write(&mut image, place, bias.wrapping_add(addend))?;
+            }
+        }
+        for entry in Self::entries(rel)? {
+            if self.is_relative(self.read(&image, entry.wrapping_add(self.word))?)? {
+                let place = self.read(&image, entry)?;
+                self.relocate_word(&mut image, place, bias)?;
+            }
+        }
+        self.apply_relr(&mut image, relr, bias)
+    }
+
+    /// Get the addresses of the entries of a relocation table.
+    #[inline]
+    fn entries(table: RelocTable) -> Result<impl Iterator<Item = u64>, Error> {
+        if table.size != 0 && table.entsize == 0 {
+            return Err(Error::Corrupted);
+        }
+        let count = table.size.checked_div(table.entsize).unwrap_or(0);
+        Ok((0..count).map(move |index| table.addr.wrapping_add(index * table.entsize)))
+    }
+
+    /// Check if the type in r_info is the relative relocation. R_*_NONE is not, and any other
+    /// type is Error::UnsupportedRelocation.
+    #[inline]
+    fn is_relative(&self, r_info: u64) -> Result<bool, Error> {
+        let r_type = if self.word == 4 {
+            r_info & 0xff
+        } else {
+            r_info & 0xffff_ffff
+        };
+        match r_type as u32 {
+            0 => Ok(false),
+            r_type if r_type == self.relative => Ok(true),
+            _ => Err(Error::UnsupportedRelocation),
+        }
+    }
+
+    /// Apply a DT_RELR table: an even entry is the address of a word to relocate, and an odd
+    /// entry is a bitmap of the words that follow the last one relocated.
+    #[inline]
+    fn apply_relr(&self, image: &mut Image, relr: RelocTable, bias: u64) -> Result<(), Error> {
+        let bits = self.word * 8 - 1;
+        let mut next = 0;
+        for entry in Self::entries(relr)? {
+            let entry = self.read(image, entry)?;
+            if entry & 1 == 0 {
+                self.relocate_word(image, entry, bias)?;
+                next = entry.wrapping_add(self.word);
+            } else {
+                for bit in 0..bits {
+                    if (entry >> (bit + 1)) & 1 != 0 {
+                        self.relocate_word(image, next.wrapping_add(bit * self.word), bias)?;
+                    }
+                }
+                next = next.wrapping_add(bits * self.word);
+            }
+        }
+        Ok(())
+    }
+
+    /// Add `bias` to the word at `addr`.
+    #[inline]
+    fn relocate_word(&self, image: &mut Image, addr: u64, bias: u64) -> Result<(), Error> {
+        let value = self.read(image, addr)?;
+        self.write(image, addr, bias.wrapping_add(value))
+    }
+
+    #[inline]
+    fn read(&self, image: &Image, addr: u64) -> Result<u64, Error> {
+        Ok(util::read_uint(
+            util::slice(image.bytes, image.offset(addr)?, self.word)?,
+            self.data,
+        ))
+    }
+
+    #[inline]
+    fn write(&self, image: &mut Image, addr: u64, value: u64) -> Result<(), Error> {
+        let start = usize::try_from(image.offset(addr)?).map_err(|_| Error::Corrupted)?;
+        let bytes = start
+            .checked_add(self.word as usize)
+            .and_then(|end| image.bytes.get_mut(start..end))
+            .ok_or(Error::Corrupted)?;
+        util::write_uint(bytes, value, self.data);
+        Ok(())
+    }
+}
+
+/// The memory of an image, from its linked address `base` on.
+struct Image<'i> {
+    bytes: &'i mut [u8],
+    base: u64,
+}
+
+impl Image<'_> {
+    /// Get the offset of the linked address `addr` in the image.
+    #[inline]
+    fn offset(&self, addr: u64) -> Result<u64, Error> {
+        addr.checked_sub(self.base).ok_or(Error::Corrupted)
+    }
+}
@@ -0,0 +1,546 @@
+use crate::{
+    file::{ElfFile, FileHeader},
+    reltype::*,
+    types::{
+        Data, Machine, ELFDATA2LSB, EM_AARCH64, EM_RISCV, EM_X86_64, SHN_ABS, SHN_LORESERVE,
+        SHN_UNDEF,
+    },
+    util, Error, Reloc, RelocationTable, Symbol, SymbolTable,
+};
+use core::fmt;
+
+/// A relocation that could not be applied, and why.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct RelocationError {
+    pub reloc: Reloc,
+    pub error: Error,
+}
+
+impl fmt::Debug for RelocationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "RelocationError:
+    offset: {:#x}
+    sym: {}
+    type: {:#x}
+    error: {:?}",
+            self.reloc.r_offset, self.reloc.r_sym, self.reloc.r_type, self.error
+        ))
+    }
+}
+
+/// Applies the relocations of one SHT_REL or SHT_RELA section of a relocatable object (ET_REL)
+/// for x86_64, AArch64 or RISC-V.
+pub struct Relocator<'a, S: Symbol> {
+    machine: Machine,
+    data: Data,
+    relocs: RelocationTable<'a>,
+    symtab: SymbolTable<'a, S>,
+}
+
+impl<'a, S: Symbol> Relocator<'a, S> {
+    /// Get a Relocator for a relocation section of `file`.
+    pub fn new<F: ElfFile<'a, Sym = S>>(file: &F, shdr: &F::Shdr) -> Result<Self, Error> {
+        let machine = Machine(file.ehdr().e_machine());
+        if !matches!(machine, EM_X86_64 | EM_AARCH64 | EM_RISCV) {
+            return Err(Error::UnsupportedRelocation);
+        }
+        Ok(Relocator {
+            machine,
+            data: file.data(),
+            relocs: file.relocation_table(shdr)?,
+            symtab: file.relocation_symtab(shdr)?,
+        })
+    }
+
+    /// Get the relocations to apply.
+    pub fn relocations(&self) -> RelocationTable<'a> {
+        self.relocs
+    }
+
+    /// Apply the relocations to `target`, the contents of the section they apply to, loaded at
+    /// `target_addr`.
+    ///
+    /// Symbols defined in a section are relocated through `section_addr`, which gets the load
+    /// address of a section by its index. Undefined, common and other special symbols are
+    /// resolved by name through `resolve`.
+    pub fn apply<A, R>(
+        &self,
+        target: &mut [u8],
+        target_addr: u64,
+        mut section_addr: A,
+        mut resolve: R,
+    ) -> Result<(), RelocationError>
+    where
+        A: FnMut(u32) -> Option<u64>,
+        R: FnMut(&S, &'a str) -> Option<u64>,
+    {
+        for reloc in self.relocs.iter() {
+            self.apply_one(&reloc, target, target_addr, &mut section_addr, &mut resolve)
+                .map_err(|error| RelocationError { reloc, error })?;
+        }
+        Ok(())
+    }
+
+    fn apply_one<A, R>(
+        &self,
+        reloc: &Reloc,
+        target: &mut [u8],
+        target_addr: u64,
+        section_addr: &mut A,
+        resolve: &mut R,
+    ) -> Result<(), Error>
+    where
+        A: FnMut(u32) -> Option<u64>,
+        R: FnMut(&S, &'a str) -> Option<u64>,
+    {
+        let offset = usize::try_from(reloc.r_offset).map_err(|_| Error::Corrupted)?;
+        let mut place = Place {
+            bytes: target.get_mut(offset..).ok_or(Error::Corrupted)?,
+            data: self.data,
+        };
+        let p = target_addr.wrapping_add(reloc.r_offset);
+        let s = self.symbol_addr(reloc.r_sym, section_addr, resolve)?;
+
+        match self.machine {
+            EM_X86_64 => x86_64(reloc, s, p, &mut place),
+            EM_AARCH64 => aarch64(reloc, s, p, &mut place),
+            _ => match RiscvRelType(reloc.r_type) {
+                // The symbol marks the auipc holding the high part; the low part is taken from
+                // the PC-relative value computed there.
+                R_RISCV_PCREL_LO12_I | R_RISCV_PCREL_LO12_S => {
+                    let auipc = s.wrapping_add(explicit_addend(reloc)? as u64);
+                    let value = self.pcrel_hi20(auipc, target_addr, section_addr, resolve)?;
+                    let r_type = if reloc.r_type == R_RISCV_PCREL_LO12_I.0 {
+                        R_RISCV_LO12_I
+                    } else {
+                        R_RISCV_LO12_S
+                    };
+                    let lo12 = Reloc {
+                        r_type: r_type.0,
+                        r_addend: Some(0),
+                        ..*reloc
+                    };
+                    riscv(&lo12, value, p, &mut place)
+                }
+                _ => riscv(reloc, s, p, &mut place),
+            },
+        }
+    }
+
+    /// Get the value of the symbol a relocation refers to. Symbol 0 has value 0.
+    fn symbol_addr<A, R>(
+        &self,
+        r_sym: u32,
+        section_addr: &mut A,
+        resolve: &mut R,
+    ) -> Result<u64, Error>
+    where
+        A: FnMut(u32) -> Option<u64>,
+        R: FnMut(&S, &'a str) -> Option<u64>,
+    {
+        if r_sym == 0 {
+            return Ok(0);
+        }
+        let sym = self.symtab.get(r_sym as usize)?;
+        match sym.section_index() {
+            SHN_ABS => Ok(sym.st_value()),
+            shndx if shndx == SHN_UNDEF || shndx.0 >= SHN_LORESERVE.0 => {
+                resolve(&sym, self.symtab.name(&sym)?).ok_or(Error::UndefinedSymbol)
+            }
+            shndx => section_addr(shndx.0 as u32)
+                .map(|addr| addr.wrapping_add(sym.st_value()))
+                .ok_or(Error::NotFound),
+        }
+    }
+
+    /// Get the S + A - P value of the R_RISCV_PCREL_HI20 relocation at `addr`.
+    fn pcrel_hi20<A, R>(
+        &self,
+        addr: u64,
+        target_addr: u64,
+        section_addr: &mut A,
+        resolve: &mut R,
+    ) -> Result<u64, Error>
+    where
+        A: FnMut(u32) -> Option<u64>,
+        R: FnMut(&S, &'a str) -> Option<u64>,
+    {
+        let hi20 = self
+            .relocs
+            .iter()
+            .find(|hi20| {
+                hi20.r_type == R_RISCV_PCREL_HI20.0
+                    && target_addr.wrapping_add(hi20.r_offset) == addr
+            })
+            .ok_or(Error::NotFound)?;
+        let s = self.symbol_addr(hi20.r_sym, section_addr, resolve)?;
+        Ok(s.wrapping_add(explicit_addend(&hi20)? as u64)
+            .wrapping_sub(addr))
+    }
+}
+
+/// The bytes from the relocated location to the end of the target section.
+struct Place<'b> {
+    bytes: &'b mut [u8],
+    data: Data,
+}
+
+impl Place<'_> {
+    fn read(&self, size: usize) -> Result<u64, Error> {
+        let bytes = self.bytes.get(..size).ok_or(Error::Corrupted)?;
+        Ok(util::read_uint(bytes, self.data))
+    }
+
+    fn write(&mut self, size: usize, value: u64) -> Result<(), Error> {
+        let bytes = self.bytes.get_mut(..size).ok_or(Error::Corrupted)?;
+        util::write_uint(bytes, value, self.data);
+        Ok(())
+    }
+
+    /// Read an instruction of `size` bytes at `offset`. AArch64 and RISC-V instructions are
+    /// little-endian whatever the data encoding.
+    fn insn(&self, offset: usize, size: usize) -> Result<u32, Error> {
+        let bytes = self
+            .bytes
+            .get(offset..offset + size)
+            .ok_or(Error::Corrupted)?;
+        Ok(util::read_uint(bytes, ELFDATA2LSB) as u32)
+    }
+
+    fn set_insn(&mut self, offset: usize, size: usize, insn: u32) -> Result<(), Error> {
+        let bytes = self
+            .bytes
+            .get_mut(offset..offset + size)
+            .ok_or(Error::Corrupted)?;
+        util::write_uint(bytes, insn as u64, ELFDATA2LSB);
+        Ok(())
+    }
+}
+
+/// The range a value written to a data field of `size` bytes must fit in.
+#[derive(Clone, Copy)]
+enum Check {
+    None,
+    Signed,
+    Unsigned,
+    /// Either a signed or an unsigned value of the field size.
+    Either,
+}
+
+/// Write `value` to a data field of `size` bytes after checking it fits.
+fn write_data(place: &mut Place, size: usize, value: u64, check: Check) -> Result<(), Error> {
+    let bits = size as u32 * 8;
+    match check {
+        Check::None => {}
+        Check::Signed => check_signed(value as i64, bits)?,
+        Check::Unsigned => check_unsigned(value, bits)?,
+        Check::Either => {
+            let value = value as i64;
+            if bits < 64 && (value < -(1 << (bits - 1)) || value >= 1 << bits) {
+                return Err(Error::RelocationOverflow);
+            }
+        }
+    }
+    place.write(size, value)
+}
+
+fn check_signed(value: i64, bits: u32) -> Result<(), Error> {
+    let min = -(1i64 << (bits - 1));
+    let max = (1i64 << (bits - 1)) - 1;
+    if value < min || value > max {
+        return Err(Error::RelocationOverflow);
+    }
+    Ok(())
+}
+
+fn check_unsigned(value: u64, bits: u32) -> Result<(), Error> {
+    if bits < 64 && value >> bits != 0 {
+        return Err(Error::RelocationOverflow);
+    }
+    Ok(())
+}
+
+fn check_aligned(value: u64, align: u64) -> Result<(), Error> {
+    if value & (align - 1) != 0 {
+        return Err(Error::MisalignedRelocation);
+    }
+    Ok(())
+}
+
+/// Get the addend of a data relocation. A SHT_REL entry keeps it in the relocated field.
+fn data_addend(reloc: &Reloc, place: &Place, size: usize) -> Result<i64, Error> {
+    match reloc.r_addend {
+        Some(addend) => Ok(addend),
+        None => {
+            let shift = 64 - size as u32 * 8;
+            Ok(((place.read(size)? << shift) as i64) >> shift)
+        }
+    }
+}
+
+/// Get the addend of a relocation whose field is not a plain integer, which only SHT_RELA
+/// entries can carry.
+fn explicit_addend(reloc: &Reloc) -> Result<i64, Error> {
+    reloc.r_addend.ok_or(Error::UnsupportedRelocation)
+}
+
+/// Replace `width` bits of `insn` starting at bit `shift` with the low bits of `value`.
+fn set_bits(insn: u32, shift: u32, width: u32, value: u64) -> u32 {
+    let mask = ((1u32 << width) - 1) << shift;
+    (insn & !mask) | (((value as u32) << shift) & mask)
+}
+
+fn x86_64(reloc: &Reloc, s: u64, p: u64, place: &mut Place) -> Result<(), Error> {
+    let (size, pcrel, check) = match X86_64RelType(reloc.r_type) {
+        R_X86_64_NONE => return Ok(()),
+        R_X86_64_64 => (8, false, Check::None),
+        R_X86_64_PC64 => (8, true, Check::None),
+        R_X86_64_32 => (4, false, Check::Unsigned),
+        R_X86_64_32S => (4, false, Check::Signed),
+        R_X86_64_PC32 | R_X86_64_PLT32 => (4, true, Check::Signed),
+        R_X86_64_16 => (2, false, Check::Either),
+        R_X86_64_PC16 => (2, true, Check::Signed),
+        R_X86_64_8 => (1, false, Check::Either),
+        R_X86_64_PC8 => (1, true, Check::Signed),
+        _ => return Err(Error::UnsupportedRelocation),
+    };
+    let value = s.wrapping_add(data_addend(reloc, place, size)? as u64);
+    let value = if pcrel { value.wrapping_sub(p) } else { value };
+    write_data(place, size, value, check)
+}
+
+fn aarch64(reloc: &Reloc, s: u64, p: u64, place: &mut Place) -> Result<(), Error> {
+    let r_type = Aarch64RelType(reloc.r_type);
+    let data = match r_type {
+        R_AARCH64_NONE | R_AARCH64_NONE_WITHDRAWN => return Ok(()),
+        R_AARCH64_ABS64 => Some((8, false, Check::None)),
+        R_AARCH64_ABS32 => Some((4, false, Check::Either)),
+        R_AARCH64_ABS16 => Some((2, false, Check::Either)),
+        R_AARCH64_PREL64 => Some((8, true, Check::None)),
+        R_AARCH64_PREL32 => Some((4, true, Check::Either)),
+        R_AARCH64_PREL16 => Some((2, true, Check::Either)),
+        _ => None,
+    };
+    if let Some((size, pcrel, check)) = data {
+        let value = s.wrapping_add(data_addend(reloc, place, size)? as u64);
+        let value = if pcrel { value.wrapping_sub(p) } else { value };
+        return write_data(place, size, value, check);
+    }
+
+    let value = s.wrapping_add(explicit_addend(reloc)? as u64);
+    let rel = value.wrapping_sub(p);
+    let insn = place.insn(0, 4)?;
+    let insn = match r_type {
+        R_AARCH64_MOVW_UABS_G0
+        | R_AARCH64_MOVW_UABS_G0_NC
+        | R_AARCH64_MOVW_UABS_G1
+        | R_AARCH64_MOVW_UABS_G1_NC
+        | R_AARCH64_MOVW_UABS_G2
+        | R_AARCH64_MOVW_UABS_G2_NC
+        | R_AARCH64_MOVW_UABS_G3 => {
+            let (shift, checked) = match r_type {
+                R_AARCH64_MOVW_UABS_G0 => (0, true),
+                R_AARCH64_MOVW_UABS_G0_NC => (0, false),
+                R_AARCH64_MOVW_UABS_G1 => (16, true),
+                R_AARCH64_MOVW_UABS_G1_NC => (16, false),
+                R_AARCH64_MOVW_UABS_G2 => (32, true),
+                R_AARCH64_MOVW_UABS_G2_NC => (32, false),
+                _ => (48, false),
+            };
+            if checked {
+                check_unsigned(value, shift + 16)?;
+            }
+            set_bits(insn, 5, 16, value >> shift)
+        }
+        R_AARCH64_LD_PREL_LO19 | R_AARCH64_CONDBR19 => {
+            check_signed(rel as i64, 21)?;
+            check_aligned(rel, 4)?;
+            set_bits(insn, 5, 19, rel >> 2)
+        }
+        R_AARCH64_TSTBR14 => {
+            check_signed(rel as i64, 16)?;
+            check_aligned(rel, 4)?;
+            set_bits(insn, 5, 14, rel >> 2)
+        }
+        R_AARCH64_JUMP26 | R_AARCH64_CALL26 => {
+            check_signed(rel as i64, 28)?;
+            check_aligned(rel, 4)?;
+            set_bits(insn, 0, 26, rel >> 2)
+        }
+        R_AARCH64_ADR_PREL_LO21 => {
+            check_signed(rel as i64, 21)?;
+            adr(insn, rel)
+        }
+        R_AARCH64_ADR_PREL_PG_HI21 | R_AARCH64_ADR_PREL_PG_HI21_NC => {
+            let pages = (value & !0xfff).wrapping_sub(p & !0xfff);
+            if r_type == R_AARCH64_ADR_PREL_PG_HI21 {
+                check_signed(pages as i64, 33)?;
+            }
+            adr(insn, pages >> 12)
+        }
+        R_AARCH64_ADD_ABS_LO12_NC => set_bits(insn, 10, 12, value & 0xfff),
+        R_AARCH64_LDST8_ABS_LO12_NC => ldst(insn, value, 0)?,
+        R_AARCH64_LDST16_ABS_LO12_NC => ldst(insn, value, 1)?,
+        R_AARCH64_LDST32_ABS_LO12_NC => ldst(insn, value, 2)?,
+        R_AARCH64_LDST64_ABS_LO12_NC => ldst(insn, value, 3)?,
+        R_AARCH64_LDST128_ABS_LO12_NC => ldst(insn, value, 4)?,
+        _ => return Err(Error::UnsupportedRelocation),
+    };
+    place.set_insn(0, 4, insn)
+}
+
+/// Set the 21-bit immediate of an ADR/ADRP instruction.
+fn adr(insn: u32, imm: u64) -> u32 {
+    set_bits(set_bits(insn, 29, 2, imm), 5, 19, imm >> 2)
+}
+
+/// Set the scaled 12-bit offset of a load/store accessing `1 << scale` bytes.
+fn ldst(insn: u32, value: u64, scale: u32) -> Result<u32, Error> {
+    let lo12 = value & 0xfff;
+    check_aligned(lo12, 1 << scale)?;
+    Ok(set_bits(insn, 10, 12, lo12 >> scale))
+}
+
+fn riscv(reloc: &Reloc, s: u64, p: u64, place: &mut Place) -> Result<(), Error> {
+    let r_type = RiscvRelType(reloc.r_type);
+    match r_type {
+        // The engine does not relax, so the instructions stay as they were assembled.
+        R_RISCV_NONE | R_RISCV_RELAX => return Ok(()),
+        R_RISCV_32 | R_RISCV_64 | R_RISCV_32_PCREL => {
+            let (size, check) = match r_type {
+                R_RISCV_32 => (4, Check::Either),
+                R_RISCV_64 => (8, Check::None),
+                _ => (4, Check::Signed),
+            };
+            let value = s.wrapping_add(data_addend(reloc, place, size)? as u64);
+            let value = if r_type == R_RISCV_32_PCREL {
+                value.wrapping_sub(p)
+            } else {
+                value
+            };
+            return write_data(place, size, value, check);
+        }
+        _ => {}
+    }
+
+    let value = s.wrapping_add(explicit_addend(reloc)? as u64);
+    let rel = value.wrapping_sub(p);
+    match r_type {
+        R_RISCV_ADD8 | R_RISCV_ADD16 | R_RISCV_ADD32 | R_RISCV_ADD64 => {
+            let size = riscv_field_size(r_type);
+            let field = place.read(size)?;
+            place.write(size, field.wrapping_add(value))
+        }
+        R_RISCV_SUB8 | R_RISCV_SUB16 | R_RISCV_SUB32 | R_RISCV_SUB64 => {
+            let size = riscv_field_size(r_type);
+            let field = place.read(size)?;
+            place.write(size, field.wrapping_sub(value))
+        }
+        R_RISCV_SET8 | R_RISCV_SET16 | R_RISCV_SET32 => {
+            place.write(riscv_field_size(r_type), value)
+        }
+        R_RISCV_SET6 => {
+            let field = place.read(1)?;
+            place.write(1, (field & 0xc0) | (value & 0x3f))
+        }
+        R_RISCV_SUB6 => {
+            let field = place.read(1)?;
+            place.write(1, (field & 0xc0) | (field.wrapping_sub(value) & 0x3f))
+        }
+        R_RISCV_BRANCH => {
+            check_signed(rel as i64, 13)?;
+            check_aligned(rel, 2)?;
+            let insn = place.insn(0, 4)? & 0x01fff07f;
+            let imm = ((rel >> 12) & 1) << 31
+                | ((rel >> 5) & 0x3f) << 25
+                | ((rel >> 1) & 0xf) << 8
+                | ((rel >> 11) & 1) << 7;
+            place.set_insn(0, 4, insn | imm as u32)
+        }
+        R_RISCV_JAL => {
+            check_signed(rel as i64, 21)?;
+            check_aligned(rel, 2)?;
+            let insn = place.insn(0, 4)? & 0xfff;
+            let imm = ((rel >> 20) & 1) << 31
+                | ((rel >> 1) & 0x3ff) << 21
+                | ((rel >> 11) & 1) << 20
+                | ((rel >> 12) & 0xff) << 12;
+            place.set_insn(0, 4, insn | imm as u32)
+        }
+        R_RISCV_RVC_BRANCH => {
+            check_signed(rel as i64, 9)?;
+            check_aligned(rel, 2)?;
+            let insn = place.insn(0, 2)? & 0xe383;
+            let imm = ((rel >> 8) & 1) << 12
+                | ((rel >> 3) & 3) << 10
+                | ((rel >> 6) & 3) << 5
+                | ((rel >> 1) & 3) << 3
+                | ((rel >> 5) & 1) << 2;
+            place.set_insn(0, 2, insn | imm as u32)
+        }
+        R_RISCV_RVC_JUMP => {
+            check_signed(rel as i64, 12)?;
+            check_aligned(rel, 2)?;
+            let insn = place.insn(0, 2)? & 0xe003;
+            let imm = ((rel >> 11) & 1) << 12
+                | ((rel >> 4) & 1) << 11
+                | ((rel >> 8) & 3) << 9
+                | ((rel >> 10) & 1) << 8
+                | ((rel >> 6) & 1) << 7
+                | ((rel >> 7) & 1) << 6
+                | ((rel >> 1) & 7) << 3
+                | ((rel >> 5) & 1) << 2;
+            place.set_insn(0, 2, insn | imm as u32)
+        }
+        R_RISCV_CALL | R_RISCV_CALL_PLT => {
+            check_signed((rel as i64).wrapping_add(0x800), 32)?;
+            let auipc = utype(place.insn(0, 4)?, rel);
+            let jalr = itype(place.insn(4, 4)?, rel);
+            place.set_insn(0, 4, auipc)?;
+            place.set_insn(4, 4, jalr)
+        }
+        R_RISCV_PCREL_HI20 => {
+            check_signed((rel as i64).wrapping_add(0x800), 32)?;
+            let insn = utype(place.insn(0, 4)?, rel);
+            place.set_insn(0, 4, insn)
+        }
+        R_RISCV_HI20 => {
+            check_signed((value as i64).wrapping_add(0x800), 32)?;
+            let insn = utype(place.insn(0, 4)?, value);
+            place.set_insn(0, 4, insn)
+        }
+        R_RISCV_LO12_I => {
+            let insn = itype(place.insn(0, 4)?, value);
+            place.set_insn(0, 4, insn)
+        }
+        R_RISCV_LO12_S => {
+            let insn = place.insn(0, 4)? & 0x01fff07f;
+            let imm = (value & 0x1f) << 7 | ((value >> 5) & 0x7f) << 25;
+            place.set_insn(0, 4, insn | imm as u32)
+        }
+        _ => Err(Error::UnsupportedRelocation),
+    }
+}
+
+/// Get the size of the field updated by an R_RISCV_ADD*, R_RISCV_SUB* or R_RISCV_SET*
+/// relocation.
+fn riscv_field_size(r_type: RiscvRelType) -> usize {
+    match r_type {
+        R_RISCV_ADD8 | R_RISCV_SUB8 | R_RISCV_SET8 => 1,
+        R_RISCV_ADD16 | R_RISCV_SUB16 | R_RISCV_SET16 => 2,
+        R_RISCV_ADD32 | R_RISCV_SUB32 | R_RISCV_SET32 => 4,
+        _ => 8,
+    }
+}
+
+/// Set the upper 20 bits of a U-type instruction, rounded so that adding the sign-extended low
+/// 12 bits gives back `value`.
+fn utype(insn: u32, value: u64) -> u32 {
+    (insn & 0xfff) | ((value as u32).wrapping_add(0x800) & 0xfffff000)
+}
+
+/// Set the 12-bit immediate of an I-type instruction.
+fn itype(insn: u32, value: u64) -> u32 {
+    set_bits(insn, 20, 12, value)
+}
@@ -0,0 +1,1451 @@
+//! Processor-specific relocation types. The same r_type value names a different relocation on
+//! every architecture, so each one gets its own newtype.
+
+use crate::types::{
+    Machine, EM_386, EM_AARCH64, EM_ARM, EM_MIPS, EM_MIPS_RS3_LE, EM_PPC64, EM_RISCV, EM_S390,
+    EM_X86_64,
+};
+use core::fmt;
+
+/// Relocation type of x86-64 (EM_X86_64).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct X86_64RelType(pub u32);
+
+pub const R_X86_64_NONE: X86_64RelType = X86_64RelType(0);
+pub const R_X86_64_64: X86_64RelType = X86_64RelType(1);
+pub const R_X86_64_PC32: X86_64RelType = X86_64RelType(2);
+pub const R_X86_64_GOT32: X86_64RelType = X86_64RelType(3);
+pub const R_X86_64_PLT32: X86_64RelType = X86_64RelType(4);
+pub const R_X86_64_COPY: X86_64RelType = X86_64RelType(5);
+pub const R_X86_64_GLOB_DAT: X86_64RelType = X86_64RelType(6);
+pub const R_X86_64_JUMP_SLOT: X86_64RelType = X86_64RelType(7);
+pub const R_X86_64_RELATIVE: X86_64RelType = X86_64RelType(8);
+pub const R_X86_64_GOTPCREL: X86_64RelType = X86_64RelType(9);
+pub const R_X86_64_32: X86_64RelType = X86_64RelType(10);
+pub const R_X86_64_32S: X86_64RelType = X86_64RelType(11);
+pub const R_X86_64_16: X86_64RelType = X86_64RelType(12);
+pub const R_X86_64_PC16: X86_64RelType = X86_64RelType(13);
+pub const R_X86_64_8: X86_64RelType = X86_64RelType(14);
+pub const R_X86_64_PC8: X86_64RelType = X86_64RelType(15);
+pub const R_X86_64_DTPMOD64: X86_64RelType = X86_64RelType(16);
+pub const R_X86_64_DTPOFF64: X86_64RelType = X86_64RelType(17);
+pub const R_X86_64_TPOFF64: X86_64RelType = X86_64RelType(18);
+pub const R_X86_64_TLSGD: X86_64RelType = X86_64RelType(19);
+pub const R_X86_64_TLSLD: X86_64RelType = X86_64RelType(20);
+pub const R_X86_64_DTPOFF32: X86_64RelType = X86_64RelType(21);
+pub const R_X86_64_GOTTPOFF: X86_64RelType = X86_64RelType(22);
+pub const R_X86_64_TPOFF32: X86_64RelType = X86_64RelType(23);
+pub const R_X86_64_PC64: X86_64RelType = X86_64RelType(24);
+pub const R_X86_64_GOTOFF64: X86_64RelType = X86_64RelType(25);
+pub const R_X86_64_GOTPC32: X86_64RelType = X86_64RelType(26);
+pub const R_X86_64_GOT64: X86_64RelType = X86_64RelType(27);
+pub const R_X86_64_GOTPCREL64: X86_64RelType = X86_64RelType(28);
+pub const R_X86_64_GOTPC64: X86_64RelType = X86_64RelType(29);
+pub const R_X86_64_GOTPLT64: X86_64RelType = X86_64RelType(30);
+pub const R_X86_64_PLTOFF64: X86_64RelType = X86_64RelType(31);
+pub const R_X86_64_SIZE32: X86_64RelType = X86_64RelType(32);
+pub const R_X86_64_SIZE64: X86_64RelType = X86_64RelType(33);
+pub const R_X86_64_GOTPC32_TLSDESC: X86_64RelType = X86_64RelType(34);
+pub const R_X86_64_TLSDESC_CALL: X86_64RelType = X86_64RelType(35);
+pub const R_X86_64_TLSDESC: X86_64RelType = X86_64RelType(36);
+pub const R_X86_64_IRELATIVE: X86_64RelType = X86_64RelType(37);
+pub const R_X86_64_RELATIVE64: X86_64RelType = X86_64RelType(38);
+pub const R_X86_64_GOTPCRELX: X86_64RelType = X86_64RelType(41);
+pub const R_X86_64_REX_GOTPCRELX: X86_64RelType = X86_64RelType(42);
+
+impl X86_64RelType {
+    /// Get the name of the relocation type, or None if it is unknown.
+    pub fn name(&self) -> Option<&'static str> {
+        let name = match self.0 {
+            0 => "R_X86_64_NONE",
+            1 => "R_X86_64_64",
+            2 => "R_X86_64_PC32",
+            3 => "R_X86_64_GOT32",
+            4 => "R_X86_64_PLT32",
+            5 => "R_X86_64_COPY",
+            6 => "R_X86_64_GLOB_DAT",
+            7 => "R_X86_64_JUMP_SLOT",
+            8 => "R_X86_64_RELATIVE",
+            9 => "R_X86_64_GOTPCREL",
+            10 => "R_X86_64_32",
+            11 => "R_X86_64_32S",
+            12 => "R_X86_64_16",
+            13 => "R_X86_64_PC16",
+            14 => "R_X86_64_8",
+            15 => "R_X86_64_PC8",
+            16 => "R_X86_64_DTPMOD64",
+            17 => "R_X86_64_DTPOFF64",
+            18 => "R_X86_64_TPOFF64",
+            19 => "R_X86_64_TLSGD",
+            20 => "R_X86_64_TLSLD",
+            21 => "R_X86_64_DTPOFF32",
+            22 => "R_X86_64_GOTTPOFF",
+            23 => "R_X86_64_TPOFF32",
+            24 => "R_X86_64_PC64",
+            25 => "R_X86_64_GOTOFF64",
+            26 => "R_X86_64_GOTPC32",
+            27 => "R_X86_64_GOT64",
+            28 => "R_X86_64_GOTPCREL64",
+            29 => "R_X86_64_GOTPC64",
+            30 => "R_X86_64_GOTPLT64",
+            31 => "R_X86_64_PLTOFF64",
+            32 => "R_X86_64_SIZE32",
+            33 => "R_X86_64_SIZE64",
+            34 => "R_X86_64_GOTPC32_TLSDESC",
+            35 => "R_X86_64_TLSDESC_CALL",
+            36 => "R_X86_64_TLSDESC",
+            37 => "R_X86_64_IRELATIVE",
+            38 => "R_X86_64_RELATIVE64",
+            41 => "R_X86_64_GOTPCRELX",
+            42 => "R_X86_64_REX_GOTPCRELX",
+            _ => return None,
+        };
+        Some(name)
+    }
+}
+
+impl fmt::Debug for X86_64RelType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!("{}", self.name().unwrap_or("UNKNOWN")))
+    }
+}
+
+/// Relocation type of i386 (EM_386).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct I386RelType(pub u32);
+
+pub const R_386_NONE: I386RelType = I386RelType(0);
+pub const R_386_32: I386RelType = I386RelType(1);
+pub const R_386_PC32: I386RelType = I386RelType(2);
+pub const R_386_GOT32: I386RelType = I386RelType(3);
+pub const R_386_PLT32: I386RelType = I386RelType(4);
+pub const R_386_COPY: I386RelType = I386RelType(5);
+pub const R_386_GLOB_DAT: I386RelType = I386RelType(6);
+pub const R_386_JMP_SLOT: I386RelType = I386RelType(7);
+pub const R_386_RELATIVE: I386RelType = I386RelType(8);
+pub const R_386_GOTOFF: I386RelType = I386RelType(9);
+pub const R_386_GOTPC: I386RelType = I386RelType(10);
+pub const R_386_32PLT: I386RelType = I386RelType(11);
+pub const R_386_TLS_TPOFF: I386RelType = I386RelType(14);
+pub const R_386_TLS_IE: I386RelType = I386RelType(15);
+pub const R_386_TLS_GOTIE: I386RelType = I386RelType(16);
+pub const R_386_TLS_LE: I386RelType = I386RelType(17);
+pub const R_386_TLS_GD: I386RelType = I386RelType(18);
+pub const R_386_TLS_LDM: I386RelType = I386RelType(19);
+pub const R_386_16: I386RelType = I386RelType(20);
+pub const R_386_PC16: I386RelType = I386RelType(21);
+pub const R_386_8: I386RelType = I386RelType(22);
+pub const R_386_PC8: I386RelType = I386RelType(23);
+pub const R_386_TLS_GD_32: I386RelType = I386RelType(24);
+pub const R_386_TLS_GD_PUSH: I386RelType = I386RelType(25);
+pub const R_386_TLS_GD_CALL: I386RelType = I386RelType(26);
+pub const R_386_TLS_GD_POP: I386RelType = I386RelType(27);
+pub const R_386_TLS_LDM_32: I386RelType = I386RelType(28);
+pub const R_386_TLS_LDM_PUSH: I386RelType = I386RelType(29);
+pub const R_386_TLS_LDM_CALL: I386RelType = I386RelType(30);
+pub const R_386_TLS_LDM_POP: I386RelType = I386RelType(31);
+pub const R_386_TLS_LDO_32: I386RelType = I386RelType(32);
+pub const R_386_TLS_IE_32: I386RelType = I386RelType(33);
+pub const R_386_TLS_LE_32: I386RelType = I386RelType(34);
+pub const R_386_TLS_DTPMOD32: I386RelType = I386RelType(35);
+pub const R_386_TLS_DTPOFF32: I386RelType = I386RelType(36);
+pub const R_386_TLS_TPOFF32: I386RelType = I386RelType(37);
+pub const R_386_SIZE32: I386RelType = I386RelType(38);
+pub const R_386_TLS_GOTDESC: I386RelType = I386RelType(39);
+pub const R_386_TLS_DESC_CALL: I386RelType = I386RelType(40);
+pub const R_386_TLS_DESC: I386RelType = I386RelType(41);
+pub const R_386_IRELATIVE: I386RelType = I386RelType(42);
+pub const R_386_GOT32X: I386RelType = I386RelType(43);
+
+impl I386RelType {
+    /// Get the name of the relocation type, or None if it is unknown.
+    pub fn name(&self) -> Option<&'static str> {
+        let name = match self.0 {
+            0 => "R_386_NONE",
+            1 => "R_386_32",
+            2 => "R_386_PC32",
+            3 => "R_386_GOT32",
+            4 => "R_386_PLT32",
+            5 => "R_386_COPY",
+            6 => "R_386_GLOB_DAT",
+            7 => "R_386_JMP_SLOT",
+            8 => "R_386_RELATIVE",
+            9 => "R_386_GOTOFF",
+            10 => "R_386_GOTPC",
+            11 => "R_386_32PLT",
+            14 => "R_386_TLS_TPOFF",
+            15 => "R_386_TLS_IE",
+            16 => "R_386_TLS_GOTIE",
+            17 => "R_386_TLS_LE",
+            18 => "R_386_TLS_GD",
+            19 => "R_386_TLS_LDM",
+            20 => "R_386_16",
+            21 => "R_386_PC16",
+            22 => "R_386_8",
+            23 => "R_386_PC8",
+            24 => "R_386_TLS_GD_32",
+            25 => "R_386_TLS_GD_PUSH",
+            26 => "R_386_TLS_GD_CALL",
+            27 => "R_386_TLS_GD_POP",
+            28 => "R_386_TLS_LDM_32",
+            29 => "R_386_TLS_LDM_PUSH",
+            30 => "R_386_TLS_LDM_CALL",
+            31 => "R_386_TLS_LDM_POP",
+            32 => "R_386_TLS_LDO_32",
+            33 => "R_386_TLS_IE_32",
+            34 => "R_386_TLS_LE_32",
+            35 => "R_386_TLS_DTPMOD32",
+            36 => "R_386_TLS_DTPOFF32",
+            37 => "R_386_TLS_TPOFF32",
+            38 => "R_386_SIZE32",
+            39 => "R_386_TLS_GOTDESC",
+            40 => "R_386_TLS_DESC_CALL",
+            41 => "R_386_TLS_DESC",
+            42 => "R_386_IRELATIVE",
+            43 => "R_386_GOT32X",
+            _ => return None,
+        };
+        Some(name)
+    }
+}
+
+impl fmt::Debug for I386RelType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!("{}", self.name().unwrap_or("UNKNOWN")))
+    }
+}
+
+/// Relocation type of AArch64 (EM_AARCH64).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Aarch64RelType(pub u32);
+
+pub const R_AARCH64_NONE: Aarch64RelType = Aarch64RelType(0);
+pub const R_AARCH64_P32_ABS32: Aarch64RelType = Aarch64RelType(1);
+pub const R_AARCH64_P32_COPY: Aarch64RelType = Aarch64RelType(180);
+pub const R_AARCH64_P32_GLOB_DAT: Aarch64RelType = Aarch64RelType(181);
+pub const R_AARCH64_P32_JUMP_SLOT: Aarch64RelType = Aarch64RelType(182);
+pub const R_AARCH64_P32_RELATIVE: Aarch64RelType = Aarch64RelType(183);
+pub const R_AARCH64_P32_TLS_DTPMOD: Aarch64RelType = Aarch64RelType(184);
+pub const R_AARCH64_P32_TLS_DTPREL: Aarch64RelType = Aarch64RelType(185);
+pub const R_AARCH64_P32_TLS_TPREL: Aarch64RelType = Aarch64RelType(186);
+pub const R_AARCH64_P32_TLSDESC: Aarch64RelType = Aarch64RelType(187);
+pub const R_AARCH64_P32_IRELATIVE: Aarch64RelType = Aarch64RelType(188);
+pub const R_AARCH64_NONE_WITHDRAWN: Aarch64RelType = Aarch64RelType(256);
+pub const R_AARCH64_ABS64: Aarch64RelType = Aarch64RelType(257);
+pub const R_AARCH64_ABS32: Aarch64RelType = Aarch64RelType(258);
+pub const R_AARCH64_ABS16: Aarch64RelType = Aarch64RelType(259);
+pub const R_AARCH64_PREL64: Aarch64RelType = Aarch64RelType(260);
+pub const R_AARCH64_PREL32: Aarch64RelType = Aarch64RelType(261);
+pub const R_AARCH64_PREL16: Aarch64RelType = Aarch64RelType(262);
+pub const R_AARCH64_MOVW_UABS_G0: Aarch64RelType = Aarch64RelType(263);
+pub const R_AARCH64_MOVW_UABS_G0_NC: Aarch64RelType = Aarch64RelType(264);
+pub const R_AARCH64_MOVW_UABS_G1: Aarch64RelType = Aarch64RelType(265);
+pub const R_AARCH64_MOVW_UABS_G1_NC: Aarch64RelType = Aarch64RelType(266);
+pub const R_AARCH64_MOVW_UABS_G2: Aarch64RelType = Aarch64RelType(267);
+pub const R_AARCH64_MOVW_UABS_G2_NC: Aarch64RelType = Aarch64RelType(268);
+pub const R_AARCH64_MOVW_UABS_G3: Aarch64RelType = Aarch64RelType(269);
+pub const R_AARCH64_MOVW_SABS_G0: Aarch64RelType = Aarch64RelType(270);
+pub const R_AARCH64_MOVW_SABS_G1: Aarch64RelType = Aarch64RelType(271);
+pub const R_AARCH64_MOVW_SABS_G2: Aarch64RelType = Aarch64RelType(272);
+pub const R_AARCH64_LD_PREL_LO19: Aarch64RelType = Aarch64RelType(273);
+pub const R_AARCH64_ADR_PREL_LO21: Aarch64RelType = Aarch64RelType(274);
+pub const R_AARCH64_ADR_PREL_PG_HI21: Aarch64RelType = Aarch64RelType(275);
+pub const R_AARCH64_ADR_PREL_PG_HI21_NC: Aarch64RelType = Aarch64RelType(276);
+pub const R_AARCH64_ADD_ABS_LO12_NC: Aarch64RelType = Aarch64RelType(277);
+pub const R_AARCH64_LDST8_ABS_LO12_NC: Aarch64RelType = Aarch64RelType(278);
+pub const R_AARCH64_TSTBR14: Aarch64RelType = Aarch64RelType(279);
+pub const R_AARCH64_CONDBR19: Aarch64RelType = Aarch64RelType(280);
+pub const R_AARCH64_JUMP26: Aarch64RelType = Aarch64RelType(282);
+pub const R_AARCH64_CALL26: Aarch64RelType = Aarch64RelType(283);
+pub const R_AARCH64_LDST16_ABS_LO12_NC: Aarch64RelType = Aarch64RelType(284);
+pub const R_AARCH64_LDST32_ABS_LO12_NC: Aarch64RelType = Aarch64RelType(285);
+pub const R_AARCH64_LDST64_ABS_LO12_NC: Aarch64RelType = Aarch64RelType(286);
+pub const R_AARCH64_MOVW_PREL_G0: Aarch64RelType = Aarch64RelType(287);
+pub const R_AARCH64_MOVW_PREL_G0_NC: Aarch64RelType = Aarch64RelType(288);
+pub const R_AARCH64_MOVW_PREL_G1: Aarch64RelType = Aarch64RelType(289);
+pub const R_AARCH64_MOVW_PREL_G1_NC: Aarch64RelType = Aarch64RelType(290);
+pub const R_AARCH64_MOVW_PREL_G2: Aarch64RelType = Aarch64RelType(291);
+pub const R_AARCH64_MOVW_PREL_G2_NC: Aarch64RelType = Aarch64RelType(292);
+pub const R_AARCH64_MOVW_PREL_G3: Aarch64RelType = Aarch64RelType(293);
+pub const R_AARCH64_LDST128_ABS_LO12_NC: Aarch64RelType = Aarch64RelType(299);
+pub const R_AARCH64_MOVW_GOTOFF_G0: Aarch64RelType = Aarch64RelType(300);
+pub const R_AARCH64_MOVW_GOTOFF_G0_NC: Aarch64RelType = Aarch64RelType(301);
+pub const R_AARCH64_MOVW_GOTOFF_G1: Aarch64RelType = Aarch64RelType(302);
+pub const R_AARCH64_MOVW_GOTOFF_G1_NC: Aarch64RelType = Aarch64RelType(303);
+pub const R_AARCH64_MOVW_GOTOFF_G2: Aarch64RelType = Aarch64RelType(304);
+pub const R_AARCH64_MOVW_GOTOFF_G2_NC: Aarch64RelType = Aarch64RelType(305);
+pub const R_AARCH64_MOVW_GOTOFF_G3: Aarch64RelType = Aarch64RelType(306);
+pub const R_AARCH64_GOTREL64: Aarch64RelType = Aarch64RelType(307);
+pub const R_AARCH64_GOTREL32: Aarch64RelType = Aarch64RelType(308);
+pub const R_AARCH64_GOT_LD_PREL19: Aarch64RelType = Aarch64RelType(309);
+pub const R_AARCH64_LD64_GOTOFF_LO15: Aarch64RelType = Aarch64RelType(310);
+pub const R_AARCH64_ADR_GOT_PAGE: Aarch64RelType = Aarch64RelType(311);
+pub const R_AARCH64_LD64_GOT_LO12_NC: Aarch64RelType = Aarch64RelType(312);
+pub const R_AARCH64_LD64_GOTPAGE_LO15: Aarch64RelType = Aarch64RelType(313);
+pub const R_AARCH64_TLSGD_ADR_PREL21: Aarch64RelType = Aarch64RelType(512);
+pub const R_AARCH64_TLSGD_ADR_PAGE21: Aarch64RelType = Aarch64RelType(513);
+pub const R_AARCH64_TLSGD_ADD_LO12_NC: Aarch64RelType = Aarch64RelType(514);
+pub const R_AARCH64_TLSGD_MOVW_G1: Aarch64RelType = Aarch64RelType(515);
+pub const R_AARCH64_TLSGD_MOVW_G0_NC: Aarch64RelType = Aarch64RelType(516);
+pub const R_AARCH64_TLSLD_ADR_PREL21: Aarch64RelType = Aarch64RelType(517);
+pub const R_AARCH64_TLSLD_ADR_PAGE21: Aarch64RelType = Aarch64RelType(518);
+pub const R_AARCH64_TLSLD_ADD_LO12_NC: Aarch64RelType = Aarch64RelType(519);
+pub const R_AARCH64_TLSLD_MOVW_G1: Aarch64RelType = Aarch64RelType(520);
+pub const R_AARCH64_TLSLD_MOVW_G0_NC: Aarch64RelType = Aarch64RelType(521);
+pub const R_AARCH64_TLSLD_LD_PREL19: Aarch64RelType = Aarch64RelType(522);
+pub const R_AARCH64_TLSLD_MOVW_DTPREL_G2: Aarch64RelType = Aarch64RelType(523);
+pub const R_AARCH64_TLSLD_MOVW_DTPREL_G1: Aarch64RelType = Aarch64RelType(524);
+pub const R_AARCH64_TLSLD_MOVW_DTPREL_G1_NC: Aarch64RelType = Aarch64RelType(525);
+pub const R_AARCH64_TLSLD_MOVW_DTPREL_G0: Aarch64RelType = Aarch64RelType(526);
+pub const R_AARCH64_TLSLD_MOVW_DTPREL_G0_NC: Aarch64RelType = Aarch64RelType(527);
+pub const R_AARCH64_TLSLD_ADD_DTPREL_HI12: Aarch64RelType = Aarch64RelType(528);
+pub const R_AARCH64_TLSLD_ADD_DTPREL_LO12: Aarch64RelType = Aarch64RelType(529);
+pub const R_AARCH64_TLSLD_ADD_DTPREL_LO12_NC: Aarch64RelType = Aarch64RelType(530);
+pub const R_AARCH64_TLSLD_LDST8_DTPREL_LO12: Aarch64RelType = Aarch64RelType(531);
+pub const R_AARCH64_TLSLD_LDST8_DTPREL_LO12_NC: Aarch64RelType = Aarch64RelType(532);
+pub const R_AARCH64_TLSLD_LDST16_DTPREL_LO12: Aarch64RelType = Aarch64RelType(533);
+pub const R_AARCH64_TLSLD_LDST16_DTPREL_LO12_NC: Aarch64RelType = Aarch64RelType(534);
+pub const R_AARCH64_TLSLD_LDST32_DTPREL_LO12: Aarch64RelType = Aarch64RelType(535);
+pub const R_AARCH64_TLSLD_LDST32_DTPREL_LO12_NC: Aarch64RelType = Aarch64RelType(536);
+pub const R_AARCH64_TLSLD_LDST64_DTPREL_LO12: Aarch64RelType = Aarch64RelType(537);
+pub const R_AARCH64_TLSLD_LDST64_DTPREL_LO12_NC: Aarch64RelType = Aarch64RelType(538);
+pub const R_AARCH64_TLSIE_MOVW_GOTTPREL_G1: Aarch64RelType = Aarch64RelType(539);
+pub const R_AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC: Aarch64RelType = Aarch64RelType(540);
+pub const R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21: Aarch64RelType = Aarch64RelType(541);
+pub const R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC: Aarch64RelType = Aarch64RelType(542);
+pub const R_AARCH64_TLSIE_LD_GOTTPREL_PREL19: Aarch64RelType = Aarch64RelType(543);
+pub const R_AARCH64_TLSLE_MOVW_TPREL_G2: Aarch64RelType = Aarch64RelType(544);
+pub const R_AARCH64_TLSLE_MOVW_TPREL_G1: Aarch64RelType = Aarch64RelType(545);
+pub const R_AARCH64_TLSLE_MOVW_TPREL_G1_NC: Aarch64RelType = Aarch64RelType(546);
+pub const R_AARCH64_TLSLE_MOVW_TPREL_G0: Aarch64RelType = Aarch64RelType(547);
+pub const R_AARCH64_TLSLE_MOVW_TPREL_G0_NC: Aarch64RelType = Aarch64RelType(548);
+pub const R_AARCH64_TLSLE_ADD_TPREL_HI12: Aarch64RelType = Aarch64RelType(549);
+pub const R_AARCH64_TLSLE_ADD_TPREL_LO12: Aarch64RelType = Aarch64RelType(550);
+pub const R_AARCH64_TLSLE_ADD_TPREL_LO12_NC: Aarch64RelType = Aarch64RelType(551);
+pub const R_AARCH64_TLSLE_LDST8_TPREL_LO12: Aarch64RelType = Aarch64RelType(552);
+pub const R_AARCH64_TLSLE_LDST8_TPREL_LO12_NC: Aarch64RelType = Aarch64RelType(553);
+pub const R_AARCH64_TLSLE_LDST16_TPREL_LO12: Aarch64RelType = Aarch64RelType(554);
+pub const R_AARCH64_TLSLE_LDST16_TPREL_LO12_NC: Aarch64RelType = Aarch64RelType(555);
+pub const R_AARCH64_TLSLE_LDST32_TPREL_LO12: Aarch64RelType = Aarch64RelType(556);
+pub const R_AARCH64_TLSLE_LDST32_TPREL_LO12_NC: Aarch64RelType = Aarch64RelType(557);
+pub const R_AARCH64_TLSLE_LDST64_TPREL_LO12: Aarch64RelType = Aarch64RelType(558);
+pub const R_AARCH64_TLSLE_LDST64_TPREL_LO12_NC: Aarch64RelType = Aarch64RelType(559);
+pub const R_AARCH64_TLSDESC_LD_PREL19: Aarch64RelType = Aarch64RelType(560);
+pub const R_AARCH64_TLSDESC_ADR_PREL21: Aarch64RelType = Aarch64RelType(561);
+pub const R_AARCH64_TLSDESC_ADR_PAGE21: Aarch64RelType = Aarch64RelType(562);
+pub const R_AARCH64_TLSDESC_LD64_LO12: Aarch64RelType = Aarch64RelType(563);
+pub const R_AARCH64_TLSDESC_ADD_LO12: Aarch64RelType = Aarch64RelType(564);
+pub const R_AARCH64_TLSDESC_OFF_G1: Aarch64RelType = Aarch64RelType(565);
+pub const R_AARCH64_TLSDESC_OFF_G0_NC: Aarch64RelType = Aarch64RelType(566);
+pub const R_AARCH64_TLSDESC_LDR: Aarch64RelType = Aarch64RelType(567);
+pub const R_AARCH64_TLSDESC_ADD: Aarch64RelType = Aarch64RelType(568);
+pub const R_AARCH64_TLSDESC_CALL: Aarch64RelType = Aarch64RelType(569);
+pub const R_AARCH64_TLSLE_LDST128_TPREL_LO12: Aarch64RelType = Aarch64RelType(570);
+pub const R_AARCH64_TLSLE_LDST128_TPREL_LO12_NC: Aarch64RelType = Aarch64RelType(571);
+pub const R_AARCH64_TLSLD_LDST128_DTPREL_LO12: Aarch64RelType = Aarch64RelType(572);
+pub const R_AARCH64_TLSLD_LDST128_DTPREL_LO12_NC: Aarch64RelType = Aarch64RelType(573);
+pub const R_AARCH64_COPY: Aarch64RelType = Aarch64RelType(1024);
+pub const R_AARCH64_GLOB_DAT: Aarch64RelType = Aarch64RelType(1025);
+pub const R_AARCH64_JUMP_SLOT: Aarch64RelType = Aarch64RelType(1026);
+pub const R_AARCH64_RELATIVE: Aarch64RelType = Aarch64RelType(1027);
+pub const R_AARCH64_TLS_DTPMOD: Aarch64RelType = Aarch64RelType(1028);
+pub const R_AARCH64_TLS_DTPREL: Aarch64RelType = Aarch64RelType(1029);
+pub const R_AARCH64_TLS_TPREL: Aarch64RelType = Aarch64RelType(1030);
+pub const R_AARCH64_TLSDESC: Aarch64RelType = Aarch64RelType(1031);
+pub const R_AARCH64_IRELATIVE: Aarch64RelType = Aarch64RelType(1032);
+
+impl Aarch64RelType {
+    /// Get the name of the relocation type, or None if it is unknown.
+    pub fn name(&self) -> Option<&'static str> {
+        let name = match self.0 {
+            0 => "R_AARCH64_NONE",
+            1 => "R_AARCH64_P32_ABS32",
+            180 => "R_AARCH64_P32_COPY",
+            181 => "R_AARCH64_P32_GLOB_DAT",
+            182 => "R_AARCH64_P32_JUMP_SLOT",
+            183 => "R_AARCH64_P32_RELATIVE",
+            184 => "R_AARCH64_P32_TLS_DTPMOD",
+            185 => "R_AARCH64_P32_TLS_DTPREL",
+            186 => "R_AARCH64_P32_TLS_TPREL",
+            187 => "R_AARCH64_P32_TLSDESC",
+            188 => "R_AARCH64_P32_IRELATIVE",
+            256 => "R_AARCH64_NONE_WITHDRAWN",
+            257 => "R_AARCH64_ABS64",
+            258 => "R_AARCH64_ABS32",
+            259 => "R_AARCH64_ABS16",
+            260 => "R_AARCH64_PREL64",
+            261 => "R_AARCH64_PREL32",
+            262 => "R_AARCH64_PREL16",
+            263 => "R_AARCH64_MOVW_UABS_G0",
+            264 => "R_AARCH64_MOVW_UABS_G0_NC",
+            265 => "R_AARCH64_MOVW_UABS_G1",
+            266 => "R_AARCH64_MOVW_UABS_G1_NC",
+            267 => "R_AARCH64_MOVW_UABS_G2",
+            268 => "R_AARCH64_MOVW_UABS_G2_NC",
+            269 => "R_AARCH64_MOVW_UABS_G3",
+            270 => "R_AARCH64_MOVW_SABS_G0",
+            271 => "R_AARCH64_MOVW_SABS_G1",
+            272 => "R_AARCH64_MOVW_SABS_G2",
+            273 => "R_AARCH64_LD_PREL_LO19",
+            274 => "R_AARCH64_ADR_PREL_LO21",
+            275 => "R_AARCH64_ADR_PREL_PG_HI21",
+            276 => "R_AARCH64_ADR_PREL_PG_HI21_NC",
+            277 => "R_AARCH64_ADD_ABS_LO12_NC",
+            278 => "R_AARCH64_LDST8_ABS_LO12_NC",
+            279 => "R_AARCH64_TSTBR14",
+            280 => "R_AARCH64_CONDBR19",
+            282 => "R_AARCH64_JUMP26",
+            283 => "R_AARCH64_CALL26",
+            284 => "R_AARCH64_LDST16_ABS_LO12_NC",
+            285 => "R_AARCH64_LDST32_ABS_LO12_NC",
+            286 => "R_AARCH64_LDST64_ABS_LO12_NC",
+            287 => "R_AARCH64_MOVW_PREL_G0",
+            288 => "R_AARCH64_MOVW_PREL_G0_NC",
+            289 => "R_AARCH64_MOVW_PREL_G1",
+            290 => "R_AARCH64_MOVW_PREL_G1_NC",
+            291 => "R_AARCH64_MOVW_PREL_G2",
+            292 => "R_AARCH64_MOVW_PREL_G2_NC",
+            293 => "R_AARCH64_MOVW_PREL_G3",
+            299 => "R_AARCH64_LDST128_ABS_LO12_NC",
+            300 => "R_AARCH64_MOVW_GOTOFF_G0",
+            301 => "R_AARCH64_MOVW_GOTOFF_G0_NC",
+            302 => "R_AARCH64_MOVW_GOTOFF_G1",
+            303 => "R_AARCH64_MOVW_GOTOFF_G1_NC",
+            304 => "R_AARCH64_MOVW_GOTOFF_G2",
+            305 => "R_AARCH64_MOVW_GOTOFF_G2_NC",
+            306 => "R_AARCH64_MOVW_GOTOFF_G3",
+            307 => "R_AARCH64_GOTREL64",
+            308 => "R_AARCH64_GOTREL32",
+            309 => "R_AARCH64_GOT_LD_PREL19",
+            310 => "R_AARCH64_LD64_GOTOFF_LO15",
+            311 => "R_AARCH64_ADR_GOT_PAGE",
+            312 => "R_AARCH64_LD64_GOT_LO12_NC",
+            313 => "R_AARCH64_LD64_GOTPAGE_LO15",
+            512 => "R_AARCH64_TLSGD_ADR_PREL21",
+            513 => "R_AARCH64_TLSGD_ADR_PAGE21",
+            514 => "R_AARCH64_TLSGD_ADD_LO12_NC",
+            515 => "R_AARCH64_TLSGD_MOVW_G1",
+            516 => "R_AARCH64_TLSGD_MOVW_G0_NC",
+            517 => "R_AARCH64_TLSLD_ADR_PREL21",
+            518 => "R_AARCH64_TLSLD_ADR_PAGE21",
+            519 => "R_AARCH64_TLSLD_ADD_LO12_NC",
+            520 => "R_AARCH64_TLSLD_MOVW_G1",
+            521 => "R_AARCH64_TLSLD_MOVW_G0_NC",
+            522 => "R_AARCH64_TLSLD_LD_PREL19",
+            523 => "R_AARCH64_TLSLD_MOVW_DTPREL_G2",
+            524 => "R_AARCH64_TLSLD_MOVW_DTPREL_G1",
+            525 => "R_AARCH64_TLSLD_MOVW_DTPREL_G1_NC",
+            526 => "R_AARCH64_TLSLD_MOVW_DTPREL_G0",
+            527 => "R_AARCH64_TLSLD_MOVW_DTPREL_G0_NC",
+            528 => "R_AARCH64_TLSLD_ADD_DTPREL_HI12",
+            529 => "R_AARCH64_TLSLD_ADD_DTPREL_LO12",
+            530 => "R_AARCH64_TLSLD_ADD_DTPREL_LO12_NC",
+            531 => "R_AARCH64_TLSLD_LDST8_DTPREL_LO12",
+            532 => "R_AARCH64_TLSLD_LDST8_DTPREL_LO12_NC",
+            533 => "R_AARCH64_TLSLD_LDST16_DTPREL_LO12",
+            534 => "R_AARCH64_TLSLD_LDST16_DTPREL_LO12_NC",
+            535 => "R_AARCH64_TLSLD_LDST32_DTPREL_LO12",
+            536 => "R_AARCH64_TLSLD_LDST32_DTPREL_LO12_NC",
+            537 => "R_AARCH64_TLSLD_LDST64_DTPREL_LO12",
+            538 => "R_AARCH64_TLSLD_LDST64_DTPREL_LO12_NC",
+            539 => "R_AARCH64_TLSIE_MOVW_GOTTPREL_G1",
+            540 => "R_AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC",
+            541 => "R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21",
+            542 => "R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC",
+            543 => "R_AARCH64_TLSIE_LD_GOTTPREL_PREL19",
+            544 => "R_AARCH64_TLSLE_MOVW_TPREL_G2",
+            545 => "R_AARCH64_TLSLE_MOVW_TPREL_G1",
+            546 => "R_AARCH64_TLSLE_MOVW_TPREL_G1_NC",
+            547 => "R_AARCH64_TLSLE_MOVW_TPREL_G0",
+            548 => "R_AARCH64_TLSLE_MOVW_TPREL_G0_NC",
+            549 => "R_AARCH64_TLSLE_ADD_TPREL_HI12",
+            550 => "R_AARCH64_TLSLE_ADD_TPREL_LO12",
+            551 => "R_AARCH64_TLSLE_ADD_TPREL_LO12_NC",
+            552 => "R_AARCH64_TLSLE_LDST8_TPREL_LO12",
+            553 => "R_AARCH64_TLSLE_LDST8_TPREL_LO12_NC",
+            554 => "R_AARCH64_TLSLE_LDST16_TPREL_LO12",
+            555 => "R_AARCH64_TLSLE_LDST16_TPREL_LO12_NC",
+            556 => "R_AARCH64_TLSLE_LDST32_TPREL_LO12",
+            557 => "R_AARCH64_TLSLE_LDST32_TPREL_LO12_NC",
+            558 => "R_AARCH64_TLSLE_LDST64_TPREL_LO12",
+            559 => "R_AARCH64_TLSLE_LDST64_TPREL_LO12_NC",
+            560 => "R_AARCH64_TLSDESC_LD_PREL19",
+            561 => "R_AARCH64_TLSDESC_ADR_PREL21",
+            562 => "R_AARCH64_TLSDESC_ADR_PAGE21",
+            563 => "R_AARCH64_TLSDESC_LD64_LO12",
+            564 => "R_AARCH64_TLSDESC_ADD_LO12",
+            565 => "R_AARCH64_TLSDESC_OFF_G1",
+            566 => "R_AARCH64_TLSDESC_OFF_G0_NC",
+            567 => "R_AARCH64_TLSDESC_LDR",
+            568 => "R_AARCH64_TLSDESC_ADD",
+            569 => "R_AARCH64_TLSDESC_CALL",
+            570 => "R_AARCH64_TLSLE_LDST128_TPREL_LO12",
+            571 => "R_AARCH64_TLSLE_LDST128_TPREL_LO12_NC",
+            572 => "R_AARCH64_TLSLD_LDST128_DTPREL_LO12",
+            573 => "R_AARCH64_TLSLD_LDST128_DTPREL_LO12_NC",
+            1024 => "R_AARCH64_COPY",
+            1025 => "R_AARCH64_GLOB_DAT",
+            1026 => "R_AARCH64_JUMP_SLOT",
+            1027 => "R_AARCH64_RELATIVE",
+            1028 => "R_AARCH64_TLS_DTPMOD",
+            1029 => "R_AARCH64_TLS_DTPREL",
+            1030 => "R_AARCH64_TLS_TPREL",
+            1031 => "R_AARCH64_TLSDESC",
+            1032 => "R_AARCH64_IRELATIVE",
+            _ => return None,
+        };
+        Some(name)
+    }
+}
+
+impl fmt::Debug for Aarch64RelType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!("{}", self.name().unwrap_or("UNKNOWN")))
+    }
+}
+
+/// Relocation type of 32-bit ARM (EM_ARM).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ArmRelType(pub u32);
+
+pub const R_ARM_NONE: ArmRelType = ArmRelType(0);
+pub const R_ARM_PC24: ArmRelType = ArmRelType(1);
+pub const R_ARM_ABS32: ArmRelType = ArmRelType(2);
+pub const R_ARM_REL32: ArmRelType = ArmRelType(3);
+pub const R_ARM_PC13: ArmRelType = ArmRelType(4);
+pub const R_ARM_ABS16: ArmRelType = ArmRelType(5);
+pub const R_ARM_ABS12: ArmRelType = ArmRelType(6);
+pub const R_ARM_THM_ABS5: ArmRelType = ArmRelType(7);
+pub const R_ARM_ABS8: ArmRelType = ArmRelType(8);
+pub const R_ARM_SBREL32: ArmRelType = ArmRelType(9);
+pub const R_ARM_THM_PC22: ArmRelType = ArmRelType(10);
+pub const R_ARM_THM_PC8: ArmRelType = ArmRelType(11);
+pub const R_ARM_AMP_VCALL9: ArmRelType = ArmRelType(12);
+pub const R_ARM_TLS_DESC: ArmRelType = ArmRelType(13);
+pub const R_ARM_THM_SWI8: ArmRelType = ArmRelType(14);
+pub const R_ARM_XPC25: ArmRelType = ArmRelType(15);
+pub const R_ARM_THM_XPC22: ArmRelType = ArmRelType(16);
+pub const R_ARM_TLS_DTPMOD32: ArmRelType = ArmRelType(17);
+pub const R_ARM_TLS_DTPOFF32: ArmRelType = ArmRelType(18);
+pub const R_ARM_TLS_TPOFF32: ArmRelType = ArmRelType(19);
+pub const R_ARM_COPY: ArmRelType = ArmRelType(20);
+pub const R_ARM_GLOB_DAT: ArmRelType = ArmRelType(21);
+pub const R_ARM_JUMP_SLOT: ArmRelType = ArmRelType(22);
+pub const R_ARM_RELATIVE: ArmRelType = ArmRelType(23);
+pub const R_ARM_GOTOFF: ArmRelType = ArmRelType(24);
+pub const R_ARM_GOTPC: ArmRelType = ArmRelType(25);
+pub const R_ARM_GOT32: ArmRelType = ArmRelType(26);
+pub const R_ARM_PLT32: ArmRelType = ArmRelType(27);
+pub const R_ARM_CALL: ArmRelType = ArmRelType(28);
+pub const R_ARM_JUMP24: ArmRelType = ArmRelType(29);
+pub const R_ARM_THM_JUMP24: ArmRelType = ArmRelType(30);
+pub const R_ARM_BASE_ABS: ArmRelType = ArmRelType(31);
+pub const R_ARM_ALU_PCREL_7_0: ArmRelType = ArmRelType(32);
+pub const R_ARM_ALU_PCREL_15_8: ArmRelType = ArmRelType(33);
+pub const R_ARM_ALU_PCREL_23_15: ArmRelType = ArmRelType(34);
+pub const R_ARM_LDR_SBREL_11_0: ArmRelType = ArmRelType(35);
+pub const R_ARM_ALU_SBREL_19_12: ArmRelType = ArmRelType(36);
+pub const R_ARM_ALU_SBREL_27_20: ArmRelType = ArmRelType(37);
+pub const R_ARM_TARGET1: ArmRelType = ArmRelType(38);
+pub const R_ARM_SBREL31: ArmRelType = ArmRelType(39);
+pub const R_ARM_V4BX: ArmRelType = ArmRelType(40);
+pub const R_ARM_TARGET2: ArmRelType = ArmRelType(41);
+pub const R_ARM_PREL31: ArmRelType = ArmRelType(42);
+pub const R_ARM_MOVW_ABS_NC: ArmRelType = ArmRelType(43);
+pub const R_ARM_MOVT_ABS: ArmRelType = ArmRelType(44);
+pub const R_ARM_MOVW_PREL_NC: ArmRelType = ArmRelType(45);
+pub const R_ARM_MOVT_PREL: ArmRelType = ArmRelType(46);
+pub const R_ARM_THM_MOVW_ABS_NC: ArmRelType = ArmRelType(47);
+pub const R_ARM_THM_MOVT_ABS: ArmRelType = ArmRelType(48);
+pub const R_ARM_THM_MOVW_PREL_NC: ArmRelType = ArmRelType(49);
+pub const R_ARM_THM_MOVT_PREL: ArmRelType = ArmRelType(50);
+pub const R_ARM_THM_JUMP19: ArmRelType = ArmRelType(51);
+pub const R_ARM_THM_JUMP6: ArmRelType = ArmRelType(52);
+pub const R_ARM_THM_ALU_PREL_11_0: ArmRelType = ArmRelType(53);
+pub const R_ARM_THM_PC12: ArmRelType = ArmRelType(54);
+pub const R_ARM_ABS32_NOI: ArmRelType = ArmRelType(55);
+pub const R_ARM_REL32_NOI: ArmRelType = ArmRelType(56);
+pub const R_ARM_ALU_PC_G0_NC: ArmRelType = ArmRelType(57);
+pub const R_ARM_ALU_PC_G0: ArmRelType = ArmRelType(58);
+pub const R_ARM_ALU_PC_G1_NC: ArmRelType = ArmRelType(59);
+pub const R_ARM_ALU_PC_G1: ArmRelType = ArmRelType(60);
+pub const R_ARM_ALU_PC_G2: ArmRelType = ArmRelType(61);
+pub const R_ARM_LDR_PC_G1: ArmRelType = ArmRelType(62);
+pub const R_ARM_LDR_PC_G2: ArmRelType = ArmRelType(63);
+pub const R_ARM_LDRS_PC_G0: ArmRelType = ArmRelType(64);
+pub const R_ARM_LDRS_PC_G1: ArmRelType = ArmRelType(65);
+pub const R_ARM_LDRS_PC_G2: ArmRelType = ArmRelType(66);
+pub const R_ARM_LDC_PC_G0: ArmRelType = ArmRelType(67);
+pub const R_ARM_LDC_PC_G1: ArmRelType = ArmRelType(68);
+pub const R_ARM_LDC_PC_G2: ArmRelType = ArmRelType(69);
+pub const R_ARM_ALU_SB_G0_NC: ArmRelType = ArmRelType(70);
+pub const R_ARM_ALU_SB_G0: ArmRelType = ArmRelType(71);
+pub const R_ARM_ALU_SB_G1_NC: ArmRelType = ArmRelType(72);
+pub const R_ARM_ALU_SB_G1: ArmRelType = ArmRelType(73);
+pub const R_ARM_ALU_SB_G2: ArmRelType = ArmRelType(74);
+pub const R_ARM_LDR_SB_G0: ArmRelType = ArmRelType(75);
+pub const R_ARM_LDR_SB_G1: ArmRelType = ArmRelType(76);
+pub const R_ARM_LDR_SB_G2: ArmRelType = ArmRelType(77);
+pub const R_ARM_LDRS_SB_G0: ArmRelType = ArmRelType(78);
+pub const R_ARM_LDRS_SB_G1: ArmRelType = ArmRelType(79);
+pub const R_ARM_LDRS_SB_G2: ArmRelType = ArmRelType(80);
+pub const R_ARM_LDC_SB_G0: ArmRelType = ArmRelType(81);
+pub const R_ARM_LDC_SB_G1: ArmRelType = ArmRelType(82);
+pub const R_ARM_LDC_SB_G2: ArmRelType = ArmRelType(83);
+pub const R_ARM_MOVW_BREL_NC: ArmRelType = ArmRelType(84);
+pub const R_ARM_MOVT_BREL: ArmRelType = ArmRelType(85);
+pub const R_ARM_MOVW_BREL: ArmRelType = ArmRelType(86);
+pub const R_ARM_THM_MOVW_BREL_NC: ArmRelType = ArmRelType(87);
+pub const R_ARM_THM_MOVT_BREL: ArmRelType = ArmRelType(88);
+pub const R_ARM_THM_MOVW_BREL: ArmRelType = ArmRelType(89);
+pub const R_ARM_TLS_GOTDESC: ArmRelType = ArmRelType(90);
+pub const R_ARM_TLS_CALL: ArmRelType = ArmRelType(91);
+pub const R_ARM_TLS_DESCSEQ: ArmRelType = ArmRelType(92);
+pub const R_ARM_THM_TLS_CALL: ArmRelType = ArmRelType(93);
+pub const R_ARM_PLT32_ABS: ArmRelType = ArmRelType(94);
+pub const R_ARM_GOT_ABS: ArmRelType = ArmRelType(95);
+pub const R_ARM_GOT_PREL: ArmRelType = ArmRelType(96);
+pub const R_ARM_GOT_BREL12: ArmRelType = ArmRelType(97);
+pub const R_ARM_GOTOFF12: ArmRelType = ArmRelType(98);
+pub const R_ARM_GOTRELAX: ArmRelType = ArmRelType(99);
+pub const R_ARM_GNU_VTENTRY: ArmRelType = ArmRelType(100);
+pub const R_ARM_GNU_VTINHERIT: ArmRelType = ArmRelType(101);
+pub const R_ARM_THM_PC11: ArmRelType = ArmRelType(102);
+pub const R_ARM_THM_PC9: ArmRelType = ArmRelType(103);
+pub const R_ARM_TLS_GD32: ArmRelType = ArmRelType(104);
+pub const R_ARM_TLS_LDM32: ArmRelType = ArmRelType(105);
+pub const R_ARM_TLS_LDO32: ArmRelType = ArmRelType(106);
+pub const R_ARM_TLS_IE32: ArmRelType = ArmRelType(107);
+pub const R_ARM_TLS_LE32: ArmRelType = ArmRelType(108);
+pub const R_ARM_TLS_LDO12: ArmRelType = ArmRelType(109);
+pub const R_ARM_TLS_LE12: ArmRelType = ArmRelType(110);
+pub const R_ARM_TLS_IE12GP: ArmRelType = ArmRelType(111);
+pub const R_ARM_ME_TOO: ArmRelType = ArmRelType(128);
+pub const R_ARM_THM_TLS_DESCSEQ16: ArmRelType = ArmRelType(129);
+pub const R_ARM_THM_TLS_DESCSEQ32: ArmRelType = ArmRelType(130);
+pub const R_ARM_THM_GOT_BREL12: ArmRelType = ArmRelType(131);
+pub const R_ARM_IRELATIVE: ArmRelType = ArmRelType(160);
+pub const R_ARM_RXPC25: ArmRelType = ArmRelType(249);
+pub const R_ARM_RSBREL32: ArmRelType = ArmRelType(250);
+pub const R_ARM_THM_RPC22: ArmRelType = ArmRelType(251);
+pub const R_ARM_RREL32: ArmRelType = ArmRelType(252);
+pub const R_ARM_RABS22: ArmRelType = ArmRelType(253);
+pub const R_ARM_RPC24: ArmRelType = ArmRelType(254);
+pub const R_ARM_RBASE: ArmRelType = ArmRelType(255);
+
+impl ArmRelType {
+    /// Get the name of the relocation type, or None if it is unknown.
+    pub fn name(&self) -> Option<&'static str> {
+        let name = match self.0 {
+            0 => "R_ARM_NONE",
+            1 => "R_ARM_PC24",
+            2 => "R_ARM_ABS32",
+            3 => "R_ARM_REL32",
+            4 => "R_ARM_PC13",
+            5 => "R_ARM_ABS16",
+            6 => "R_ARM_ABS12",
+            7 => "R_ARM_THM_ABS5",
+            8 => "R_ARM_ABS8",
+            9 => "R_ARM_SBREL32",
+            10 => "R_ARM_THM_PC22",
+            11 => "R_ARM_THM_PC8",
+            12 => "R_ARM_AMP_VCALL9",
+            13 => "R_ARM_TLS_DESC",
+            14 => "R_ARM_THM_SWI8",
+            15 => "R_ARM_XPC25",
+            16 => "R_ARM_THM_XPC22",
+            17 => "R_ARM_TLS_DTPMOD32",
+            18 => "R_ARM_TLS_DTPOFF32",
+            19 => "R_ARM_TLS_TPOFF32",
+            20 => "R_ARM_COPY",
+            21 => "R_ARM_GLOB_DAT",
+            22 => "R_ARM_JUMP_SLOT",
+            23 => "R_ARM_RELATIVE",
+            24 => "R_ARM_GOTOFF",
+            25 => "R_ARM_GOTPC",
+            26 => "R_ARM_GOT32",
+            27 => "R_ARM_PLT32",
+            28 => "R_ARM_CALL",
+            29 => "R_ARM_JUMP24",
+            30 => "R_ARM_THM_JUMP24",
+            31 => "R_ARM_BASE_ABS",
+            32 => "R_ARM_ALU_PCREL_7_0",
+            33 => "R_ARM_ALU_PCREL_15_8",
+            34 => "R_ARM_ALU_PCREL_23_15",
+            35 => "R_ARM_LDR_SBREL_11_0",
+            36 => "R_ARM_ALU_SBREL_19_12",
+            37 => "R_ARM_ALU_SBREL_27_20",
+            38 => "R_ARM_TARGET1",
+            39 => "R_ARM_SBREL31",
+            40 => "R_ARM_V4BX",
+            41 => "R_ARM_TARGET2",
+            42 => "R_ARM_PREL31",
+            43 => "R_ARM_MOVW_ABS_NC",
+            44 => "R_ARM_MOVT_ABS",
+            45 => "R_ARM_MOVW_PREL_NC",
+            46 => "R_ARM_MOVT_PREL",
+            47 => "R_ARM_THM_MOVW_ABS_NC",
+            48 => "R_ARM_THM_MOVT_ABS",
+            49 => "R_ARM_THM_MOVW_PREL_NC",
+            50 => "R_ARM_THM_MOVT_PREL",
+            51 => "R_ARM_THM_JUMP19",
+            52 => "R_ARM_THM_JUMP6",
+            53 => "R_ARM_THM_ALU_PREL_11_0",
+            54 => "R_ARM_THM_PC12",
+            55 => "R_ARM_ABS32_NOI",
+            56 => "R_ARM_REL32_NOI",
+            57 => "R_ARM_ALU_PC_G0_NC",
+            58 => "R_ARM_ALU_PC_G0",
+            59 => "R_ARM_ALU_PC_G1_NC",
+            60 => "R_ARM_ALU_PC_G1",
+            61 => "R_ARM_ALU_PC_G2",
+            62 => "R_ARM_LDR_PC_G1",
+            63 => "R_ARM_LDR_PC_G2",
+            64 => "R_ARM_LDRS_PC_G0",
+            65 => "R_ARM_LDRS_PC_G1",
+            66 => "R_ARM_LDRS_PC_G2",
+            67 => "R_ARM_LDC_PC_G0",
+            68 => "R_ARM_LDC_PC_G1",
+            69 => "R_ARM_LDC_PC_G2",
+            70 => "R_ARM_ALU_SB_G0_NC",
+            71 => "R_ARM_ALU_SB_G0",
+            72 => "R_ARM_ALU_SB_G1_NC",
+            73 => "R_ARM_ALU_SB_G1",
+            74 => "R_ARM_ALU_SB_G2",
+            75 => "R_ARM_LDR_SB_G0",
+            76 => "R_ARM_LDR_SB_G1",
+            77 => "R_ARM_LDR_SB_G2",
+            78 => "R_ARM_LDRS_SB_G0",
+            79 => "R_ARM_LDRS_SB_G1",
+            80 => "R_ARM_LDRS_SB_G2",
+            81 => "R_ARM_LDC_SB_G0",
+            82 => "R_ARM_LDC_SB_G1",
+            83 => "R_ARM_LDC_SB_G2",
+            84 => "R_ARM_MOVW_BREL_NC",
+            85 => "R_ARM_MOVT_BREL",
+            86 => "R_ARM_MOVW_BREL",
+            87 => "R_ARM_THM_MOVW_BREL_NC",
+            88 => "R_ARM_THM_MOVT_BREL",
+            89 => "R_ARM_THM_MOVW_BREL",
+            90 => "R_ARM_TLS_GOTDESC",
+            91 => "R_ARM_TLS_CALL",
+            92 => "R_ARM_TLS_DESCSEQ",
+            93 => "R_ARM_THM_TLS_CALL",
+            94 => "R_ARM_PLT32_ABS",
+            95 => "R_ARM_GOT_ABS",
+            96 => "R_ARM_GOT_PREL",
+            97 => "R_ARM_GOT_BREL12",
+            98 => "R_ARM_GOTOFF12",
+            99 => "R_ARM_GOTRELAX",
+            100 => "R_ARM_GNU_VTENTRY",
+            101 => "R_ARM_GNU_VTINHERIT",
+            102 => "R_ARM_THM_PC11",
+            103 => "R_ARM_THM_PC9",
+            104 => "R_ARM_TLS_GD32",
+            105 => "R_ARM_TLS_LDM32",
+            106 => "R_ARM_TLS_LDO32",
+            107 => "R_ARM_TLS_IE32",
+            108 => "R_ARM_TLS_LE32",
+            109 => "R_ARM_TLS_LDO12",
+            110 => "R_ARM_TLS_LE12",
+            111 => "R_ARM_TLS_IE12GP",
+            128 => "R_ARM_ME_TOO",
+            129 => "R_ARM_THM_TLS_DESCSEQ16",
+            130 => "R_ARM_THM_TLS_DESCSEQ32",
+            131 => "R_ARM_THM_GOT_BREL12",
+            160 => "R_ARM_IRELATIVE",
+            249 => "R_ARM_RXPC25",
+            250 => "R_ARM_RSBREL32",
+            251 => "R_ARM_THM_RPC22",
+            252 => "R_ARM_RREL32",
+            253 => "R_ARM_RABS22",
+            254 => "R_ARM_RPC24",
+            255 => "R_ARM_RBASE",
+            _ => return None,
+        };
+        Some(name)
+    }
+}
+
+impl fmt::Debug for ArmRelType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!("{}", self.name().unwrap_or("UNKNOWN")))
+    }
+}
+
+pub const R_ARM_SWI24: ArmRelType = R_ARM_TLS_DESC;
+pub const R_ARM_THM_TLS_DESCSEQ: ArmRelType = R_ARM_THM_TLS_DESCSEQ16;
+
+/// Relocation type of RISC-V (EM_RISCV).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct RiscvRelType(pub u32);
+
+pub const R_RISCV_NONE: RiscvRelType = RiscvRelType(0);
+pub const R_RISCV_32: RiscvRelType = RiscvRelType(1);
+pub const R_RISCV_64: RiscvRelType = RiscvRelType(2);
+pub const R_RISCV_RELATIVE: RiscvRelType = RiscvRelType(3);
+pub const R_RISCV_COPY: RiscvRelType = RiscvRelType(4);
+pub const R_RISCV_JUMP_SLOT: RiscvRelType = RiscvRelType(5);
+pub const R_RISCV_TLS_DTPMOD32: RiscvRelType = RiscvRelType(6);
+pub const R_RISCV_TLS_DTPMOD64: RiscvRelType = RiscvRelType(7);
+pub const R_RISCV_TLS_DTPREL32: RiscvRelType = RiscvRelType(8);
+pub const R_RISCV_TLS_DTPREL64: RiscvRelType = RiscvRelType(9);
+pub const R_RISCV_TLS_TPREL32: RiscvRelType = RiscvRelType(10);
+pub const R_RISCV_TLS_TPREL64: RiscvRelType = RiscvRelType(11);
+pub const R_RISCV_BRANCH: RiscvRelType = RiscvRelType(16);
+pub const R_RISCV_JAL: RiscvRelType = RiscvRelType(17);
+pub const R_RISCV_CALL: RiscvRelType = RiscvRelType(18);
+pub const R_RISCV_CALL_PLT: RiscvRelType = RiscvRelType(19);
+pub const R_RISCV_GOT_HI20: RiscvRelType = RiscvRelType(20);
+pub const R_RISCV_TLS_GOT_HI20: RiscvRelType = RiscvRelType(21);
+pub const R_RISCV_TLS_GD_HI20: RiscvRelType = RiscvRelType(22);
+pub const R_RISCV_PCREL_HI20: RiscvRelType = RiscvRelType(23);
+pub const R_RISCV_PCREL_LO12_I: RiscvRelType = RiscvRelType(24);
+pub const R_RISCV_PCREL_LO12_S: RiscvRelType = RiscvRelType(25);
+pub const R_RISCV_HI20: RiscvRelType = RiscvRelType(26);
+pub const R_RISCV_LO12_I: RiscvRelType = RiscvRelType(27);
+pub const R_RISCV_LO12_S: RiscvRelType = RiscvRelType(28);
+pub const R_RISCV_TPREL_HI20: RiscvRelType = RiscvRelType(29);
+pub const R_RISCV_TPREL_LO12_I: RiscvRelType = RiscvRelType(30);
+pub const R_RISCV_TPREL_LO12_S: RiscvRelType = RiscvRelType(31);
+pub const R_RISCV_TPREL_ADD: RiscvRelType = RiscvRelType(32);
+pub const R_RISCV_ADD8: RiscvRelType = RiscvRelType(33);
+pub const R_RISCV_ADD16: RiscvRelType = RiscvRelType(34);
+pub const R_RISCV_ADD32: RiscvRelType = RiscvRelType(35);
+pub const R_RISCV_ADD64: RiscvRelType = RiscvRelType(36);
+pub const R_RISCV_SUB8: RiscvRelType = RiscvRelType(37);
+pub const R_RISCV_SUB16: RiscvRelType = RiscvRelType(38);
+pub const R_RISCV_SUB32: RiscvRelType = RiscvRelType(39);
+pub const R_RISCV_SUB64: RiscvRelType = RiscvRelType(40);
+pub const R_RISCV_GNU_VTINHERIT: RiscvRelType = RiscvRelType(41);
+pub const R_RISCV_GNU_VTENTRY: RiscvRelType = RiscvRelType(42);
+pub const R_RISCV_ALIGN: RiscvRelType = RiscvRelType(43);
+pub const R_RISCV_RVC_BRANCH: RiscvRelType = RiscvRelType(44);
+pub const R_RISCV_RVC_JUMP: RiscvRelType = RiscvRelType(45);
+pub const R_RISCV_RVC_LUI: RiscvRelType = RiscvRelType(46);
+pub const R_RISCV_GPREL_I: RiscvRelType = RiscvRelType(47);
+pub const R_RISCV_GPREL_S: RiscvRelType = RiscvRelType(48);
+pub const R_RISCV_TPREL_I: RiscvRelType = RiscvRelType(49);
+pub const R_RISCV_TPREL_S: RiscvRelType = RiscvRelType(50);
+pub const R_RISCV_RELAX: RiscvRelType = RiscvRelType(51);
+pub const R_RISCV_SUB6: RiscvRelType = RiscvRelType(52);
+pub const R_RISCV_SET6: RiscvRelType = RiscvRelType(53);
+pub const R_RISCV_SET8: RiscvRelType = RiscvRelType(54);
+pub const R_RISCV_SET16: RiscvRelType = RiscvRelType(55);
+pub const R_RISCV_SET32: RiscvRelType = RiscvRelType(56);
+pub const R_RISCV_32_PCREL: RiscvRelType = RiscvRelType(57);
+pub const R_RISCV_IRELATIVE: RiscvRelType = RiscvRelType(58);
+
+impl RiscvRelType {
+    /// Get the name of the relocation type, or None if it is unknown.
+    pub fn name(&self) -> Option<&'static str> {
+        let name = match self.0 {
+            0 => "R_RISCV_NONE",
+            1 => "R_RISCV_32",
+            2 => "R_RISCV_64",
+            3 => "R_RISCV_RELATIVE",
+            4 => "R_RISCV_COPY",
+            5 => "R_RISCV_JUMP_SLOT",
+            6 => "R_RISCV_TLS_DTPMOD32",
+            7 => "R_RISCV_TLS_DTPMOD64",
+            8 => "R_RISCV_TLS_DTPREL32",
+            9 => "R_RISCV_TLS_DTPREL64",
+            10 => "R_RISCV_TLS_TPREL32",
+            11 => "R_RISCV_TLS_TPREL64",
+            16 => "R_RISCV_BRANCH",
+            17 => "R_RISCV_JAL",
+            18 => "R_RISCV_CALL",
+            19 => "R_RISCV_CALL_PLT",
+            20 => "R_RISCV_GOT_HI20",
+            21 => "R_RISCV_TLS_GOT_HI20",
+            22 => "R_RISCV_TLS_GD_HI20",
+            23 => "R_RISCV_PCREL_HI20",
+            24 => "R_RISCV_PCREL_LO12_I",
+            25 => "R_RISCV_PCREL_LO12_S",
+            26 => "R_RISCV_HI20",
+            27 => "R_RISCV_LO12_I",
+            28 => "R_RISCV_LO12_S",
+            29 => "R_RISCV_TPREL_HI20",
+            30 => "R_RISCV_TPREL_LO12_I",
+            31 => "R_RISCV_TPREL_LO12_S",
+            32 => "R_RISCV_TPREL_ADD",
+            33 => "R_RISCV_ADD8",
+            34 => "R_RISCV_ADD16",
+            35 => "R_RISCV_ADD32",
+            36 => "R_RISCV_ADD64",
+            37 => "R_RISCV_SUB8",
+            38 => "R_RISCV_SUB16",
+            39 => "R_RISCV_SUB32",
+            40 => "R_RISCV_SUB64",
+            41 => "R_RISCV_GNU_VTINHERIT",
+            42 => "R_RISCV_GNU_VTENTRY",
+            43 => "R_RISCV_ALIGN",
+            44 => "R_RISCV_RVC_BRANCH",
+            45 => "R_RISCV_RVC_JUMP",
+            46 => "R_RISCV_RVC_LUI",
+            47 => "R_RISCV_GPREL_I",
+            48 => "R_RISCV_GPREL_S",
+            49 => "R_RISCV_TPREL_I",
+            50 => "R_RISCV_TPREL_S",
+            51 => "R_RISCV_RELAX",
+            52 => "R_RISCV_SUB6",
+            53 => "R_RISCV_SET6",
+            54 => "R_RISCV_SET8",
+            55 => "R_RISCV_SET16",
+            56 => "R_RISCV_SET32",
+            57 => "R_RISCV_32_PCREL",
+            58 => "R_RISCV_IRELATIVE",
+            _ => return None,
+        };
+        Some(name)
+    }
+}
+
+impl fmt::Debug for RiscvRelType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!("{}", self.name().unwrap_or("UNKNOWN")))
+    }
+}
+
+/// Relocation type of 64-bit PowerPC (EM_PPC64).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Ppc64RelType(pub u32);
+
+pub const R_PPC64_NONE: Ppc64RelType = Ppc64RelType(0);
+pub const R_PPC64_ADDR32: Ppc64RelType = Ppc64RelType(1);
+pub const R_PPC64_ADDR24: Ppc64RelType = Ppc64RelType(2);
+pub const R_PPC64_ADDR16: Ppc64RelType = Ppc64RelType(3);
+pub const R_PPC64_ADDR16_LO: Ppc64RelType = Ppc64RelType(4);
+pub const R_PPC64_ADDR16_HI: Ppc64RelType = Ppc64RelType(5);
+pub const R_PPC64_ADDR16_HA: Ppc64RelType = Ppc64RelType(6);
+pub const R_PPC64_ADDR14: Ppc64RelType = Ppc64RelType(7);
+pub const R_PPC64_ADDR14_BRTAKEN: Ppc64RelType = Ppc64RelType(8);
+pub const R_PPC64_ADDR14_BRNTAKEN: Ppc64RelType = Ppc64RelType(9);
+pub const R_PPC64_REL24: Ppc64RelType = Ppc64RelType(10);
+pub const R_PPC64_REL14: Ppc64RelType = Ppc64RelType(11);
+pub const R_PPC64_REL14_BRTAKEN: Ppc64RelType = Ppc64RelType(12);
+pub const R_PPC64_REL14_BRNTAKEN: Ppc64RelType = Ppc64RelType(13);
+pub const R_PPC64_GOT16: Ppc64RelType = Ppc64RelType(14);
+pub const R_PPC64_GOT16_LO: Ppc64RelType = Ppc64RelType(15);
+pub const R_PPC64_GOT16_HI: Ppc64RelType = Ppc64RelType(16);
+pub const R_PPC64_GOT16_HA: Ppc64RelType = Ppc64RelType(17);
+pub const R_PPC64_COPY: Ppc64RelType = Ppc64RelType(19);
+pub const R_PPC64_GLOB_DAT: Ppc64RelType = Ppc64RelType(20);
+pub const R_PPC64_JMP_SLOT: Ppc64RelType = Ppc64RelType(21);
+pub const R_PPC64_RELATIVE: Ppc64RelType = Ppc64RelType(22);
+pub const R_PPC64_UADDR32: Ppc64RelType = Ppc64RelType(24);
+pub const R_PPC64_UADDR16: Ppc64RelType = Ppc64RelType(25);
+pub const R_PPC64_REL32: Ppc64RelType = Ppc64RelType(26);
+pub const R_PPC64_PLT32: Ppc64RelType = Ppc64RelType(27);
+pub const R_PPC64_PLTREL32: Ppc64RelType = Ppc64RelType(28);
+pub const R_PPC64_PLT16_LO: Ppc64RelType = Ppc64RelType(29);
+pub const R_PPC64_PLT16_HI: Ppc64RelType = Ppc64RelType(30);
+pub const R_PPC64_PLT16_HA: Ppc64RelType = Ppc64RelType(31);
+pub const R_PPC64_SECTOFF: Ppc64RelType = Ppc64RelType(33);
+pub const R_PPC64_SECTOFF_LO: Ppc64RelType = Ppc64RelType(34);
+pub const R_PPC64_SECTOFF_HI: Ppc64RelType = Ppc64RelType(35);
+pub const R_PPC64_SECTOFF_HA: Ppc64RelType = Ppc64RelType(36);
+pub const R_PPC64_ADDR30: Ppc64RelType = Ppc64RelType(37);
+pub const R_PPC64_ADDR64: Ppc64RelType = Ppc64RelType(38);
+pub const R_PPC64_ADDR16_HIGHER: Ppc64RelType = Ppc64RelType(39);
+pub const R_PPC64_ADDR16_HIGHERA: Ppc64RelType = Ppc64RelType(40);
+pub const R_PPC64_ADDR16_HIGHEST: Ppc64RelType = Ppc64RelType(41);
+pub const R_PPC64_ADDR16_HIGHESTA: Ppc64RelType = Ppc64RelType(42);
+pub const R_PPC64_UADDR64: Ppc64RelType = Ppc64RelType(43);
+pub const R_PPC64_REL64: Ppc64RelType = Ppc64RelType(44);
+pub const R_PPC64_PLT64: Ppc64RelType = Ppc64RelType(45);
+pub const R_PPC64_PLTREL64: Ppc64RelType = Ppc64RelType(46);
+pub const R_PPC64_TOC16: Ppc64RelType = Ppc64RelType(47);
+pub const R_PPC64_TOC16_LO: Ppc64RelType = Ppc64RelType(48);
+pub const R_PPC64_TOC16_HI: Ppc64RelType = Ppc64RelType(49);
+pub const R_PPC64_TOC16_HA: Ppc64RelType = Ppc64RelType(50);
+pub const R_PPC64_TOC: Ppc64RelType = Ppc64RelType(51);
+pub const R_PPC64_PLTGOT16: Ppc64RelType = Ppc64RelType(52);
+pub const R_PPC64_PLTGOT16_LO: Ppc64RelType = Ppc64RelType(53);
+pub const R_PPC64_PLTGOT16_HI: Ppc64RelType = Ppc64RelType(54);
+pub const R_PPC64_PLTGOT16_HA: Ppc64RelType = Ppc64RelType(55);
+pub const R_PPC64_ADDR16_DS: Ppc64RelType = Ppc64RelType(56);
+pub const R_PPC64_ADDR16_LO_DS: Ppc64RelType = Ppc64RelType(57);
+pub const R_PPC64_GOT16_DS: Ppc64RelType = Ppc64RelType(58);
+pub const R_PPC64_GOT16_LO_DS: Ppc64RelType = Ppc64RelType(59);
+pub const R_PPC64_PLT16_LO_DS: Ppc64RelType = Ppc64RelType(60);
+pub const R_PPC64_SECTOFF_DS: Ppc64RelType = Ppc64RelType(61);
+pub const R_PPC64_SECTOFF_LO_DS: Ppc64RelType = Ppc64RelType(62);
+pub const R_PPC64_TOC16_DS: Ppc64RelType = Ppc64RelType(63);
+pub const R_PPC64_TOC16_LO_DS: Ppc64RelType = Ppc64RelType(64);
+pub const R_PPC64_PLTGOT16_DS: Ppc64RelType = Ppc64RelType(65);
+pub const R_PPC64_PLTGOT16_LO_DS: Ppc64RelType = Ppc64RelType(66);
+pub const R_PPC64_TLS: Ppc64RelType = Ppc64RelType(67);
+pub const R_PPC64_DTPMOD64: Ppc64RelType = Ppc64RelType(68);
+pub const R_PPC64_TPREL16: Ppc64RelType = Ppc64RelType(69);
+pub const R_PPC64_TPREL16_LO: Ppc64RelType = Ppc64RelType(70);
+pub const R_PPC64_TPREL16_HI: Ppc64RelType = Ppc64RelType(71);
+pub const R_PPC64_TPREL16_HA: Ppc64RelType = Ppc64RelType(72);
+pub const R_PPC64_TPREL64: Ppc64RelType = Ppc64RelType(73);
+pub const R_PPC64_DTPREL16: Ppc64RelType = Ppc64RelType(74);
+pub const R_PPC64_DTPREL16_LO: Ppc64RelType = Ppc64RelType(75);
+pub const R_PPC64_DTPREL16_HI: Ppc64RelType = Ppc64RelType(76);
+pub const R_PPC64_DTPREL16_HA: Ppc64RelType = Ppc64RelType(77);
+pub const R_PPC64_DTPREL64: Ppc64RelType = Ppc64RelType(78);
+pub const R_PPC64_GOT_TLSGD16: Ppc64RelType = Ppc64RelType(79);
+pub const R_PPC64_GOT_TLSGD16_LO: Ppc64RelType = Ppc64RelType(80);
+pub const R_PPC64_GOT_TLSGD16_HI: Ppc64RelType = Ppc64RelType(81);
+pub const R_PPC64_GOT_TLSGD16_HA: Ppc64RelType = Ppc64RelType(82);
+pub const R_PPC64_GOT_TLSLD16: Ppc64RelType = Ppc64RelType(83);
+pub const R_PPC64_GOT_TLSLD16_LO: Ppc64RelType = Ppc64RelType(84);
+pub const R_PPC64_GOT_TLSLD16_HI: Ppc64RelType = Ppc64RelType(85);
+pub const R_PPC64_GOT_TLSLD16_HA: Ppc64RelType = Ppc64RelType(86);
+pub const R_PPC64_GOT_TPREL16_DS: Ppc64RelType = Ppc64RelType(87);
+pub const R_PPC64_GOT_TPREL16_LO_DS: Ppc64RelType = Ppc64RelType(88);
+pub const R_PPC64_GOT_TPREL16_HI: Ppc64RelType = Ppc64RelType(89);
+pub const R_PPC64_GOT_TPREL16_HA: Ppc64RelType = Ppc64RelType(90);
+pub const R_PPC64_GOT_DTPREL16_DS: Ppc64RelType = Ppc64RelType(91);
+pub const R_PPC64_GOT_DTPREL16_LO_DS: Ppc64RelType = Ppc64RelType(92);
+pub const R_PPC64_GOT_DTPREL16_HI: Ppc64RelType = Ppc64RelType(93);
+pub const R_PPC64_GOT_DTPREL16_HA: Ppc64RelType = Ppc64RelType(94);
+pub const R_PPC64_TPREL16_DS: Ppc64RelType = Ppc64RelType(95);
+pub const R_PPC64_TPREL16_LO_DS: Ppc64RelType = Ppc64RelType(96);
+pub const R_PPC64_TPREL16_HIGHER: Ppc64RelType = Ppc64RelType(97);
+pub const R_PPC64_TPREL16_HIGHERA: Ppc64RelType = Ppc64RelType(98);
+pub const R_PPC64_TPREL16_HIGHEST: Ppc64RelType = Ppc64RelType(99);
+pub const R_PPC64_TPREL16_HIGHESTA: Ppc64RelType = Ppc64RelType(100);
+pub const R_PPC64_DTPREL16_DS: Ppc64RelType = Ppc64RelType(101);
+pub const R_PPC64_DTPREL16_LO_DS: Ppc64RelType = Ppc64RelType(102);
+pub const R_PPC64_DTPREL16_HIGHER: Ppc64RelType = Ppc64RelType(103);
+pub const R_PPC64_DTPREL16_HIGHERA: Ppc64RelType = Ppc64RelType(104);
+pub const R_PPC64_DTPREL16_HIGHEST: Ppc64RelType = Ppc64RelType(105);
+pub const R_PPC64_DTPREL16_HIGHESTA: Ppc64RelType = Ppc64RelType(106);
+pub const R_PPC64_TLSGD: Ppc64RelType = Ppc64RelType(107);
+pub const R_PPC64_TLSLD: Ppc64RelType = Ppc64RelType(108);
+pub const R_PPC64_TOCSAVE: Ppc64RelType = Ppc64RelType(109);
+pub const R_PPC64_ADDR16_HIGH: Ppc64RelType = Ppc64RelType(110);
+pub const R_PPC64_ADDR16_HIGHA: Ppc64RelType = Ppc64RelType(111);
+pub const R_PPC64_TPREL16_HIGH: Ppc64RelType = Ppc64RelType(112);
+pub const R_PPC64_TPREL16_HIGHA: Ppc64RelType = Ppc64RelType(113);
+pub const R_PPC64_DTPREL16_HIGH: Ppc64RelType = Ppc64RelType(114);
+pub const R_PPC64_DTPREL16_HIGHA: Ppc64RelType = Ppc64RelType(115);
+pub const R_PPC64_JMP_IREL: Ppc64RelType = Ppc64RelType(247);
+pub const R_PPC64_IRELATIVE: Ppc64RelType = Ppc64RelType(248);
+pub const R_PPC64_REL16: Ppc64RelType = Ppc64RelType(249);
+pub const R_PPC64_REL16_LO: Ppc64RelType = Ppc64RelType(250);
+pub const R_PPC64_REL16_HI: Ppc64RelType = Ppc64RelType(251);
+pub const R_PPC64_REL16_HA: Ppc64RelType = Ppc64RelType(252);
+
+impl Ppc64RelType {
+    /// Get the name of the relocation type, or None if it is unknown.
+    pub fn name(&self) -> Option<&'static str> {
+        let name = match self.0 {
+            0 => "R_PPC64_NONE",
+            1 => "R_PPC64_ADDR32",
+            2 => "R_PPC64_ADDR24",
+            3 => "R_PPC64_ADDR16",
+            4 => "R_PPC64_ADDR16_LO",
+            5 => "R_PPC64_ADDR16_HI",
+            6 => "R_PPC64_ADDR16_HA",
+            7 => "R_PPC64_ADDR14",
+            8 => "R_PPC64_ADDR14_BRTAKEN",
+            9 => "R_PPC64_ADDR14_BRNTAKEN",
+            10 => "R_PPC64_REL24",
+            11 => "R_PPC64_REL14",
+            12 => "R_PPC64_REL14_BRTAKEN",
+            13 => "R_PPC64_REL14_BRNTAKEN",
+            14 => "R_PPC64_GOT16",
+            15 => "R_PPC64_GOT16_LO",
+            16 => "R_PPC64_GOT16_HI",
+            17 => "R_PPC64_GOT16_HA",
+            19 => "R_PPC64_COPY",
+            20 => "R_PPC64_GLOB_DAT",
+            21 => "R_PPC64_JMP_SLOT",
+            22 => "R_PPC64_RELATIVE",
+            24 => "R_PPC64_UADDR32",
+            25 => "R_PPC64_UADDR16",
+            26 => "R_PPC64_REL32",
+            27 => "R_PPC64_PLT32",
+            28 => "R_PPC64_PLTREL32",
+            29 => "R_PPC64_PLT16_LO",
+            30 => "R_PPC64_PLT16_HI",
+            31 => "R_PPC64_PLT16_HA",
+            33 => "R_PPC64_SECTOFF",
+            34 => "R_PPC64_SECTOFF_LO",
+            35 => "R_PPC64_SECTOFF_HI",
+            36 => "R_PPC64_SECTOFF_HA",
+            37 => "R_PPC64_ADDR30",
+            38 => "R_PPC64_ADDR64",
+            39 => "R_PPC64_ADDR16_HIGHER",
+            40 => "R_PPC64_ADDR16_HIGHERA",
+            41 => "R_PPC64_ADDR16_HIGHEST",
+            42 => "R_PPC64_ADDR16_HIGHESTA",
+            43 => "R_PPC64_UADDR64",
+            44 => "R_PPC64_REL64",
+            45 => "R_PPC64_PLT64",
+            46 => "R_PPC64_PLTREL64",
+            47 => "R_PPC64_TOC16",
+            48 => "R_PPC64_TOC16_LO",
+            49 => "R_PPC64_TOC16_HI",
+            50 => "R_PPC64_TOC16_HA",
+            51 => "R_PPC64_TOC",
+            52 => "R_PPC64_PLTGOT16",
+            53 => "R_PPC64_PLTGOT16_LO",
+            54 => "R_PPC64_PLTGOT16_HI",
+            55 => "R_PPC64_PLTGOT16_HA",
+            56 => "R_PPC64_ADDR16_DS",
+            57 => "R_PPC64_ADDR16_LO_DS",
+            58 => "R_PPC64_GOT16_DS",
+            59 => "R_PPC64_GOT16_LO_DS",
+            60 => "R_PPC64_PLT16_LO_DS",
+            61 => "R_PPC64_SECTOFF_DS",
+            62 => "R_PPC64_SECTOFF_LO_DS",
+            63 => "R_PPC64_TOC16_DS",
+            64 => "R_PPC64_TOC16_LO_DS",
+            65 => "R_PPC64_PLTGOT16_DS",
+            66 => "R_PPC64_PLTGOT16_LO_DS",
+            67 => "R_PPC64_TLS",
+            68 => "R_PPC64_DTPMOD64",
+            69 => "R_PPC64_TPREL16",
+            70 => "R_PPC64_TPREL16_LO",
+            71 => "R_PPC64_TPREL16_HI",
+            72 => "R_PPC64_TPREL16_HA",
+            73 => "R_PPC64_TPREL64",
+            74 => "R_PPC64_DTPREL16",
+            75 => "R_PPC64_DTPREL16_LO",
+            76 => "R_PPC64_DTPREL16_HI",
+            77 => "R_PPC64_DTPREL16_HA",
+            78 => "R_PPC64_DTPREL64",
+            79 => "R_PPC64_GOT_TLSGD16",
+            80 => "R_PPC64_GOT_TLSGD16_LO",
+            81 => "R_PPC64_GOT_TLSGD16_HI",
+            82 => "R_PPC64_GOT_TLSGD16_HA",
+            83 => "R_PPC64_GOT_TLSLD16",
+            84 => "R_PPC64_GOT_TLSLD16_LO",
+            85 => "R_PPC64_GOT_TLSLD16_HI",
+            86 => "R_PPC64_GOT_TLSLD16_HA",
+            87 => "R_PPC64_GOT_TPREL16_DS",
+            88 => "R_PPC64_GOT_TPREL16_LO_DS",
+            89 => "R_PPC64_GOT_TPREL16_HI",
+            90 => "R_PPC64_GOT_TPREL16_HA",
+            91 => "R_PPC64_GOT_DTPREL16_DS",
+            92 => "R_PPC64_GOT_DTPREL16_LO_DS",
+            93 => "R_PPC64_GOT_DTPREL16_HI",
+            94 => "R_PPC64_GOT_DTPREL16_HA",
+            95 => "R_PPC64_TPREL16_DS",
+            96 => "R_PPC64_TPREL16_LO_DS",
+            97 => "R_PPC64_TPREL16_HIGHER",
+            98 => "R_PPC64_TPREL16_HIGHERA",
+            99 => "R_PPC64_TPREL16_HIGHEST",
+            100 => "R_PPC64_TPREL16_HIGHESTA",
+            101 => "R_PPC64_DTPREL16_DS",
+            102 => "R_PPC64_DTPREL16_LO_DS",
+            103 => "R_PPC64_DTPREL16_HIGHER",
+            104 => "R_PPC64_DTPREL16_HIGHERA",
+            105 => "R_PPC64_DTPREL16_HIGHEST",
+            106 => "R_PPC64_DTPREL16_HIGHESTA",
+            107 => "R_PPC64_TLSGD",
+            108 => "R_PPC64_TLSLD",
+            109 => "R_PPC64_TOCSAVE",
+            110 => "R_PPC64_ADDR16_HIGH",
+            111 => "R_PPC64_ADDR16_HIGHA",
+            112 => "R_PPC64_TPREL16_HIGH",
+            113 => "R_PPC64_TPREL16_HIGHA",
+            114 => "R_PPC64_DTPREL16_HIGH",
+            115 => "R_PPC64_DTPREL16_HIGHA",
+            247 => "R_PPC64_JMP_IREL",
+            248 => "R_PPC64_IRELATIVE",
+            249 => "R_PPC64_REL16",
+            250 => "R_PPC64_REL16_LO",
+            251 => "R_PPC64_REL16_HI",
+            252 => "R_PPC64_REL16_HA",
+            _ => return None,
+        };
+        Some(name)
+    }
+}
+
+impl fmt::Debug for Ppc64RelType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!("{}", self.name().unwrap_or("UNKNOWN")))
+    }
+}
+
+/// Relocation type of MIPS (EM_MIPS).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct MipsRelType(pub u32);
+
+pub const R_MIPS_NONE: MipsRelType = MipsRelType(0);
+pub const R_MIPS_16: MipsRelType = MipsRelType(1);
+pub const R_MIPS_32: MipsRelType = MipsRelType(2);
+pub const R_MIPS_REL32: MipsRelType = MipsRelType(3);
+pub const R_MIPS_26: MipsRelType = MipsRelType(4);
+pub const R_MIPS_HI16: MipsRelType = MipsRelType(5);
+pub const R_MIPS_LO16: MipsRelType = MipsRelType(6);
+pub const R_MIPS_GPREL16: MipsRelType = MipsRelType(7);
+pub const R_MIPS_LITERAL: MipsRelType = MipsRelType(8);
+pub const R_MIPS_GOT16: MipsRelType = MipsRelType(9);
+pub const R_MIPS_PC16: MipsRelType = MipsRelType(10);
+pub const R_MIPS_CALL16: MipsRelType = MipsRelType(11);
+pub const R_MIPS_GPREL32: MipsRelType = MipsRelType(12);
+pub const R_MIPS_SHIFT5: MipsRelType = MipsRelType(16);
+pub const R_MIPS_SHIFT6: MipsRelType = MipsRelType(17);
+pub const R_MIPS_64: MipsRelType = MipsRelType(18);
+pub const R_MIPS_GOT_DISP: MipsRelType = MipsRelType(19);
+pub const R_MIPS_GOT_PAGE: MipsRelType = MipsRelType(20);
+pub const R_MIPS_GOT_OFST: MipsRelType = MipsRelType(21);
+pub const R_MIPS_GOT_HI16: MipsRelType = MipsRelType(22);
+pub const R_MIPS_GOT_LO16: MipsRelType = MipsRelType(23);
+pub const R_MIPS_SUB: MipsRelType = MipsRelType(24);
+pub const R_MIPS_INSERT_A: MipsRelType = MipsRelType(25);
+pub const R_MIPS_INSERT_B: MipsRelType = MipsRelType(26);
+pub const R_MIPS_DELETE: MipsRelType = MipsRelType(27);
+pub const R_MIPS_HIGHER: MipsRelType = MipsRelType(28);
+pub const R_MIPS_HIGHEST: MipsRelType = MipsRelType(29);
+pub const R_MIPS_CALL_HI16: MipsRelType = MipsRelType(30);
+pub const R_MIPS_CALL_LO16: MipsRelType = MipsRelType(31);
+pub const R_MIPS_SCN_DISP: MipsRelType = MipsRelType(32);
+pub const R_MIPS_REL16: MipsRelType = MipsRelType(33);
+pub const R_MIPS_ADD_IMMEDIATE: MipsRelType = MipsRelType(34);
+pub const R_MIPS_PJUMP: MipsRelType = MipsRelType(35);
+pub const R_MIPS_RELGOT: MipsRelType = MipsRelType(36);
+pub const R_MIPS_JALR: MipsRelType = MipsRelType(37);
+pub const R_MIPS_TLS_DTPMOD32: MipsRelType = MipsRelType(38);
+pub const R_MIPS_TLS_DTPREL32: MipsRelType = MipsRelType(39);
+pub const R_MIPS_TLS_DTPMOD64: MipsRelType = MipsRelType(40);
+pub const R_MIPS_TLS_DTPREL64: MipsRelType = MipsRelType(41);
+pub const R_MIPS_TLS_GD: MipsRelType = MipsRelType(42);
+pub const R_MIPS_TLS_LDM: MipsRelType = MipsRelType(43);
+pub const R_MIPS_TLS_DTPREL_HI16: MipsRelType = MipsRelType(44);
+pub const R_MIPS_TLS_DTPREL_LO16: MipsRelType = MipsRelType(45);
+pub const R_MIPS_TLS_GOTTPREL: MipsRelType = MipsRelType(46);
+pub const R_MIPS_TLS_TPREL32: MipsRelType = MipsRelType(47);
+pub const R_MIPS_TLS_TPREL64: MipsRelType = MipsRelType(48);
+pub const R_MIPS_TLS_TPREL_HI16: MipsRelType = MipsRelType(49);
+pub const R_MIPS_TLS_TPREL_LO16: MipsRelType = MipsRelType(50);
+pub const R_MIPS_GLOB_DAT: MipsRelType = MipsRelType(51);
+pub const R_MIPS_COPY: MipsRelType = MipsRelType(126);
+pub const R_MIPS_JUMP_SLOT: MipsRelType = MipsRelType(127);
+
+impl MipsRelType {
+    /// Get the name of the relocation type, or None if it is unknown.
+    pub fn name(&self) -> Option<&'static str> {
+        let name = match self.0 {
+            0 => "R_MIPS_NONE",
+            1 => "R_MIPS_16",
+            2 => "R_MIPS_32",
+            3 => "R_MIPS_REL32",
+            4 => "R_MIPS_26",
+            5 => "R_MIPS_HI16",
+            6 => "R_MIPS_LO16",
+            7 => "R_MIPS_GPREL16",
+            8 => "R_MIPS_LITERAL",
+            9 => "R_MIPS_GOT16",
+            10 => "R_MIPS_PC16",
+            11 => "R_MIPS_CALL16",
+            12 => "R_MIPS_GPREL32",
+            16 => "R_MIPS_SHIFT5",
+            17 => "R_MIPS_SHIFT6",
+            18 => "R_MIPS_64",
+            19 => "R_MIPS_GOT_DISP",
+            20 => "R_MIPS_GOT_PAGE",
+            21 => "R_MIPS_GOT_OFST",
+            22 => "R_MIPS_GOT_HI16",
+            23 => "R_MIPS_GOT_LO16",
+            24 => "R_MIPS_SUB",
+            25 => "R_MIPS_INSERT_A",
+            26 => "R_MIPS_INSERT_B",
+            27 => "R_MIPS_DELETE",
+            28 => "R_MIPS_HIGHER",
+            29 => "R_MIPS_HIGHEST",
+            30 => "R_MIPS_CALL_HI16",
+            31 => "R_MIPS_CALL_LO16",
+            32 => "R_MIPS_SCN_DISP",
+            33 => "R_MIPS_REL16",
+            34 => "R_MIPS_ADD_IMMEDIATE",
+            35 => "R_MIPS_PJUMP",
+            36 => "R_MIPS_RELGOT",
+            37 => "R_MIPS_JALR",
+            38 => "R_MIPS_TLS_DTPMOD32",
+            39 => "R_MIPS_TLS_DTPREL32",
+            40 => "R_MIPS_TLS_DTPMOD64",
+            41 => "R_MIPS_TLS_DTPREL64",
+            42 => "R_MIPS_TLS_GD",
+            43 => "R_MIPS_TLS_LDM",
+            44 => "R_MIPS_TLS_DTPREL_HI16",
+            45 => "R_MIPS_TLS_DTPREL_LO16",
+            46 => "R_MIPS_TLS_GOTTPREL",
+            47 => "R_MIPS_TLS_TPREL32",
+            48 => "R_MIPS_TLS_TPREL64",
+            49 => "R_MIPS_TLS_TPREL_HI16",
+            50 => "R_MIPS_TLS_TPREL_LO16",
+            51 => "R_MIPS_GLOB_DAT",
+            126 => "R_MIPS_COPY",
+            127 => "R_MIPS_JUMP_SLOT",
+            _ => return None,
+        };
+        Some(name)
+    }
+}
+
+impl fmt::Debug for MipsRelType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!("{}", self.name().unwrap_or("UNKNOWN")))
+    }
+}
+
+/// Relocation type of s390x (EM_S390).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct S390RelType(pub u32);
+
+pub const R_390_NONE: S390RelType = S390RelType(0);
+pub const R_390_8: S390RelType = S390RelType(1);
+pub const R_390_12: S390RelType = S390RelType(2);
+pub const R_390_16: S390RelType = S390RelType(3);
+pub const R_390_32: S390RelType = S390RelType(4);
+pub const R_390_PC32: S390RelType = S390RelType(5);
+pub const R_390_GOT12: S390RelType = S390RelType(6);
+pub const R_390_GOT32: S390RelType = S390RelType(7);
+pub const R_390_PLT32: S390RelType = S390RelType(8);
+pub const R_390_COPY: S390RelType = S390RelType(9);
+pub const R_390_GLOB_DAT: S390RelType = S390RelType(10);
+pub const R_390_JMP_SLOT: S390RelType = S390RelType(11);
+pub const R_390_RELATIVE: S390RelType = S390RelType(12);
+pub const R_390_GOTOFF32: S390RelType = S390RelType(13);
+pub const R_390_GOTPC: S390RelType = S390RelType(14);
+pub const R_390_GOT16: S390RelType = S390RelType(15);
+pub const R_390_PC16: S390RelType = S390RelType(16);
+pub const R_390_PC16DBL: S390RelType = S390RelType(17);
+pub const R_390_PLT16DBL: S390RelType = S390RelType(18);
+pub const R_390_PC32DBL: S390RelType = S390RelType(19);
+pub const R_390_PLT32DBL: S390RelType = S390RelType(20);
+pub const R_390_GOTPCDBL: S390RelType = S390RelType(21);
+pub const R_390_64: S390RelType = S390RelType(22);
+pub const R_390_PC64: S390RelType = S390RelType(23);
+pub const R_390_GOT64: S390RelType = S390RelType(24);
+pub const R_390_PLT64: S390RelType = S390RelType(25);
+pub const R_390_GOTENT: S390RelType = S390RelType(26);
+pub const R_390_GOTOFF16: S390RelType = S390RelType(27);
+pub const R_390_GOTOFF64: S390RelType = S390RelType(28);
+pub const R_390_GOTPLT12: S390RelType = S390RelType(29);
+pub const R_390_GOTPLT16: S390RelType = S390RelType(30);
+pub const R_390_GOTPLT32: S390RelType = S390RelType(31);
+pub const R_390_GOTPLT64: S390RelType = S390RelType(32);
+pub const R_390_GOTPLTENT: S390RelType = S390RelType(33);
+pub const R_390_PLTOFF16: S390RelType = S390RelType(34);
+pub const R_390_PLTOFF32: S390RelType = S390RelType(35);
+pub const R_390_PLTOFF64: S390RelType = S390RelType(36);
+pub const R_390_TLS_LOAD: S390RelType = S390RelType(37);
+pub const R_390_TLS_GDCALL: S390RelType = S390RelType(38);
+pub const R_390_TLS_LDCALL: S390RelType = S390RelType(39);
+pub const R_390_TLS_GD32: S390RelType = S390RelType(40);
+pub const R_390_TLS_GD64: S390RelType = S390RelType(41);
+pub const R_390_TLS_GOTIE12: S390RelType = S390RelType(42);
+pub const R_390_TLS_GOTIE32: S390RelType = S390RelType(43);
+pub const R_390_TLS_GOTIE64: S390RelType = S390RelType(44);
+pub const R_390_TLS_LDM32: S390RelType = S390RelType(45);
+pub const R_390_TLS_LDM64: S390RelType = S390RelType(46);
+pub const R_390_TLS_IE32: S390RelType = S390RelType(47);
+pub const R_390_TLS_IE64: S390RelType = S390RelType(48);
+pub const R_390_TLS_IEENT: S390RelType = S390RelType(49);
+pub const R_390_TLS_LE32: S390RelType = S390RelType(50);
+pub const R_390_TLS_LE64: S390RelType = S390RelType(51);
+pub const R_390_TLS_LDO32: S390RelType = S390RelType(52);
+pub const R_390_TLS_LDO64: S390RelType = S390RelType(53);
+pub const R_390_TLS_DTPMOD: S390RelType = S390RelType(54);
+pub const R_390_TLS_DTPOFF: S390RelType = S390RelType(55);
+pub const R_390_TLS_TPOFF: S390RelType = S390RelType(56);
+pub const R_390_20: S390RelType = S390RelType(57);
+pub const R_390_GOT20: S390RelType = S390RelType(58);
+pub const R_390_GOTPLT20: S390RelType = S390RelType(59);
+pub const R_390_TLS_GOTIE20: S390RelType = S390RelType(60);
+pub const R_390_IRELATIVE: S390RelType = S390RelType(61);
+
+impl S390RelType {
+    /// Get the name of the relocation type, or None if it is unknown.
+    pub fn name(&self) -> Option<&'static str> {
+        let name = match self.0 {
+            0 => "R_390_NONE",
+            1 => "R_390_8",
+            2 => "R_390_12",
+            3 => "R_390_16",
+            4 => "R_390_32",
+            5 => "R_390_PC32",
+            6 => "R_390_GOT12",
+            7 => "R_390_GOT32",
+            8 => "R_390_PLT32",
+            9 => "R_390_COPY",
+            10 => "R_390_GLOB_DAT",
+            11 => "R_390_JMP_SLOT",
+            12 => "R_390_RELATIVE",
+            13 => "R_390_GOTOFF32",
+            14 => "R_390_GOTPC",
+            15 => "R_390_GOT16",
+            16 => "R_390_PC16",
+            17 => "R_390_PC16DBL",
+            18 => "R_390_PLT16DBL",
+            19 => "R_390_PC32DBL",
+            20 => "R_390_PLT32DBL",
+            21 => "R_390_GOTPCDBL",
+            22 => "R_390_64",
+            23 => "R_390_PC64",
+            24 => "R_390_GOT64",
+            25 => "R_390_PLT64",
+            26 => "R_390_GOTENT",
+            27 => "R_390_GOTOFF16",
+            28 => "R_390_GOTOFF64",
+            29 => "R_390_GOTPLT12",
+            30 => "R_390_GOTPLT16",
+            31 => "R_390_GOTPLT32",
+            32 => "R_390_GOTPLT64",
+            33 => "R_390_GOTPLTENT",
+            34 => "R_390_PLTOFF16",
+            35 => "R_390_PLTOFF32",
+            36 => "R_390_PLTOFF64",
+            37 => "R_390_TLS_LOAD",
+            38 => "R_390_TLS_GDCALL",
+            39 => "R_390_TLS_LDCALL",
+            40 => "R_390_TLS_GD32",
+            41 => "R_390_TLS_GD64",
+            42 => "R_390_TLS_GOTIE12",
+            43 => "R_390_TLS_GOTIE32",
+            44 => "R_390_TLS_GOTIE64",
+            45 => "R_390_TLS_LDM32",
+            46 => "R_390_TLS_LDM64",
+            47 => "R_390_TLS_IE32",
+            48 => "R_390_TLS_IE64",
+            49 => "R_390_TLS_IEENT",
+            50 => "R_390_TLS_LE32",
+            51 => "R_390_TLS_LE64",
+            52 => "R_390_TLS_LDO32",
+            53 => "R_390_TLS_LDO64",
+            54 => "R_390_TLS_DTPMOD",
+            55 => "R_390_TLS_DTPOFF",
+            56 => "R_390_TLS_TPOFF",
+            57 => "R_390_20",
+            58 => "R_390_GOT20",
+            59 => "R_390_GOTPLT20",
+            60 => "R_390_TLS_GOTIE20",
+            61 => "R_390_IRELATIVE",
+            _ => return None,
+        };
+        Some(name)
+    }
+}
+
+impl fmt::Debug for S390RelType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!("{}", self.name().unwrap_or("UNKNOWN")))
+    }
+}
+
+/// Get the name of relocation type `r_type` on `machine`, or None if the machine or the type is
+/// unknown.
+pub fn relocation_type_name(machine: Machine, r_type: u32) -> Option<&'static str> {
+    match machine {
+        EM_X86_64 => X86_64RelType(r_type).name(),
+        EM_386 => I386RelType(r_type).name(),
+        EM_AARCH64 => Aarch64RelType(r_type).name(),
+        EM_ARM => ArmRelType(r_type).name(),
+        EM_RISCV => RiscvRelType(r_type).name(),
+        EM_PPC64 => Ppc64RelType(r_type).name(),
+        EM_MIPS | EM_MIPS_RS3_LE => MipsRelType(r_type).name(),
+        EM_S390 => S390RelType(r_type).name(),
+        _ => None,
+    }
+}
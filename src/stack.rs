@@ -0,0 +1,230 @@
+use crate::{
+    coredump::AuxEntry,
+    file::FileHeader,
+    types::{
+        AuxType, Class, Data, Machine, AT_BASE, AT_ENTRY, AT_NULL, AT_PAGESZ, AT_PHDR, AT_PHENT,
+        AT_PHNUM, AT_RANDOM, ELFCLASS32, ELFCLASS64, EM_AARCH64, EM_RISCV, EM_X86_64,
+    },
+    util, ElfFile, Error, Loader,
+};
+
+/// The number of entries an AuxvBuilder holds, not counting AT_NULL.
+pub const AUXV_CAPACITY: usize = 32;
+
+/// Collects the entries of the auxiliary vector that a new process is started with.
+#[derive(Clone, Copy)]
+pub struct AuxvBuilder {
+    entries: [AuxEntry; AUXV_CAPACITY],
+    len: usize,
+}
+
+impl Default for AuxvBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AuxvBuilder {
+    /// Get an empty AuxvBuilder.
+    pub fn new() -> Self {
+        AuxvBuilder {
+            entries: [AuxEntry {
+                a_type: AT_NULL,
+                a_val: 0,
+            }; AUXV_CAPACITY],
+            len: 0,
+        }
+    }
+
+    /// Get an AuxvBuilder with the entries that describe `file` loaded at `bias`: AT_PHDR,
+    /// AT_PHENT, AT_PHNUM, AT_PAGESZ, AT_BASE and AT_ENTRY.
+    ///
+    /// AT_BASE is 0, which is right for a program without an interpreter; otherwise set it to
+    /// the load bias of the interpreter. Returns Error::NotFound if the program headers are
+    /// not loaded with the file, Error::Corrupted if their address overflows, and the errors
+    /// of Loader::load for a bad `bias` or `page_size`.
+    pub fn from_file<'a, F: ElfFile<'a>>(
+        file: &F,
+        bias: u64,
+        page_size: u64,
+    ) -> Result<Self, Error> {
+        let loader = Loader::new(file, page_size)?;
+        loader.check_bias(bias)?;
+        let phdr = loader.phdr_vaddr()?.ok_or(Error::NotFound)?;
+        let ehdr = file.ehdr();
+
+        let mut auxv = Self::new();
+        auxv.set(AT_PHDR, phdr.wrapping_add(bias))?
+            .set(AT_PHENT, ehdr.e_phentsize() as u64)?
+            .set(AT_PHNUM, file.phdr_num() as u64)?
+            .set(AT_PAGESZ, page_size)?
+            .set(AT_BASE, 0)?
+            .set(AT_ENTRY, ehdr.e_entry().wrapping_add(bias))?;
+        Ok(auxv)
+    }
+
+    /// Set the value of `a_type`, replacing an earlier one. Returns Error::BufferTooSmall if
+    /// AUXV_CAPACITY entries are already set.
+    pub fn set(&mut self, a_type: AuxType, a_val: u64) -> Result<&mut Self, Error> {
+        if let Some(entry) = self.entries[..self.len]
+            .iter_mut()
+            .find(|entry| entry.a_type == a_type)
+        {
+            entry.a_val = a_val;
+        } else {
+            let entry = self
+                .entries
+                .get_mut(self.len)
+                .ok_or(Error::BufferTooSmall)?;
+            *entry = AuxEntry { a_type, a_val };
+            self.len += 1;
+        }
+        Ok(self)
+    }
+
+    /// Get the value of `a_type`, if it is set.
+    pub fn get(&self, a_type: AuxType) -> Option<u64> {
+        self.entries()
+            .iter()
+            .find(|entry| entry.a_type == a_type)
+            .map(|entry| entry.a_val)
+    }
+
+    /// Get the entries in the order they were first set.
+    pub fn entries(&self) -> &[AuxEntry] {
+        &self.entries[..self.len]
+    }
+}
+
+/// Writes the initial stack of a process as the System V ABI lays it out: argc at the stack
+/// pointer, then the argv pointers, the envp pointers and the auxiliary vector, each
+/// terminated by a null entry, with the strings they point to and the AT_RANDOM bytes above.
+#[derive(Clone, Copy)]
+pub struct InitialStack {
+    word: u64,
+    align: u64,
+    data: Data,
+}
+
+impl InitialStack {
+    /// Get an InitialStack for a process of `machine`, `class` and `data`. Returns
+    /// Error::UnsupportedMachine unless it is a 64-bit x86_64 or AArch64, or a RISC-V process.
+    pub fn new(machine: Machine, class: Class, data: Data) -> Result<Self, Error> {
+        let word = match (machine, class) {
+            (EM_X86_64 | EM_AARCH64 | EM_RISCV, ELFCLASS64) => 8,
+            (EM_RISCV, ELFCLASS32) => 4,
+            _ => return Err(Error::UnsupportedMachine),
+        };
+        // All three ABIs want the stack pointer 16-byte aligned on entry.
+        Ok(InitialStack {
+            word,
+            align: 16,
+            data,
+        })
+    }
+
+    /// Get an InitialStack for running `file`.
+    pub fn for_file<'a, F: ElfFile<'a>>(file: &F) -> Result<Self, Error> {
+        Self::new(Machine(file.ehdr().e_machine()), file.class(), file.data())
+    }
+
+    /// Build the initial stack in `stack`, the memory just below the address `top`, and get
+    /// the stack pointer to start the process with.
+    ///
+    /// The strings of `argv` and `envp` are copied with a terminating NUL, and `random` is
+    /// copied for AT_RANDOM, which is set to its address. Returns Error::BufferTooSmall if
+    /// the stack does not fit in `stack`, and Error::InvalidAlignment if `top` is not
+    /// aligned for the stack pointer.
+    pub fn build(
+        &self,
+        stack: &mut [u8],
+        top: u64,
+        argv: &[&[u8]],
+        envp: &[&[u8]],
+        auxv: &AuxvBuilder,
+        random: &[u8; 16],
+    ) -> Result<u64, Error> {
+        if !top.is_multiple_of(self.align) {
+            return Err(Error::InvalidAlignment);
+        }
+        let bottom = top
+            .checked_sub(stack.len() as u64)
+            .ok_or(Error::BufferTooSmall)?;
+        let mut writer = StackWriter {
+            stack,
+            bottom,
+            data: self.data,
+        };
+
+        // The strings go at the top, in order, with argv below envp.
+        let mut cursor = top;
+        for string in argv.iter().chain(envp).rev() {
+            cursor = cursor
+                .checked_sub(string.len() as u64 + 1)
+                .ok_or(Error::BufferTooSmall)?;
+            writer.bytes(cursor, string)?;
+            writer.bytes(cursor + string.len() as u64, &[0])?;
+        }
+        let strings = cursor;
+        let random_addr = strings.checked_sub(16).ok_or(Error::BufferTooSmall)? & !15;
+        writer.bytes(random_addr, random)?;
+
+        let mut auxv = *auxv;
+        auxv.set(AT_RANDOM, random_addr)?;
+        let words = 1 + (argv.len() + 1 + envp.len() + 1) as u64 + (auxv.len as u64 + 1) * 2;
+        let sp = random_addr
+            .checked_sub(words * self.word)
+            .ok_or(Error::BufferTooSmall)?
+            & !(self.align - 1);
+
+        let mut cursor = sp;
+        let mut word = |writer: &mut StackWriter, value: u64| {
+            let addr = cursor;
+            cursor += self.word;
+            writer.uint(addr, value, self.word)
+        };
+        word(&mut writer, argv.len() as u64)?;
+        let mut string = strings;
+        for vector in [argv, envp] {
+            for s in vector {
+                word(&mut writer, string)?;
+                string += s.len() as u64 + 1;
+            }
+            word(&mut writer, 0)?;
+        }
+        for entry in auxv.entries() {
+            word(&mut writer, entry.a_type.0)?;
+            word(&mut writer, entry.a_val)?;
+        }
+        word(&mut writer, AT_NULL.0)?;
+        word(&mut writer, 0)?;
+        Ok(sp)
+    }
+}
+
+/// Writes to the memory of the stack by address.
+struct StackWriter<'s> {
+    stack: &'s mut [u8],
+    bottom: u64,
+    data: Data,
+}
+
+impl StackWriter<'_> {
+    fn get(&mut self, addr: u64, size: u64) -> Result<&mut [u8], Error> {
+        let start = addr.checked_sub(self.bottom).ok_or(Error::BufferTooSmall)? as usize;
+        self.stack
+            .get_mut(start..start + size as usize)
+            .ok_or(Error::BufferTooSmall)
+    }
+
+    fn bytes(&mut self, addr: u64, bytes: &[u8]) -> Result<(), Error> {
+        self.get(addr, bytes.len() as u64)?.copy_from_slice(bytes);
+        Ok(())
+    }
+
+    fn uint(&mut self, addr: u64, value: u64, size: u64) -> Result<(), Error> {
+        let data = self.data;
+        util::write_uint(self.get(addr, size)?, value, data);
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use crate::Error;
+use core::str;
+
+/// A string table (SHT_STRTAB), a sequence of NUL-terminated strings referenced by offset.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct StrTab<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> StrTab<'a> {
+    /// Get a StrTab over the contents of a string table.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        StrTab { bytes }
+    }
+
+    /// Get the string at `offset` without its terminating NUL.
+    pub fn get(&self, offset: u32) -> Result<&'a [u8], Error> {
+        let tail = self.bytes.get(offset as usize..).ok_or(Error::Corrupted)?;
+        let len = tail.iter().position(|&b| b == 0).ok_or(Error::Corrupted)?;
+        Ok(&tail[..len])
+    }
+
+    /// Get the string at `offset` as a UTF-8 str.
+    pub fn get_str(&self, offset: u32) -> Result<&'a str, Error> {
+        str::from_utf8(self.get(offset)?).map_err(|_| Error::InvalidUtf8)
+    }
+}
@@ -0,0 +1,346 @@
+use crate::{
+    table::{Entry, Table, TableIter},
+    types::{Class, Data, ShIndex, SymBind, SymType, SymVis, ELFCLASS32, ELFCLASS64},
+    util, Error, StrTab,
+};
+use core::fmt;
+
+/// Accessors shared by the symbols of both classes. Values and sizes are widened to u64.
+pub trait Symbol: Entry {
+    fn st_name(&self) -> u32;
+    fn st_value(&self) -> u64;
+    fn st_size(&self) -> u64;
+    fn st_info(&self) -> u8;
+    fn st_other(&self) -> u8;
+    fn st_shndx(&self) -> u16;
+
+    /// Get the binding (STB_*) from st_info.
+    fn bind(&self) -> SymBind {
+        SymBind(self.st_info() >> 4)
+    }
+
+    /// Get the type (STT_*) from st_info.
+    fn sym_type(&self) -> SymType {
+        SymType(self.st_info() & 0xf)
+    }
+
+    /// Get the visibility (STV_*) from st_other.
+    fn visibility(&self) -> SymVis {
+        SymVis(self.st_other() & 0x3)
+    }
+
+    /// Get the index of the section the symbol is defined in, or a SHN_* special index.
+    fn section_index(&self) -> ShIndex {
+        ShIndex(self.st_shndx())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+/// Symbol of ELF32.
+pub struct Elf32Sym {
+    pub st_name: u32,
+    pub st_value: u32,
+    pub st_size: u32,
+    pub st_info: u8,
+    pub st_other: u8,
+    pub st_shndx: u16,
+}
+
+impl Entry for Elf32Sym {
+    fn size(_class: Class) -> usize {
+        16
+    }
+
+    fn parse(bytes: &[u8], _class: Class, data: Data) -> Result<Self, Error> {
+        let bytes = bytes.get(..16).ok_or(Error::Corrupted)?;
+        Ok(Elf32Sym {
+            st_name: util::bytes_to_u32(&bytes[0..4], data),
+            st_value: util::bytes_to_u32(&bytes[4..8], data),
+            st_size: util::bytes_to_u32(&bytes[8..12], data),
+            st_info: bytes[12],
+            st_other: bytes[13],
+            st_shndx: util::bytes_to_u16(&bytes[14..16], data),
+        })
+    }
+}
+
+impl Symbol for Elf32Sym {
+    fn st_name(&self) -> u32 {
+        self.st_name
+    }
+    fn st_value(&self) -> u64 {
+        self.st_value as u64
+    }
+    fn st_size(&self) -> u64 {
+        self.st_size as u64
+    }
+    fn st_info(&self) -> u8 {
+        self.st_info
+    }
+    fn st_other(&self) -> u8 {
+        self.st_other
+    }
+    fn st_shndx(&self) -> u16 {
+        self.st_shndx
+    }
+}
+
+impl fmt::Debug for Elf32Sym {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ELF32Symbol: 
+    name: {:#x}
+    value: {:#x}
+    size: {:#x}
+    bind: {:?}
+    type: {:?}
+    visibility: {:?}
+    shndx: {:?}",
+            self.st_name,
+            self.st_value,
+            self.st_size,
+            self.bind(),
+            self.sym_type(),
+            self.visibility(),
+            self.section_index()
+        ))
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+/// Symbol of ELF64.
+pub struct Elf64Sym {
+    pub st_name: u32,
+    pub st_info: u8,
+    pub st_other: u8,
+    pub st_shndx: u16,
+    pub st_value: u64,
+    pub st_size: u64,
+}
+
+impl Entry for Elf64Sym {
+    fn size(_class: Class) -> usize {
+        24
+    }
+
+    fn parse(bytes: &[u8], _class: Class, data: Data) -> Result<Self, Error> {
+        let bytes = bytes.get(..24).ok_or(Error::Corrupted)?;
+        Ok(Elf64Sym {
+            st_name: util::bytes_to_u32(&bytes[0..4], data),
+            st_info: bytes[4],
+            st_other: bytes[5],
+            st_shndx: util::bytes_to_u16(&bytes[6..8], data),
+            st_value: util::bytes_to_u64(&bytes[8..16], data),
+            st_size: util::bytes_to_u64(&bytes[16..24], data),
+        })
+    }
+}
+
+impl Symbol for Elf64Sym {
+    fn st_name(&self) -> u32 {
+        self.st_name
+    }
+    fn st_value(&self) -> u64 {
+        self.st_value
+    }
+    fn st_size(&self) -> u64 {
+        self.st_size
+    }
+    fn st_info(&self) -> u8 {
+        self.st_info
+    }
+    fn st_other(&self) -> u8 {
+        self.st_other
+    }
+    fn st_shndx(&self) -> u16 {
+        self.st_shndx
+    }
+}
+
+impl fmt::Debug for Elf64Sym {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ELF64Symbol: 
+    name: {:#x}
+    value: {:#x}
+    size: {:#x}
+    bind: {:?}
+    type: {:?}
+    visibility: {:?}
+    shndx: {:?}",
+            self.st_name,
+            self.st_value,
+            self.st_size,
+            self.bind(),
+            self.sym_type(),
+            self.visibility(),
+            self.section_index()
+        ))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+/// Symbol of either class, widened to ELF64 field sizes.
+pub struct Sym {
+    pub st_name: u32,
+    pub st_info: u8,
+    pub st_other: u8,
+    pub st_shndx: u16,
+    pub st_value: u64,
+    pub st_size: u64,
+}
+
+impl From<Elf32Sym> for Sym {
+    fn from(sym: Elf32Sym) -> Self {
+        Sym {
+            st_name: sym.st_name,
+            st_info: sym.st_info,
+            st_other: sym.st_other,
+            st_shndx: sym.st_shndx,
+            st_value: sym.st_value as u64,
+            st_size: sym.st_size as u64,
+        }
+    }
+}
+
+impl From<Elf64Sym> for Sym {
+    fn from(sym: Elf64Sym) -> Self {
+        Sym {
+            st_name: sym.st_name,
+            st_info: sym.st_info,
+            st_other: sym.st_other,
+            st_shndx: sym.st_shndx,
+            st_value: sym.st_value,
+            st_size: sym.st_size,
+        }
+    }
+}
+
+impl Entry for Sym {
+    fn size(class: Class) -> usize {
+        if class == ELFCLASS64 {
+            Elf64Sym::size(class)
+        } else {
+            Elf32Sym::size(class)
+        }
+    }
+
+    fn parse(bytes: &[u8], class: Class, data: Data) -> Result<Self, Error> {
+        match class {
+            ELFCLASS32 => Elf32Sym::parse(bytes, class, data).map(Sym::from),
+            ELFCLASS64 => Elf64Sym::parse(bytes, class, data).map(Sym::from),
+            _ => Err(Error::InvalidClass),
+        }
+    }
+}
+
+impl Symbol for Sym {
+    fn st_name(&self) -> u32 {
+        self.st_name
+    }
+    fn st_value(&self) -> u64 {
+        self.st_value
+    }
+    fn st_size(&self) -> u64 {
+        self.st_size
+    }
+    fn st_info(&self) -> u8 {
+        self.st_info
+    }
+    fn st_other(&self) -> u8 {
+        self.st_other
+    }
+    fn st_shndx(&self) -> u16 {
+        self.st_shndx
+    }
+}
+
+impl fmt::Debug for Sym {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ELFSymbol: 
+    name: {:#x}
+    value: {:#x}
+    size: {:#x}
+    bind: {:?}
+    type: {:?}
+    visibility: {:?}
+    shndx: {:?}",
+            self.st_name,
+            self.st_value,
+            self.st_size,
+            self.bind(),
+            self.sym_type(),
+            self.visibility(),
+            self.section_index()
+        ))
+    }
+}
+
+/// A symbol table (SHT_SYMTAB or SHT_DYNSYM) together with the string table it links to.
+#[derive(Clone, Copy)]
+pub struct SymbolTable<'a, S: Symbol> {
+    table: Table<'a, S>,
+    strtab: StrTab<'a>,
+}
+
+impl<'a, S: Symbol> SymbolTable<'a, S> {
+    /// Get a SymbolTable from its entries and the string table holding the names.
+    pub fn new(table: Table<'a, S>, strtab: StrTab<'a>) -> Self {
+        SymbolTable { table, strtab }
+    }
+
+    /// Get the number of symbols, including the null symbol at index 0.
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    /// Check if the table has no symbols.
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    /// Get a Result<nth symbol, Error>.
+    pub fn get(&self, index: usize) -> Result<S, Error> {
+        self.table.get(index)
+    }
+
+    /// Get the name of a symbol.
+    pub fn name(&self, sym: &S) -> Result<&'a str, Error> {
+        self.strtab.get_str(sym.st_name())
+    }
+
+    /// Get the string table the symbol names are stored in.
+    pub fn strtab(&self) -> StrTab<'a> {
+        self.strtab
+    }
+
+    /// Get an iterator over the symbols without resolving their names.
+    pub fn symbols(&self) -> TableIter<'a, S> {
+        self.table.iter()
+    }
+
+    /// Get an iterator over the symbols and their names.
+    pub fn iter(&self) -> SymbolIter<'a, S> {
+        SymbolIter {
+            symbols: self.table.iter(),
+            strtab: self.strtab,
+        }
+    }
+}
+
+pub struct SymbolIter<'a, S: Symbol> {
+    symbols: TableIter<'a, S>,
+    strtab: StrTab<'a>,
+}
+
+impl<'a, S: Symbol> Iterator for SymbolIter<'a, S> {
+    type Item = Result<(S, &'a str), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let sym = self.symbols.next()?;
+        Some(self.strtab.get_str(sym.st_name()).map(|name| (sym, name)))
+    }
+}
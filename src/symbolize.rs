@@ -0,0 +1,177 @@
+use crate::{
+    types::{
+        SHN_COMMON, SHN_UNDEF, STB_GLOBAL, STB_WEAK, STT_FUNC, STT_GNU_IFUNC, STT_NOTYPE,
+        STT_OBJECT,
+    },
+    Error, StrTab, Symbol, SymbolTable,
+};
+
+/// The address range of a symbol, as stored in the buffer backing a Symbolizer.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct SymbolRange {
+    start: u64,
+    size: u64,
+    /// The largest end address of this range and every range sorted before it.
+    cover_end: u64,
+    st_name: u32,
+    rank: u8,
+}
+
+impl SymbolRange {
+    /// Get the address of the symbol.
+    pub fn start(&self) -> u64 {
+        self.start
+    }
+
+    /// Get the size of the symbol. Zero-sized symbols are labels.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    fn new<S: Symbol>(sym: &S) -> Option<Self> {
+        let sym_type = sym.sym_type();
+        let type_rank = match sym_type {
+            STT_FUNC | STT_GNU_IFUNC => 2,
+            STT_OBJECT => 1,
+            STT_NOTYPE => 0,
+            _ => return None,
+        };
+        let section_index = sym.section_index();
+        if sym.st_name() == 0 || section_index == SHN_UNDEF || section_index == SHN_COMMON {
+            return None;
+        }
+        let bind_rank = match sym.bind() {
+            STB_GLOBAL => 2,
+            STB_WEAK => 1,
+            _ => 0,
+        };
+        Some(SymbolRange {
+            start: sym.st_value(),
+            size: sym.st_size(),
+            cover_end: 0,
+            st_name: sym.st_name(),
+            rank: type_rank * 3 + bind_rank,
+        })
+    }
+
+    fn end(&self) -> u64 {
+        self.start.saturating_add(self.size)
+    }
+}
+
+/// Address to symbol lookup over the functions and objects of a symbol table. The ranges are
+/// kept sorted in a caller-provided buffer, so no allocator is needed.
+pub struct Symbolizer<'a, 'b> {
+    ranges: &'b [SymbolRange],
+    strtab: StrTab<'a>,
+    end: Option<u64>,
+}
+
+impl<'a, 'b> Symbolizer<'a, 'b> {
+    /// Get the number of SymbolRange the buffer passed to `new` must hold for `symtab`.
+    pub fn required_len<S: Symbol>(symtab: &SymbolTable<'a, S>) -> usize {
+        symtab
+            .symbols()
+            .filter(|sym| SymbolRange::new(sym).is_some())
+            .count()
+    }
+
+    /// Get a Symbolizer built into `buf`. Returns Error::BufferTooSmall if `buf` is shorter than
+    /// `required_len(symtab)`.
+    pub fn new<S: Symbol>(
+        symtab: &SymbolTable<'a, S>,
+        buf: &'b mut [SymbolRange],
+    ) -> Result<Self, Error> {
+        let mut len = 0;
+        for range in symtab.symbols().filter_map(|sym| SymbolRange::new(&sym)) {
+            *buf.get_mut(len).ok_or(Error::BufferTooSmall)? = range;
+            len += 1;
+        }
+
+        let ranges = &mut buf[..len];
+        // Within one address, larger ranges come first and the preferred name comes last, so
+        // a backward scan meets the innermost and preferred symbol first.
+        ranges.sort_unstable_by(|a, b| {
+            a.start
+                .cmp(&b.start)
+                .then(b.size.cmp(&a.size))
+                .then(a.rank.cmp(&b.rank))
+                .then(b.st_name.cmp(&a.st_name))
+        });
+        let mut cover_end = 0;
+        for range in ranges.iter_mut() {
+            cover_end = cover_end.max(range.end());
+            range.cover_end = cover_end;
+        }
+
+        Ok(Symbolizer {
+            ranges,
+            strtab: symtab.strtab(),
+            end: None,
+        })
+    }
+
+    /// Set the end of the addresses the symbols describe, such as the end of .text. No
+    /// zero-sized symbol covers addresses at or past it.
+    pub fn set_end(&mut self, end: u64) {
+        self.end = Some(end);
+    }
+
+    /// Get the number of symbols in the lookup table.
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Check if the lookup table has no symbols.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Get the sorted symbol ranges.
+    pub fn ranges(&self) -> &[SymbolRange] {
+        self.ranges
+    }
+
+    /// Get the range of the symbol covering `addr`.
+    ///
+    /// If sized symbols overlap, the one starting closest to `addr` wins. A zero-sized symbol
+    /// covers `addr` only when no sized symbol does and it is the closest symbol below `addr`,
+    /// up to the next symbol and the end set by `set_end`. Without either, it covers only its
+    /// own address.
+    pub fn lookup(&self, addr: u64) -> Option<&SymbolRange> {
+        let below = self.ranges.partition_point(|range| range.start <= addr);
+        let candidates = &self.ranges[..below];
+
+        for range in candidates.iter().rev() {
+            if range.cover_end <= addr {
+                break;
+            }
+            if addr < range.end() {
+                return Some(range);
+            }
+        }
+
+        let last = candidates.last()?;
+        let first_at_last = candidates.partition_point(|range| range.start < last.start);
+        if candidates[first_at_last].size != 0 {
+            return None;
+        }
+        let next = self.ranges.get(below).map(|range| range.start);
+        let end = match (next, self.end) {
+            (Some(next), Some(end)) => next.min(end),
+            (Some(end), None) | (None, Some(end)) => end,
+            (None, None) => last.start.saturating_add(1),
+        };
+        if addr >= end {
+            return None;
+        }
+        Some(last)
+    }
+
+    /// Get the name of the symbol covering `addr` and the offset of `addr` into it.
+    pub fn symbolize(&self, addr: u64) -> Option<(&'a str, u64)> {
+        let range = self.lookup(addr)?;
+        let name = self.strtab.get_str(range.st_name).ok()?;
+        Some((name, addr - range.start))
+    }
+}
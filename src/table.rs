@@ -0,0 +1,85 @@
+use crate::{types::Class, types::Data, Error};
+use core::marker::PhantomData;
+
+/// A fixed-size entry of a table in the file, such as a symbol or a relocation.
+pub trait Entry: Copy {
+    /// Get the size of the entry in a file of `class`.
+    fn size(class: Class) -> usize;
+
+    /// Decode an entry from the first `size(class)` bytes.
+    fn parse(bytes: &[u8], class: Class, data: Data) -> Result<Self, Error>;
+}
+
+/// A table of fixed-size entries, such as the contents of a SHT_SYMTAB or SHT_RELA section.
+#[derive(Clone, Copy)]
+pub struct Table<'a, T: Entry> {
+    bytes: &'a [u8],
+    entsize: usize,
+    class: Class,
+    data: Data,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T: Entry> Table<'a, T> {
+    /// Get a table over `bytes` whose entries are `entsize` bytes apart. An entsize of 0 means
+    /// the entries are packed.
+    pub fn new(bytes: &'a [u8], entsize: u64, class: Class, data: Data) -> Result<Self, Error> {
+        let size = T::size(class);
+        let entsize = match entsize {
+            0 => size,
+            entsize => usize::try_from(entsize).map_err(|_| Error::Corrupted)?,
+        };
+        if entsize < size {
+            return Err(Error::Corrupted);
+        }
+        Ok(Table {
+            bytes,
+            entsize,
+            class,
+            data,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Get the number of entries.
+    pub fn len(&self) -> usize {
+        self.bytes.len() / self.entsize
+    }
+
+    /// Check if the table has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get a Result<nth entry, Error>.
+    pub fn get(&self, index: usize) -> Result<T, Error> {
+        if index >= self.len() {
+            return Err(Error::InvalidIndex);
+        }
+        let start = index * self.entsize;
+        T::parse(&self.bytes[start..], self.class, self.data)
+    }
+
+    /// Get an entry iterator.
+    pub fn iter(&self) -> TableIter<'a, T> {
+        TableIter {
+            index: 0,
+            table: *self,
+        }
+    }
+}
+
+pub struct TableIter<'a, T: Entry> {
+    index: usize,
+    table: Table<'a, T>,
+}
+
+impl<T: Entry> Iterator for TableIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.table.get(self.index).ok()?;
+        self.index += 1;
+        Some(entry)
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{
+    types::{
+        Class, Data, Machine, ELFCLASS32, ELFCLASS64, EM_386, EM_AARCH64, EM_RISCV, EM_X86_64,
+    },
+    util, Error,
+};
+use core::fmt;
+
+/// Where the thread pointer is relative to the TLS block of the executable.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TlsVariant {
+    /// The TLS block follows the thread pointer, after a TCB of a size fixed by the ABI
+    /// (AArch64, RISC-V).
+    I,
+    /// The TLS block ends at the thread pointer, which points to the TCB and holds its own
+    /// address (x86_64, i386).
+    II,
+}
+
+/// The initialization image of the thread-local storage of a file, from its PT_TLS segment.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct TlsTemplate<'a> {
+    image: &'a [u8],
+    size: u64,
+    align: u64,
+    machine: Machine,
+    class: Class,
+    data: Data,
+}
+
+impl fmt::Debug for TlsTemplate<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "ELFTlsTemplate:
+    image: {:#x} bytes
+    size: {:#x}
+    align: {:#x}
+    machine: {:?}",
+            self.image.len(),
+            self.size,
+            self.align,
+            self.machine
+        ))
+    }
+}
+
+/// The layout of a block that holds the TLS of one thread and the thread control block (TCB)
+/// of the caller. The offsets are from the start of the block.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TlsLayout {
+    pub variant: TlsVariant,
+    /// The size of the block.
+    pub size: u64,
+    /// The alignment of the block.
+    pub align: u64,
+    /// The offset of the address the thread pointer is set to.
+    pub tp_offset: u64,
+    /// The offset of the TLS block of the file.
+    pub tls_offset: u64,
+    /// The offset of the TCB of the caller.
+    pub tcb_offset: u64,
+}
+
+impl<'a> TlsTemplate<'a> {
+    /// Get a TlsTemplate for a PT_TLS segment with the contents `image`, p_memsz `size` and
+    /// p_align `align`, of a file of `machine`, `class` and `data`. Returns
+    /// Error::InvalidAlignment unless `align` is 0 or a power of two, and Error::Corrupted if
+    /// `image` is larger than `size`.
+    pub fn new(
+        image: &'a [u8],
+        size: u64,
+        align: u64,
+        machine: Machine,
+        class: Class,
+        data: Data,
+    ) -> Result<Self, Error> {
+        let align = align.max(1);
+        if !align.is_power_of_two() {
+            return Err(Error::InvalidAlignment);
+        }
+        if (image.len() as u64) > size {
+            return Err(Error::Corrupted);
+        }
+        Ok(TlsTemplate {
+            image,
+            size,
+            align,
+            machine,
+            class,
+            data,
+        })
+    }
+
+    /// Get the initialized data (p_filesz bytes). The rest of the block is zeroed.
+    pub fn image(&self) -> &'a [u8] {
+        self.image
+    }
+
+    /// Get the size of the TLS block (p_memsz).
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Get the alignment of the TLS block, a power of two.
+    pub fn align(&self) -> u64 {
+        self.align
+    }
+
+    /// Get the TLS variant of the machine, and the size of the TCB the ABI puts between the
+    /// thread pointer and the TLS block in variant I. Returns Error::UnsupportedMachine unless
+    /// it is x86_64, i386, AArch64 or RISC-V.
+    #[inline]
+    fn abi(&self) -> Result<(TlsVariant, u64), Error> {
+        match (self.machine, self.class) {
+            (EM_X86_64, ELFCLASS64) | (EM_386, ELFCLASS32) => Ok((TlsVariant::II, 0)),
+            (EM_AARCH64, ELFCLASS64) => Ok((TlsVariant::I, 16)),
+            (EM_RISCV, ELFCLASS32 | ELFCLASS64) => Ok((TlsVariant::I, 0)),
+            _ => Err(Error::UnsupportedMachine),
+        }
+    }
+
+    /// Get the layout of a block with room for a TCB of `tcb_size` bytes.
+    ///
+    /// In variant I, the TCB ends where the TLS block and the TCB of the ABI begin, at the
+    /// thread pointer. In variant II, the TCB begins at the thread pointer, right after the
+    /// TLS block, and is at least a word for the pointer to itself.
+    pub fn layout(&self, tcb_size: u64) -> Result<TlsLayout, Error> {
+        let (variant, abi_tcb) = self.abi()?;
+        let word = if self.class == ELFCLASS64 { 8 } else { 4 };
+        // Keep the TCB aligned for two words, like the stack.
+        let align = self.align.max(word * 2);
+        let overflow = || Error::Corrupted;
+        let align_up = |value: u64, align: u64| {
+            value
+                .checked_add(align - 1)
+                .map(|value| value & !(align - 1))
+                .ok_or_else(overflow)
+        };
+
+        Ok(match variant {
+            TlsVariant::I => {
+                let tp_offset = align_up(tcb_size, align)?;
+                let tls_offset = tp_offset
+                    .checked_add(align_up(abi_tcb, self.align)?)
+                    .ok_or_else(overflow)?;
+                TlsLayout {
+                    variant,
+                    size: tls_offset.checked_add(self.size).ok_or_else(overflow)?,
+                    align,
+                    tp_offset,
+                    tls_offset,
+                    tcb_offset: tp_offset - tcb_size,
+                }
+            }
+            TlsVariant::II => {
+                let tp_offset = align_up(self.size, align)?;
+                TlsLayout {
+                    variant,
+                    size: tp_offset
+                        .checked_add(tcb_size.max(word))
+                        .ok_or_else(overflow)?,
+                    align,
+                    tp_offset,
+                    tls_offset: tp_offset - align_up(self.size, self.align)?,
+                    tcb_offset: tp_offset,
+                }
+            }
+        })
+    }
+
+    /// Initialize the TLS in `block`, laid out as `layout`, which is at the address `addr`, and
+    /// get the value of the thread pointer.
+    ///
+    /// The TLS block is filled with the image and zeroes, and so is the TCB of the ABI in
+    /// variant I. In variant II, the first word of the TCB is set to the thread pointer. The
+    /// rest of the TCB is left to the caller. Returns Error::BufferTooSmall if `block` is
+    /// smaller than layout.size, and Error::InvalidAlignment if `addr` is not aligned to
+    /// layout.align.
+    pub fn initialize(
+        &self,
+        layout: &TlsLayout,
+        block: &mut [u8],
+        addr: u64,
+    ) -> Result<u64, Error> {
+        if !addr.is_multiple_of(layout.align) {
+            return Err(Error::InvalidAlignment);
+        }
+        if (block.len() as u64) < layout.size {
+            return Err(Error::BufferTooSmall);
+        }
+        let tp = addr.checked_add(layout.tp_offset).ok_or(Error::Corrupted)?;
+
+        match layout.variant {
+            TlsVariant::I => {
+                let abi_tcb = layout.tls_offset.saturating_sub(layout.tp_offset);
+                part(block, layout.tp_offset, abi_tcb)?.fill(0);
+            }
+            TlsVariant::II => {
+                let word = if self.class == ELFCLASS64 { 8 } else { 4 };
+                util::write_uint(part(block, layout.tcb_offset, word)?, tp, self.data);
+            }
+        }
+        let tls = part(block, layout.tls_offset, self.size)?;
+        let (data, bss) = tls.split_at_mut(self.image.len());
+        data.copy_from_slice(self.image);
+        bss.fill(0);
+        Ok(tp)
+    }
+}
+
+/// Get `len` bytes at `offset` of the block.
+fn part(block: &mut [u8], offset: u64, len: u64) -> Result<&mut [u8], Error> {
+    let start = usize::try_from(offset).map_err(|_| Error::BufferTooSmall)?;
+    let len = usize::try_from(len).map_err(|_| Error::BufferTooSmall)?;
+    start
+        .checked_add(len)
+        .and_then(|end| block.get_mut(start..end))
+        .ok_or(Error::BufferTooSmall)
+}
@@ -1,6 +1,8 @@
 use core::fmt;
 
 pub const EI_NIDENT: usize = 16;
+pub const EI_CLASS: usize = 4;
+pub const EI_DATA: usize = 5;
 pub const MAGIC_NUM: [u8; 4] = [0x7f, b'E', b'L', b'F'];
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -161,6 +163,13 @@ pub const ELFCLASSNONE: Class = Class(0);
 pub const ELFCLASS32: Class = Class(1);
 pub const ELFCLASS64: Class = Class(2);
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Data(pub u8);
+
+pub const ELFDATANONE: Data = Data(0);
+pub const ELFDATA2LSB: Data = Data(1);
+pub const ELFDATA2MSB: Data = Data(2);
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct PType(pub u32);
 pub const PT_NULL: PType = PType(0);
@@ -179,6 +188,9 @@ pub const PT_GNU_EH_FRAME: PType = PType(0x6474e550);
 pub const PT_GNU_PROPERTY: PType = PType(0x6474e553);
 pub const PT_GNU_STACK: PType = PType(0x6474e551);
 
+/// e_phnum value meaning that the real number of program headers is in sh_info of section 0.
+pub const PN_XNUM: u16 = 0xffff;
+
 impl fmt::Debug for PType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let name = match self.0 {
@@ -206,6 +218,17 @@ impl fmt::Debug for PType {
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct PFlag(pub u32);
 
+pub const PF_X: PFlag = PFlag(1);
+pub const PF_W: PFlag = PFlag(2);
+pub const PF_R: PFlag = PFlag(4);
+
+impl PFlag {
+    /// Check if every flag of `flags` is set.
+    pub fn contains(&self, flags: PFlag) -> bool {
+        self.0 & flags.0 == flags.0
+    }
+}
+
 impl fmt::Debug for PFlag {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let name = match self.0 {
@@ -239,6 +262,10 @@ pub const SHT_REL: ShType = ShType(9);
 pub const SHT_SHLIB: ShType = ShType(10);
 pub const SHT_DYNSYM: ShType = ShType(11);
 pub const SHT_NUM: ShType = ShType(12);
+pub const SHT_GNU_HASH: ShType = ShType(0x6ffffff6);
+pub const SHT_GNU_VERDEF: ShType = ShType(0x6ffffffd);
+pub const SHT_GNU_VERNEED: ShType = ShType(0x6ffffffe);
+pub const SHT_GNU_VERSYM: ShType = ShType(0x6fffffff);
 pub const SHT_LOPROC: ShType = ShType(0x70000000);
 pub const SHT_HIPROC: ShType = ShType(0x7fffffff);
 pub const SHT_LOUSER: ShType = ShType(0x80000000);
@@ -260,6 +287,10 @@ impl fmt::Debug for ShType {
             10 => "SHT_SHLIB",
             11 => "SHT_DYNSYM",
             12 => "SHT_NUM",
+            0x6ffffff6 => "SHT_GNU_HASH",
+            0x6ffffffd => "SHT_GNU_VERDEF",
+            0x6ffffffe => "SHT_GNU_VERNEED",
+            0x6fffffff => "SHT_GNU_VERSYM",
             0x70000000 => "SHT_LOPROC",
             0x7fffffff => "SHT_HIPROC",
             0x80000000 => "SHT_LOUSER",
@@ -270,6 +301,119 @@ impl fmt::Debug for ShType {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ShIndex(pub u16);
+
+pub const SHN_UNDEF: ShIndex = ShIndex(0);
+pub const SHN_LORESERVE: ShIndex = ShIndex(0xff00);
+pub const SHN_LOPROC: ShIndex = ShIndex(0xff00);
+pub const SHN_HIPROC: ShIndex = ShIndex(0xff1f);
+pub const SHN_LOOS: ShIndex = ShIndex(0xff20);
+pub const SHN_HIOS: ShIndex = ShIndex(0xff3f);
+pub const SHN_ABS: ShIndex = ShIndex(0xfff1);
+pub const SHN_COMMON: ShIndex = ShIndex(0xfff2);
+pub const SHN_XINDEX: ShIndex = ShIndex(0xffff);
+pub const SHN_HIRESERVE: ShIndex = ShIndex(0xffff);
+
+impl fmt::Debug for ShIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self.0 {
+            0 => "SHN_UNDEF",
+            0xff00..=0xff1f => "SHN_PROC",
+            0xff20..=0xff3f => "SHN_OS",
+            0xfff1 => "SHN_ABS",
+            0xfff2 => "SHN_COMMON",
+            0xffff => "SHN_XINDEX",
+            0xff40..=0xfffe => "SHN_RESERVE",
+            _ => return f.write_fmt(format_args!("{}", self.0)),
+        };
+        f.write_fmt(format_args!("{}", name))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SymBind(pub u8);
+
+pub const STB_LOCAL: SymBind = SymBind(0);
+pub const STB_GLOBAL: SymBind = SymBind(1);
+pub const STB_WEAK: SymBind = SymBind(2);
+pub const STB_GNU_UNIQUE: SymBind = SymBind(10);
+pub const STB_LOOS: SymBind = SymBind(10);
+pub const STB_HIOS: SymBind = SymBind(12);
+pub const STB_LOPROC: SymBind = SymBind(13);
+pub const STB_HIPROC: SymBind = SymBind(15);
+
+impl fmt::Debug for SymBind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self.0 {
+            0 => "STB_LOCAL",
+            1 => "STB_GLOBAL",
+            2 => "STB_WEAK",
+            10 => "STB_GNU_UNIQUE",
+            11..=12 => "STB_OS",
+            13..=15 => "STB_PROC",
+            _ => "UNKNOWN",
+        };
+        f.write_fmt(format_args!("{}", name))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SymType(pub u8);
+
+pub const STT_NOTYPE: SymType = SymType(0);
+pub const STT_OBJECT: SymType = SymType(1);
+pub const STT_FUNC: SymType = SymType(2);
+pub const STT_SECTION: SymType = SymType(3);
+pub const STT_FILE: SymType = SymType(4);
+pub const STT_COMMON: SymType = SymType(5);
+pub const STT_TLS: SymType = SymType(6);
+pub const STT_GNU_IFUNC: SymType = SymType(10);
+pub const STT_LOOS: SymType = SymType(10);
+pub const STT_HIOS: SymType = SymType(12);
+pub const STT_LOPROC: SymType = SymType(13);
+pub const STT_HIPROC: SymType = SymType(15);
+
+impl fmt::Debug for SymType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self.0 {
+            0 => "STT_NOTYPE",
+            1 => "STT_OBJECT",
+            2 => "STT_FUNC",
+            3 => "STT_SECTION",
+            4 => "STT_FILE",
+            5 => "STT_COMMON",
+            6 => "STT_TLS",
+            10 => "STT_GNU_IFUNC",
+            11..=12 => "STT_OS",
+            13..=15 => "STT_PROC",
+            _ => "UNKNOWN",
+        };
+        f.write_fmt(format_args!("{}", name))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SymVis(pub u8);
+
+pub const STV_DEFAULT: SymVis = SymVis(0);
+pub const STV_INTERNAL: SymVis = SymVis(1);
+pub const STV_HIDDEN: SymVis = SymVis(2);
+pub const STV_PROTECTED: SymVis = SymVis(3);
+
+impl fmt::Debug for SymVis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self.0 {
+            0 => "STV_DEFAULT",
+            1 => "STV_INTERNAL",
+            2 => "STV_HIDDEN",
+            3 => "STV_PROTECTED",
+            _ => "UNKNOWN",
+        };
+        f.write_fmt(format_args!("{}", name))
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct ShFlag(pub u64);
 
@@ -310,3 +454,586 @@ impl fmt::Debug for ShFlag {
         ))
     }
 }
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct DynTag(pub i64);
+
+pub const DT_NULL: DynTag = DynTag(0);
+pub const DT_NEEDED: DynTag = DynTag(1);
+pub const DT_PLTRELSZ: DynTag = DynTag(2);
+pub const DT_PLTGOT: DynTag = DynTag(3);
+pub const DT_HASH: DynTag = DynTag(4);
+pub const DT_STRTAB: DynTag = DynTag(5);
+pub const DT_SYMTAB: DynTag = DynTag(6);
+pub const DT_RELA: DynTag = DynTag(7);
+pub const DT_RELASZ: DynTag = DynTag(8);
+pub const DT_RELAENT: DynTag = DynTag(9);
+pub const DT_STRSZ: DynTag = DynTag(10);
+pub const DT_SYMENT: DynTag = DynTag(11);
+pub const DT_INIT: DynTag = DynTag(12);
+pub const DT_FINI: DynTag = DynTag(13);
+pub const DT_SONAME: DynTag = DynTag(14);
+pub const DT_RPATH: DynTag = DynTag(15);
+pub const DT_SYMBOLIC: DynTag = DynTag(16);
+pub const DT_REL: DynTag = DynTag(17);
+pub const DT_RELSZ: DynTag = DynTag(18);
+pub const DT_RELENT: DynTag = DynTag(19);
+pub const DT_PLTREL: DynTag = DynTag(20);
+pub const DT_DEBUG: DynTag = DynTag(21);
+pub const DT_TEXTREL: DynTag = DynTag(22);
+pub const DT_JMPREL: DynTag = DynTag(23);
+pub const DT_BIND_NOW: DynTag = DynTag(24);
+pub const DT_INIT_ARRAY: DynTag = DynTag(25);
+pub const DT_FINI_ARRAY: DynTag = DynTag(26);
+pub const DT_INIT_ARRAYSZ: DynTag = DynTag(27);
+pub const DT_FINI_ARRAYSZ: DynTag = DynTag(28);
+pub const DT_RUNPATH: DynTag = DynTag(29);
+pub const DT_FLAGS: DynTag = DynTag(30);
+pub const DT_ENCODING: DynTag = DynTag(32);
+pub const DT_PREINIT_ARRAY: DynTag = DynTag(32);
+pub const DT_PREINIT_ARRAYSZ: DynTag = DynTag(33);
+pub const DT_SYMTAB_SHNDX: DynTag = DynTag(34);
+pub const DT_RELRSZ: DynTag = DynTag(35);
+pub const DT_RELR: DynTag = DynTag(36);
+pub const DT_RELRENT: DynTag = DynTag(37);
+pub const DT_LOOS: DynTag = DynTag(0x6000000d);
+pub const DT_HIOS: DynTag = DynTag(0x6ffff000);
+pub const DT_VALRNGLO: DynTag = DynTag(0x6ffffd00);
+pub const DT_GNU_PRELINKED: DynTag = DynTag(0x6ffffdf5);
+pub const DT_GNU_CONFLICTSZ: DynTag = DynTag(0x6ffffdf6);
+pub const DT_GNU_LIBLISTSZ: DynTag = DynTag(0x6ffffdf7);
+pub const DT_CHECKSUM: DynTag = DynTag(0x6ffffdf8);
+pub const DT_PLTPADSZ: DynTag = DynTag(0x6ffffdf9);
+pub const DT_MOVEENT: DynTag = DynTag(0x6ffffdfa);
+pub const DT_MOVESZ: DynTag = DynTag(0x6ffffdfb);
+pub const DT_FEATURE_1: DynTag = DynTag(0x6ffffdfc);
+pub const DT_POSFLAG_1: DynTag = DynTag(0x6ffffdfd);
+pub const DT_SYMINSZ: DynTag = DynTag(0x6ffffdfe);
+pub const DT_SYMINENT: DynTag = DynTag(0x6ffffdff);
+pub const DT_VALRNGHI: DynTag = DynTag(0x6ffffdff);
+pub const DT_ADDRRNGLO: DynTag = DynTag(0x6ffffe00);
+pub const DT_GNU_HASH: DynTag = DynTag(0x6ffffef5);
+pub const DT_TLSDESC_PLT: DynTag = DynTag(0x6ffffef6);
+pub const DT_TLSDESC_GOT: DynTag = DynTag(0x6ffffef7);
+pub const DT_GNU_CONFLICT: DynTag = DynTag(0x6ffffef8);
+pub const DT_GNU_LIBLIST: DynTag = DynTag(0x6ffffef9);
+pub const DT_CONFIG: DynTag = DynTag(0x6ffffefa);
+pub const DT_DEPAUDIT: DynTag = DynTag(0x6ffffefb);
+pub const DT_AUDIT: DynTag = DynTag(0x6ffffefc);
+pub const DT_PLTPAD: DynTag = DynTag(0x6ffffefd);
+pub const DT_MOVETAB: DynTag = DynTag(0x6ffffefe);
+pub const DT_SYMINFO: DynTag = DynTag(0x6ffffeff);
+pub const DT_ADDRRNGHI: DynTag = DynTag(0x6ffffeff);
+pub const DT_VERSYM: DynTag = DynTag(0x6ffffff0);
+pub const DT_RELACOUNT: DynTag = DynTag(0x6ffffff9);
+pub const DT_RELCOUNT: DynTag = DynTag(0x6ffffffa);
+pub const DT_FLAGS_1: DynTag = DynTag(0x6ffffffb);
+pub const DT_VERDEF: DynTag = DynTag(0x6ffffffc);
+pub const DT_VERDEFNUM: DynTag = DynTag(0x6ffffffd);
+pub const DT_VERNEED: DynTag = DynTag(0x6ffffffe);
+pub const DT_VERNEEDNUM: DynTag = DynTag(0x6fffffff);
+pub const DT_LOPROC: DynTag = DynTag(0x70000000);
+pub const DT_AUXILIARY: DynTag = DynTag(0x7ffffffd);
+pub const DT_FILTER: DynTag = DynTag(0x7fffffff);
+pub const DT_HIPROC: DynTag = DynTag(0x7fffffff);
+
+impl fmt::Debug for DynTag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self.0 {
+            0 => "DT_NULL",
+            1 => "DT_NEEDED",
+            2 => "DT_PLTRELSZ",
+            3 => "DT_PLTGOT",
+            4 => "DT_HASH",
+            5 => "DT_STRTAB",
+            6 => "DT_SYMTAB",
+            7 => "DT_RELA",
+            8 => "DT_RELASZ",
+            9 => "DT_RELAENT",
+            10 => "DT_STRSZ",
+            11 => "DT_SYMENT",
+            12 => "DT_INIT",
+            13 => "DT_FINI",
+            14 => "DT_SONAME",
+            15 => "DT_RPATH",
+            16 => "DT_SYMBOLIC",
+            17 => "DT_REL",
+            18 => "DT_RELSZ",
+            19 => "DT_RELENT",
+            20 => "DT_PLTREL",
+            21 => "DT_DEBUG",
+            22 => "DT_TEXTREL",
+            23 => "DT_JMPREL",
+            24 => "DT_BIND_NOW",
+            25 => "DT_INIT_ARRAY",
+            26 => "DT_FINI_ARRAY",
+            27 => "DT_INIT_ARRAYSZ",
+            28 => "DT_FINI_ARRAYSZ",
+            29 => "DT_RUNPATH",
+            30 => "DT_FLAGS",
+            32 => "DT_PREINIT_ARRAY",
+            33 => "DT_PREINIT_ARRAYSZ",
+            34 => "DT_SYMTAB_SHNDX",
+            35 => "DT_RELRSZ",
+            36 => "DT_RELR",
+            37 => "DT_RELRENT",
+            0x6ffffdf5 => "DT_GNU_PRELINKED",
+            0x6ffffdf6 => "DT_GNU_CONFLICTSZ",
+            0x6ffffdf7 => "DT_GNU_LIBLISTSZ",
+            0x6ffffdf8 => "DT_CHECKSUM",
+            0x6ffffdf9 => "DT_PLTPADSZ",
+            0x6ffffdfa => "DT_MOVEENT",
+            0x6ffffdfb => "DT_MOVESZ",
+            0x6ffffdfc => "DT_FEATURE_1",
+            0x6ffffdfd => "DT_POSFLAG_1",
+            0x6ffffdfe => "DT_SYMINSZ",
+            0x6ffffdff => "DT_SYMINENT",
+            0x6ffffef5 => "DT_GNU_HASH",
+            0x6ffffef6 => "DT_TLSDESC_PLT",
+            0x6ffffef7 => "DT_TLSDESC_GOT",
+            0x6ffffef8 => "DT_GNU_CONFLICT",
+            0x6ffffef9 => "DT_GNU_LIBLIST",
+            0x6ffffefa => "DT_CONFIG",
+            0x6ffffefb => "DT_DEPAUDIT",
+            0x6ffffefc => "DT_AUDIT",
+            0x6ffffefd => "DT_PLTPAD",
+            0x6ffffefe => "DT_MOVETAB",
+            0x6ffffeff => "DT_SYMINFO",
+            0x6ffffff0 => "DT_VERSYM",
+            0x6ffffff9 => "DT_RELACOUNT",
+            0x6ffffffa => "DT_RELCOUNT",
+            0x6ffffffb => "DT_FLAGS_1",
+            0x6ffffffc => "DT_VERDEF",
+            0x6ffffffd => "DT_VERDEFNUM",
+            0x6ffffffe => "DT_VERNEED",
+            0x6fffffff => "DT_VERNEEDNUM",
+            0x7ffffffd => "DT_AUXILIARY",
+            0x7fffffff => "DT_FILTER",
+            0x6000000d..=0x6ffff000 => "DT_OS",
+            0x70000000..=0x7fffffff => "DT_PROC",
+            _ => "UNKNOWN",
+        };
+        f.write_fmt(format_args!("{}", name))
+    }
+}
+
+/// Write the names of the flags set in `bits`, each followed by a space.
+fn write_flags(f: &mut fmt::Formatter, bits: u64, names: &[(u64, &str)]) -> fmt::Result {
+    for &(flag, name) in names {
+        if bits & flag != 0 {
+            f.write_fmt(format_args!("{} ", name))?;
+        }
+    }
+    Ok(())
+}
+
+/// Flags of DT_FLAGS.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct DynFlags(pub u64);
+
+pub const DF_ORIGIN: DynFlags = DynFlags(0x1);
+pub const DF_SYMBOLIC: DynFlags = DynFlags(0x2);
+pub const DF_TEXTREL: DynFlags = DynFlags(0x4);
+pub const DF_BIND_NOW: DynFlags = DynFlags(0x8);
+pub const DF_STATIC_TLS: DynFlags = DynFlags(0x10);
+
+impl DynFlags {
+    /// Check if every flag of `flags` is set.
+    pub fn contains(&self, flags: DynFlags) -> bool {
+        self.0 & flags.0 == flags.0
+    }
+}
+
+impl fmt::Debug for DynFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_flags(
+            f,
+            self.0,
+            &[
+                (0x1, "DF_ORIGIN"),
+                (0x2, "DF_SYMBOLIC"),
+                (0x4, "DF_TEXTREL"),
+                (0x8, "DF_BIND_NOW"),
+                (0x10, "DF_STATIC_TLS"),
+            ],
+        )
+    }
+}
+
+/// Flags of DT_FLAGS_1.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct DynFlags1(pub u64);
+
+pub const DF_1_NOW: DynFlags1 = DynFlags1(0x1);
+pub const DF_1_GLOBAL: DynFlags1 = DynFlags1(0x2);
+pub const DF_1_GROUP: DynFlags1 = DynFlags1(0x4);
+pub const DF_1_NODELETE: DynFlags1 = DynFlags1(0x8);
+pub const DF_1_LOADFLTR: DynFlags1 = DynFlags1(0x10);
+pub const DF_1_INITFIRST: DynFlags1 = DynFlags1(0x20);
+pub const DF_1_NOOPEN: DynFlags1 = DynFlags1(0x40);
+pub const DF_1_ORIGIN: DynFlags1 = DynFlags1(0x80);
+pub const DF_1_DIRECT: DynFlags1 = DynFlags1(0x100);
+pub const DF_1_TRANS: DynFlags1 = DynFlags1(0x200);
+pub const DF_1_INTERPOSE: DynFlags1 = DynFlags1(0x400);
+pub const DF_1_NODEFLIB: DynFlags1 = DynFlags1(0x800);
+pub const DF_1_NODUMP: DynFlags1 = DynFlags1(0x1000);
+pub const DF_1_CONFALT: DynFlags1 = DynFlags1(0x2000);
+pub const DF_1_ENDFILTEE: DynFlags1 = DynFlags1(0x4000);
+pub const DF_1_DISPRELDNE: DynFlags1 = DynFlags1(0x8000);
+pub const DF_1_DISPRELPND: DynFlags1 = DynFlags1(0x10000);
+pub const DF_1_NODIRECT: DynFlags1 = DynFlags1(0x20000);
+pub const DF_1_IGNMULDEF: DynFlags1 = DynFlags1(0x40000);
+pub const DF_1_NOKSYMS: DynFlags1 = DynFlags1(0x80000);
+pub const DF_1_NOHDR: DynFlags1 = DynFlags1(0x100000);
+pub const DF_1_EDITED: DynFlags1 = DynFlags1(0x200000);
+pub const DF_1_NORELOC: DynFlags1 = DynFlags1(0x400000);
+pub const DF_1_SYMINTPOSE: DynFlags1 = DynFlags1(0x800000);
+pub const DF_1_GLOBAUDIT: DynFlags1 = DynFlags1(0x1000000);
+pub const DF_1_SINGLETON: DynFlags1 = DynFlags1(0x2000000);
+pub const DF_1_STUB: DynFlags1 = DynFlags1(0x4000000);
+pub const DF_1_PIE: DynFlags1 = DynFlags1(0x8000000);
+pub const DF_1_KMOD: DynFlags1 = DynFlags1(0x10000000);
+pub const DF_1_WEAKFILTER: DynFlags1 = DynFlags1(0x20000000);
+pub const DF_1_NOCOMMON: DynFlags1 = DynFlags1(0x40000000);
+
+impl DynFlags1 {
+    /// Check if every flag of `flags` is set.
+    pub fn contains(&self, flags: DynFlags1) -> bool {
+        self.0 & flags.0 == flags.0
+    }
+}
+
+impl fmt::Debug for DynFlags1 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_flags(
+            f,
+            self.0,
+            &[
+                (0x1, "DF_1_NOW"),
+                (0x2, "DF_1_GLOBAL"),
+                (0x4, "DF_1_GROUP"),
+                (0x8, "DF_1_NODELETE"),
+                (0x10, "DF_1_LOADFLTR"),
+                (0x20, "DF_1_INITFIRST"),
+                (0x40, "DF_1_NOOPEN"),
+                (0x80, "DF_1_ORIGIN"),
+                (0x100, "DF_1_DIRECT"),
+                (0x200, "DF_1_TRANS"),
+                (0x400, "DF_1_INTERPOSE"),
+                (0x800, "DF_1_NODEFLIB"),
+                (0x1000, "DF_1_NODUMP"),
+                (0x2000, "DF_1_CONFALT"),
+                (0x4000, "DF_1_ENDFILTEE"),
+                (0x8000, "DF_1_DISPRELDNE"),
+                (0x10000, "DF_1_DISPRELPND"),
+                (0x20000, "DF_1_NODIRECT"),
+                (0x40000, "DF_1_IGNMULDEF"),
+                (0x80000, "DF_1_NOKSYMS"),
+                (0x100000, "DF_1_NOHDR"),
+                (0x200000, "DF_1_EDITED"),
+                (0x400000, "DF_1_NORELOC"),
+                (0x800000, "DF_1_SYMINTPOSE"),
+                (0x1000000, "DF_1_GLOBAUDIT"),
+                (0x2000000, "DF_1_SINGLETON"),
+                (0x4000000, "DF_1_STUB"),
+                (0x8000000, "DF_1_PIE"),
+                (0x10000000, "DF_1_KMOD"),
+                (0x20000000, "DF_1_WEAKFILTER"),
+                (0x40000000, "DF_1_NOCOMMON"),
+            ],
+        )
+    }
+}
+
+/// Type of a note whose owner is "GNU".
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct GnuNoteType(pub u32);
+
+pub const NT_GNU_ABI_TAG: GnuNoteType = GnuNoteType(1);
+pub const NT_GNU_HWCAP: GnuNoteType = GnuNoteType(2);
+pub const NT_GNU_BUILD_ID: GnuNoteType = GnuNoteType(3);
+pub const NT_GNU_GOLD_VERSION: GnuNoteType = GnuNoteType(4);
+pub const NT_GNU_PROPERTY_TYPE_0: GnuNoteType = GnuNoteType(5);
+
+impl fmt::Debug for GnuNoteType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self.0 {
+            1 => "NT_GNU_ABI_TAG",
+            2 => "NT_GNU_HWCAP",
+            3 => "NT_GNU_BUILD_ID",
+            4 => "NT_GNU_GOLD_VERSION",
+            5 => "NT_GNU_PROPERTY_TYPE_0",
+            _ => "UNKNOWN",
+        };
+        f.write_fmt(format_args!("{}", name))
+    }
+}
+
+/// Operating system of a NT_GNU_ABI_TAG note.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct NoteOs(pub u32);
+
+pub const ELF_NOTE_OS_LINUX: NoteOs = NoteOs(0);
+pub const ELF_NOTE_OS_GNU: NoteOs = NoteOs(1);
+pub const ELF_NOTE_OS_SOLARIS2: NoteOs = NoteOs(2);
+pub const ELF_NOTE_OS_FREEBSD: NoteOs = NoteOs(3);
+
+impl fmt::Debug for NoteOs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self.0 {
+            0 => "ELF_NOTE_OS_LINUX",
+            1 => "ELF_NOTE_OS_GNU",
+            2 => "ELF_NOTE_OS_SOLARIS2",
+            3 => "ELF_NOTE_OS_FREEBSD",
+            _ => "UNKNOWN",
+        };
+        f.write_fmt(format_args!("{}", name))
+    }
+}
+
+/// Type of a property in a NT_GNU_PROPERTY_TYPE_0 note.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct GnuPropertyType(pub u32);
+
+pub const GNU_PROPERTY_STACK_SIZE: GnuPropertyType = GnuPropertyType(1);
+pub const GNU_PROPERTY_NO_COPY_ON_PROTECTED: GnuPropertyType = GnuPropertyType(2);
+pub const GNU_PROPERTY_LOPROC: GnuPropertyType = GnuPropertyType(0xc0000000);
+pub const GNU_PROPERTY_HIPROC: GnuPropertyType = GnuPropertyType(0xdfffffff);
+pub const GNU_PROPERTY_LOUSER: GnuPropertyType = GnuPropertyType(0xe0000000);
+pub const GNU_PROPERTY_HIUSER: GnuPropertyType = GnuPropertyType(0xffffffff);
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_AND: GnuPropertyType = GnuPropertyType(0xc0000000);
+pub const GNU_PROPERTY_X86_FEATURE_1_AND: GnuPropertyType = GnuPropertyType(0xc0000002);
+pub const GNU_PROPERTY_X86_FEATURE_2_NEEDED: GnuPropertyType = GnuPropertyType(0xc0008001);
+pub const GNU_PROPERTY_X86_ISA_1_NEEDED: GnuPropertyType = GnuPropertyType(0xc0008002);
+pub const GNU_PROPERTY_X86_FEATURE_2_USED: GnuPropertyType = GnuPropertyType(0xc0010001);
+pub const GNU_PROPERTY_X86_ISA_1_USED: GnuPropertyType = GnuPropertyType(0xc0010002);
+
+impl fmt::Debug for GnuPropertyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self.0 {
+            1 => "GNU_PROPERTY_STACK_SIZE",
+            2 => "GNU_PROPERTY_NO_COPY_ON_PROTECTED",
+            0xc0000000 => "GNU_PROPERTY_AARCH64_FEATURE_1_AND",
+            0xc0000002 => "GNU_PROPERTY_X86_FEATURE_1_AND",
+            0xc0008001 => "GNU_PROPERTY_X86_FEATURE_2_NEEDED",
+            0xc0008002 => "GNU_PROPERTY_X86_ISA_1_NEEDED",
+            0xc0010001 => "GNU_PROPERTY_X86_FEATURE_2_USED",
+            0xc0010002 => "GNU_PROPERTY_X86_ISA_1_USED",
+            0xc0000001..=0xdfffffff => "GNU_PROPERTY_PROC",
+            0xe0000000..=0xffffffff => "GNU_PROPERTY_USER",
+            _ => "UNKNOWN",
+        };
+        f.write_fmt(format_args!("{}", name))
+    }
+}
+
+/// Control-flow protection features of GNU_PROPERTY_X86_FEATURE_1_AND.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct X86Feature1(pub u32);
+
+pub const GNU_PROPERTY_X86_FEATURE_1_IBT: X86Feature1 = X86Feature1(0x1);
+pub const GNU_PROPERTY_X86_FEATURE_1_SHSTK: X86Feature1 = X86Feature1(0x2);
+
+impl X86Feature1 {
+    /// Check if every feature of `features` is set.
+    pub fn contains(&self, features: X86Feature1) -> bool {
+        self.0 & features.0 == features.0
+    }
+}
+
+impl fmt::Debug for X86Feature1 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_flags(f, self.0 as u64, &[(0x1, "IBT"), (0x2, "SHSTK")])
+    }
+}
+
+/// x86 ISA levels of GNU_PROPERTY_X86_ISA_1_NEEDED and GNU_PROPERTY_X86_ISA_1_USED.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct X86Isa1(pub u32);
+
+pub const GNU_PROPERTY_X86_ISA_1_BASELINE: X86Isa1 = X86Isa1(0x1);
+pub const GNU_PROPERTY_X86_ISA_1_V2: X86Isa1 = X86Isa1(0x2);
+pub const GNU_PROPERTY_X86_ISA_1_V3: X86Isa1 = X86Isa1(0x4);
+pub const GNU_PROPERTY_X86_ISA_1_V4: X86Isa1 = X86Isa1(0x8);
+
+impl X86Isa1 {
+    /// Check if every level of `levels` is set.
+    pub fn contains(&self, levels: X86Isa1) -> bool {
+        self.0 & levels.0 == levels.0
+    }
+}
+
+impl fmt::Debug for X86Isa1 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_flags(
+            f,
+            self.0 as u64,
+            &[
+                (0x1, "x86-64-baseline"),
+                (0x2, "x86-64-v2"),
+                (0x4, "x86-64-v3"),
+                (0x8, "x86-64-v4"),
+            ],
+        )
+    }
+}
+
+/// Control-flow protection features of GNU_PROPERTY_AARCH64_FEATURE_1_AND.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Aarch64Feature1(pub u32);
+
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_BTI: Aarch64Feature1 = Aarch64Feature1(0x1);
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_PAC: Aarch64Feature1 = Aarch64Feature1(0x2);
+
+impl Aarch64Feature1 {
+    /// Check if every feature of `features` is set.
+    pub fn contains(&self, features: Aarch64Feature1) -> bool {
+        self.0 & features.0 == features.0
+    }
+}
+
+impl fmt::Debug for Aarch64Feature1 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_flags(f, self.0 as u64, &[(0x1, "BTI"), (0x2, "PAC")])
+    }
+}
+
+/// Type of a note in a core file, owned by "CORE" (or "LINUX" for the extended register sets).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CoreNoteType(pub u32);
+
+pub const NT_PRSTATUS: CoreNoteType = CoreNoteType(1);
+pub const NT_PRFPREG: CoreNoteType = CoreNoteType(2);
+pub const NT_PRPSINFO: CoreNoteType = CoreNoteType(3);
+pub const NT_TASKSTRUCT: CoreNoteType = CoreNoteType(4);
+pub const NT_AUXV: CoreNoteType = CoreNoteType(6);
+pub const NT_X86_XSTATE: CoreNoteType = CoreNoteType(0x202);
+pub const NT_ARM_TLS: CoreNoteType = CoreNoteType(0x401);
+pub const NT_ARM_HW_BREAK: CoreNoteType = CoreNoteType(0x402);
+pub const NT_ARM_HW_WATCH: CoreNoteType = CoreNoteType(0x403);
+pub const NT_ARM_SYSTEM_CALL: CoreNoteType = CoreNoteType(0x404);
+pub const NT_ARM_SVE: CoreNoteType = CoreNoteType(0x405);
+pub const NT_ARM_PAC_MASK: CoreNoteType = CoreNoteType(0x406);
+pub const NT_SIGINFO: CoreNoteType = CoreNoteType(0x53494749);
+pub const NT_FILE: CoreNoteType = CoreNoteType(0x46494c45);
+
+impl fmt::Debug for CoreNoteType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self.0 {
+            1 => "NT_PRSTATUS",
+            2 => "NT_PRFPREG",
+            3 => "NT_PRPSINFO",
+            4 => "NT_TASKSTRUCT",
+            6 => "NT_AUXV",
+            0x202 => "NT_X86_XSTATE",
+            0x401 => "NT_ARM_TLS",
+            0x402 => "NT_ARM_HW_BREAK",
+            0x403 => "NT_ARM_HW_WATCH",
+            0x404 => "NT_ARM_SYSTEM_CALL",
+            0x405 => "NT_ARM_SVE",
+            0x406 => "NT_ARM_PAC_MASK",
+            0x53494749 => "NT_SIGINFO",
+            0x46494c45 => "NT_FILE",
+            _ => "UNKNOWN",
+        };
+        f.write_fmt(format_args!("{}", name))
+    }
+}
+
+/// Type of an auxiliary vector entry.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct AuxType(pub u64);
+
+pub const AT_NULL: AuxType = AuxType(0);
+pub const AT_IGNORE: AuxType = AuxType(1);
+pub const AT_EXECFD: AuxType = AuxType(2);
+pub const AT_PHDR: AuxType = AuxType(3);
+pub const AT_PHENT: AuxType = AuxType(4);
+pub const AT_PHNUM: AuxType = AuxType(5);
+pub const AT_PAGESZ: AuxType = AuxType(6);
+pub const AT_BASE: AuxType = AuxType(7);
+pub const AT_FLAGS: AuxType = AuxType(8);
+pub const AT_ENTRY: AuxType = AuxType(9);
+pub const AT_NOTELF: AuxType = AuxType(10);
+pub const AT_UID: AuxType = AuxType(11);
+pub const AT_EUID: AuxType = AuxType(12);
+pub const AT_GID: AuxType = AuxType(13);
+pub const AT_EGID: AuxType = AuxType(14);
+pub const AT_PLATFORM: AuxType = AuxType(15);
+pub const AT_HWCAP: AuxType = AuxType(16);
+pub const AT_CLKTCK: AuxType = AuxType(17);
+pub const AT_SECURE: AuxType = AuxType(23);
+pub const AT_BASE_PLATFORM: AuxType = AuxType(24);
+pub const AT_RANDOM: AuxType = AuxType(25);
+pub const AT_HWCAP2: AuxType = AuxType(26);
+pub const AT_RSEQ_FEATURE_SIZE: AuxType = AuxType(27);
+pub const AT_RSEQ_ALIGN: AuxType = AuxType(28);
+pub const AT_HWCAP3: AuxType = AuxType(29);
+pub const AT_HWCAP4: AuxType = AuxType(30);
+pub const AT_EXECFN: AuxType = AuxType(31);
+pub const AT_SYSINFO: AuxType = AuxType(32);
+pub const AT_SYSINFO_EHDR: AuxType = AuxType(33);
+pub const AT_MINSIGSTKSZ: AuxType = AuxType(51);
+
+impl fmt::Debug for AuxType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self.0 {
+            0 => "AT_NULL",
+            1 => "AT_IGNORE",
+            2 => "AT_EXECFD",
+            3 => "AT_PHDR",
+            4 => "AT_PHENT",
+            5 => "AT_PHNUM",
+            6 => "AT_PAGESZ",
+            7 => "AT_BASE",
+            8 => "AT_FLAGS",
+            9 => "AT_ENTRY",
+            10 => "AT_NOTELF",
+            11 => "AT_UID",
+            12 => "AT_EUID",
+            13 => "AT_GID",
+            14 => "AT_EGID",
+            15 => "AT_PLATFORM",
+            16 => "AT_HWCAP",
+            17 => "AT_CLKTCK",
+            23 => "AT_SECURE",
+            24 => "AT_BASE_PLATFORM",
+            25 => "AT_RANDOM",
+            26 => "AT_HWCAP2",
+            27 => "AT_RSEQ_FEATURE_SIZE",
+            28 => "AT_RSEQ_ALIGN",
+            29 => "AT_HWCAP3",
+            30 => "AT_HWCAP4",
+            31 => "AT_EXECFN",
+            32 => "AT_SYSINFO",
+            33 => "AT_SYSINFO_EHDR",
+            51 => "AT_MINSIGSTKSZ",
+            _ => "UNKNOWN",
+        };
+        f.write_fmt(format_args!("{}", name))
+    }
+}
+
+/// Flags of a version definition (vd_flags) or a version requirement (vna_flags).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct VerFlags(pub u16);
+
+pub const VER_FLG_BASE: VerFlags = VerFlags(0x1);
+pub const VER_FLG_WEAK: VerFlags = VerFlags(0x2);
+
+impl VerFlags {
+    /// Check if every flag of `flags` is set.
+    pub fn contains(&self, flags: VerFlags) -> bool {
+        self.0 & flags.0 == flags.0
+    }
+}
+
+impl fmt::Debug for VerFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_flags(
+            f,
+            self.0 as u64,
+            &[(0x1, "VER_FLG_BASE"), (0x2, "VER_FLG_WEAK")],
+        )
+    }
+}
@@ -1,14 +1,75 @@
-pub fn bytes_to_u16(bytes: &[u8]) -> u16 {
+use crate::{
+    types::{Data, ELFDATA2MSB},
+    Error,
+};
+
+pub fn bytes_to_u16(bytes: &[u8], data: Data) -> u16 {
     let arr = bytes[..2].try_into().unwrap();
-    u16::from_le_bytes(arr)
+    if data == ELFDATA2MSB {
+        u16::from_be_bytes(arr)
+    } else {
+        u16::from_le_bytes(arr)
+    }
 }
 
-pub fn bytes_to_u32(bytes: &[u8]) -> u32 {
+pub fn bytes_to_u32(bytes: &[u8], data: Data) -> u32 {
     let arr = bytes[..4].try_into().unwrap();
-    u32::from_le_bytes(arr)
+    if data == ELFDATA2MSB {
+        u32::from_be_bytes(arr)
+    } else {
+        u32::from_le_bytes(arr)
+    }
 }
 
-pub fn bytes_to_u64(bytes: &[u8]) -> u64 {
+pub fn bytes_to_u64(bytes: &[u8], data: Data) -> u64 {
     let arr = bytes[..8].try_into().unwrap();
-    u64::from_le_bytes(arr)
+    if data == ELFDATA2MSB {
+        u64::from_be_bytes(arr)
+    } else {
+        u64::from_le_bytes(arr)
+    }
+}
+
+/// Get the first `size` bytes of the `index`th entry of a table at `offset`.
+pub fn table_entry(
+    bytes: &[u8],
+    offset: u64,
+    entsize: u64,
+    index: u64,
+    size: usize,
+) -> Result<&[u8], Error> {
+    let start = index
+        .checked_mul(entsize)
+        .and_then(|entry_offset| entry_offset.checked_add(offset))
+        .ok_or(Error::Corrupted)?;
+    let start = usize::try_from(start).map_err(|_| Error::Corrupted)?;
+    let end = start.checked_add(size).ok_or(Error::Corrupted)?;
+    bytes.get(start..end).ok_or(Error::Corrupted)
+}
+
+/// Get `size` bytes at `offset`.
+pub fn slice(bytes: &[u8], offset: u64, size: u64) -> Result<&[u8], Error> {
+    let start = usize::try_from(offset).map_err(|_| Error::Corrupted)?;
+    let size = usize::try_from(size).map_err(|_| Error::Corrupted)?;
+    let end = start.checked_add(size).ok_or(Error::Corrupted)?;
+    bytes.get(start..end).ok_or(Error::Corrupted)
+}
+
+/// Read an unsigned integer of `bytes.len()` (at most 8) bytes.
+pub fn read_uint(bytes: &[u8], data: Data) -> u64 {
+    let fold = |value: u64, &byte: &u8| (value << 8) | byte as u64;
+    if data == ELFDATA2MSB {
+        bytes.iter().fold(0, fold)
+    } else {
+        bytes.iter().rev().fold(0, fold)
+    }
+}
+
+/// Write the low `bytes.len()` (at most 8) bytes of `value`.
+pub fn write_uint(bytes: &mut [u8], value: u64, data: Data) {
+    let len = bytes.len();
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        let shift = if data == ELFDATA2MSB { len - 1 - i } else { i };
+        *byte = (value >> (shift * 8)) as u8;
+    }
 }
@@ -0,0 +1,440 @@
+use crate::{
+    table::{Entry, Table},
+    types::{Class, Data, VerFlags, ELFDATA2LSB},
+    util, Error, StrTab,
+};
+use core::cmp::Ordering;
+
+/// Version index of symbols that are local to the file.
+pub const VER_NDX_LOCAL: u16 = 0;
+/// Version index of global symbols that are not versioned.
+pub const VER_NDX_GLOBAL: u16 = 1;
+
+/// An entry of a SHT_GNU_VERSYM section: the version index of the dynamic symbol at the same
+/// index, with the hidden bit on top.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Versym(pub u16);
+
+impl Versym {
+    /// Get the version index without the hidden bit.
+    pub fn index(&self) -> u16 {
+        self.0 & 0x7fff
+    }
+
+    /// Check if the symbol is hidden, i.e. it is not the default version of its name
+    /// (name@VERSION rather than name@@VERSION).
+    pub fn is_hidden(&self) -> bool {
+        self.0 & 0x8000 != 0
+    }
+}
+
+impl Entry for Versym {
+    fn size(_class: Class) -> usize {
+        2
+    }
+
+    fn parse(bytes: &[u8], _class: Class, data: Data) -> Result<Self, Error> {
+        let bytes = bytes.get(..2).ok_or(Error::Corrupted)?;
+        Ok(Versym(util::bytes_to_u16(bytes, data)))
+    }
+}
+
+/// Split a version name such as GLIBC_2.2.5 into the part before the last '_' and the dotted
+/// numbers after it.
+fn split_version(name: &str) -> Option<(&str, &str)> {
+    let (prefix, numbers) = name.rsplit_once('_')?;
+    let valid = numbers
+        .split('.')
+        .all(|number| !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()));
+    valid.then_some((prefix, numbers))
+}
+
+/// Compare two version names such as GLIBC_2.14 and GLIBC_2.2.5 by the dotted numbers after
+/// the last '_'. Names without such numbers, e.g. GLIBC_PRIVATE, do not compare.
+pub fn compare_versions(a: &str, b: &str) -> Option<Ordering> {
+    fn numbers(numbers: &str) -> impl Iterator<Item = u64> + '_ {
+        numbers
+            .split('.')
+            .map(|number| number.parse().unwrap_or(u64::MAX))
+    }
+    let (a_prefix, a_numbers) = split_version(a)?;
+    let (b_prefix, b_numbers) = split_version(b)?;
+    Some(
+        a_prefix
+            .cmp(b_prefix)
+            .then_with(|| numbers(a_numbers).cmp(numbers(b_numbers))),
+    )
+}
+
+/// A version a needed library must provide (Elf_Vernaux).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Vernaux<'a> {
+    pub hash: u32,
+    pub flags: VerFlags,
+    /// The version index the SHT_GNU_VERSYM entries refer to this version by (vna_other).
+    pub index: u16,
+    pub name: &'a str,
+}
+
+/// A library the file needs versions of (Elf_Verneed).
+#[derive(Clone, Copy)]
+pub struct Verneed<'a> {
+    pub version: u16,
+    pub file: &'a str,
+    count: u16,
+    aux: &'a [u8],
+    strtab: StrTab<'a>,
+    data: Data,
+}
+
+impl<'a> Verneed<'a> {
+    /// Get an iterator over the versions required from the library.
+    pub fn versions(&self) -> VernauxIter<'a> {
+        VernauxIter {
+            bytes: self.aux,
+            remaining: self.count,
+            strtab: self.strtab,
+            data: self.data,
+        }
+    }
+
+    /// Get the highest version required from the library, as ordered by compare_versions.
+    pub fn highest_version(&self) -> Option<&'a str> {
+        self.versions()
+            .map(|aux| aux.name)
+            .filter(|name| split_version(name).is_some())
+            .max_by(|a, b| compare_versions(a, b).unwrap_or(Ordering::Equal))
+    }
+}
+
+/// An iterator over the entries of a SHT_GNU_VERNEED section. It stops at the first entry
+/// that is malformed.
+#[derive(Clone, Copy)]
+pub struct VerneedIter<'a> {
+    bytes: &'a [u8],
+    remaining: u32,
+    strtab: StrTab<'a>,
+    data: Data,
+}
+
+impl<'a> VerneedIter<'a> {
+    /// Get a VerneedIter over the contents of a SHT_GNU_VERNEED section holding `count`
+    /// (sh_info) entries, with names in `strtab` (sh_link).
+    pub fn new(bytes: &'a [u8], count: u32, strtab: StrTab<'a>, data: Data) -> Self {
+        VerneedIter {
+            bytes,
+            remaining: count,
+            strtab,
+            data,
+        }
+    }
+
+    fn parse(&self) -> Option<(Verneed<'a>, u32)> {
+        let entry = self.bytes.get(..16)?;
+        let aux = util::bytes_to_u32(&entry[8..12], self.data) as usize;
+        let verneed = Verneed {
+            version: util::bytes_to_u16(&entry[0..2], self.data),
+            file: self
+                .strtab
+                .get_str(util::bytes_to_u32(&entry[4..8], self.data))
+                .ok()?,
+            count: util::bytes_to_u16(&entry[2..4], self.data),
+            aux: self.bytes.get(aux..)?,
+            strtab: self.strtab,
+            data: self.data,
+        };
+        Some((verneed, util::bytes_to_u32(&entry[12..16], self.data)))
+    }
+}
+
+impl<'a> Iterator for VerneedIter<'a> {
+    type Item = Verneed<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let Some((verneed, next)) = self.parse() else {
+            self.remaining = 0;
+            return None;
+        };
+        match self.bytes.get(next as usize..) {
+            Some(bytes) if next != 0 => {
+                self.bytes = bytes;
+                self.remaining -= 1;
+            }
+            _ => self.remaining = 0,
+        }
+        Some(verneed)
+    }
+}
+
+pub struct VernauxIter<'a> {
+    bytes: &'a [u8],
+    remaining: u16,
+    strtab: StrTab<'a>,
+    data: Data,
+}
+
+impl<'a> Iterator for VernauxIter<'a> {
+    type Item = Vernaux<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let entry = self.bytes.get(..16)?;
+        let vernaux = Vernaux {
+            hash: util::bytes_to_u32(&entry[0..4], self.data),
+            flags: VerFlags(util::bytes_to_u16(&entry[4..6], self.data)),
+            index: util::bytes_to_u16(&entry[6..8], self.data),
+            name: self
+                .strtab
+                .get_str(util::bytes_to_u32(&entry[8..12], self.data))
+                .ok()?,
+        };
+        let next = util::bytes_to_u32(&entry[12..16], self.data) as usize;
+        match self.bytes.get(next..) {
+            Some(bytes) if next != 0 => {
+                self.bytes = bytes;
+                self.remaining -= 1;
+            }
+            _ => self.remaining = 0,
+        }
+        Some(vernaux)
+    }
+}
+
+/// A version the file defines (Elf_Verdef).
+#[derive(Clone, Copy)]
+pub struct Verdef<'a> {
+    pub version: u16,
+    pub flags: VerFlags,
+    /// The version index the SHT_GNU_VERSYM entries refer to this version by.
+    pub index: u16,
+    pub hash: u32,
+    /// The name of the version, or the soname of the file for the VER_FLG_BASE entry.
+    pub name: &'a str,
+    count: u16,
+    aux: &'a [u8],
+    strtab: StrTab<'a>,
+    data: Data,
+}
+
+impl<'a> Verdef<'a> {
+    /// Get an iterator over the names of the versions this version inherits from.
+    pub fn parents(&self) -> VerdauxIter<'a> {
+        let mut names = VerdauxIter {
+            bytes: self.aux,
+            remaining: self.count,
+            strtab: self.strtab,
+            data: self.data,
+        };
+        names.next();
+        names
+    }
+}
+
+/// An iterator over the entries of a SHT_GNU_VERDEF section. It stops at the first entry
+/// that is malformed.
+#[derive(Clone, Copy)]
+pub struct VerdefIter<'a> {
+    bytes: &'a [u8],
+    remaining: u32,
+    strtab: StrTab<'a>,
+    data: Data,
+}
+
+impl<'a> VerdefIter<'a> {
+    /// Get a VerdefIter over the contents of a SHT_GNU_VERDEF section holding `count`
+    /// (sh_info) entries, with names in `strtab` (sh_link).
+    pub fn new(bytes: &'a [u8], count: u32, strtab: StrTab<'a>, data: Data) -> Self {
+        VerdefIter {
+            bytes,
+            remaining: count,
+            strtab,
+            data,
+        }
+    }
+
+    fn parse(&self) -> Option<(Verdef<'a>, u32)> {
+        let entry = self.bytes.get(..20)?;
+        let count = util::bytes_to_u16(&entry[6..8], self.data);
+        let aux = self
+            .bytes
+            .get(util::bytes_to_u32(&entry[12..16], self.data) as usize..)?;
+        let name = VerdauxIter {
+            bytes: aux,
+            remaining: count,
+            strtab: self.strtab,
+            data: self.data,
+        }
+        .next()?;
+        let verdef = Verdef {
+            version: util::bytes_to_u16(&entry[0..2], self.data),
+            flags: VerFlags(util::bytes_to_u16(&entry[2..4], self.data)),
+            index: util::bytes_to_u16(&entry[4..6], self.data),
+            hash: util::bytes_to_u32(&entry[8..12], self.data),
+            name,
+            count,
+            aux,
+            strtab: self.strtab,
+            data: self.data,
+        };
+        Some((verdef, util::bytes_to_u32(&entry[16..20], self.data)))
+    }
+}
+
+impl<'a> Iterator for VerdefIter<'a> {
+    type Item = Verdef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let Some((verdef, next)) = self.parse() else {
+            self.remaining = 0;
+            return None;
+        };
+        match self.bytes.get(next as usize..) {
+            Some(bytes) if next != 0 => {
+                self.bytes = bytes;
+                self.remaining -= 1;
+            }
+            _ => self.remaining = 0,
+        }
+        Some(verdef)
+    }
+}
+
+pub struct VerdauxIter<'a> {
+    bytes: &'a [u8],
+    remaining: u16,
+    strtab: StrTab<'a>,
+    data: Data,
+}
+
+impl<'a> Iterator for VerdauxIter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let entry = self.bytes.get(..8)?;
+        let name = self
+            .strtab
+            .get_str(util::bytes_to_u32(&entry[0..4], self.data))
+            .ok()?;
+        let next = util::bytes_to_u32(&entry[4..8], self.data) as usize;
+        match self.bytes.get(next..) {
+            Some(bytes) if next != 0 => {
+                self.bytes = bytes;
+                self.remaining -= 1;
+            }
+            _ => self.remaining = 0,
+        }
+        Some(name)
+    }
+}
+
+/// The version of a dynamic symbol.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SymbolVersion<'a> {
+    pub name: &'a str,
+    /// The symbol is not the default version of its name.
+    pub hidden: bool,
+    /// The library that must provide the version, or None if the file defines it.
+    pub file: Option<&'a str>,
+}
+
+/// The versions of the dynamic symbols: the SHT_GNU_VERSYM entries, and the versions they
+/// refer to in the SHT_GNU_VERDEF and SHT_GNU_VERNEED sections.
+#[derive(Clone, Copy)]
+pub struct SymbolVersions<'a> {
+    versym: Table<'a, Versym>,
+    verdefs: Option<VerdefIter<'a>>,
+    verneeds: Option<VerneedIter<'a>>,
+}
+
+impl<'a> SymbolVersions<'a> {
+    /// Get a SymbolVersions from the SHT_GNU_VERSYM entries and the version definitions and
+    /// requirements, if the file has them.
+    pub fn new(
+        versym: Table<'a, Versym>,
+        verdefs: Option<VerdefIter<'a>>,
+        verneeds: Option<VerneedIter<'a>>,
+    ) -> Self {
+        SymbolVersions {
+            versym,
+            verdefs,
+            verneeds,
+        }
+    }
+
+    /// Get the SHT_GNU_VERSYM entry of the dynamic symbol at `index`.
+    pub fn versym(&self, index: usize) -> Result<Versym, Error> {
+        self.versym.get(index)
+    }
+
+    /// Get the version of the dynamic symbol at `index`. Local and unversioned global symbols
+    /// have no version, so they are NotFound.
+    pub fn get(&self, index: usize) -> Result<SymbolVersion<'a>, Error> {
+        let versym = self.versym(index)?;
+        let version = versym.index();
+        if version == VER_NDX_LOCAL || version == VER_NDX_GLOBAL {
+            return Err(Error::NotFound);
+        }
+        if let Some(verdef) = self.definitions().find(|verdef| verdef.index == version) {
+            return Ok(SymbolVersion {
+                name: verdef.name,
+                hidden: versym.is_hidden(),
+                file: None,
+            });
+        }
+        self.requirements()
+            .find_map(|verneed| {
+                let vernaux = verneed.versions().find(|aux| aux.index == version)?;
+                Some(SymbolVersion {
+                    name: vernaux.name,
+                    hidden: versym.is_hidden(),
+                    file: Some(verneed.file),
+                })
+            })
+            .ok_or(Error::NotFound)
+    }
+
+    /// Get an iterator over the versions the file defines (SHT_GNU_VERDEF).
+    pub fn definitions(&self) -> VerdefIter<'a> {
+        self.verdefs
+            .unwrap_or(VerdefIter::new(&[], 0, StrTab::new(&[]), ELFDATA2LSB))
+    }
+
+    /// Get an iterator over the libraries the file needs versions of (SHT_GNU_VERNEED).
+    pub fn requirements(&self) -> VerneedIter<'a> {
+        self.verneeds
+            .unwrap_or(VerneedIter::new(&[], 0, StrTab::new(&[]), ELFDATA2LSB))
+    }
+
+    /// Get an iterator over each needed library and the highest version required from it.
+    /// Libraries that require only versions without numbers are skipped.
+    pub fn highest_required(&self) -> HighestRequiredIter<'a> {
+        HighestRequiredIter {
+            verneeds: self.requirements(),
+        }
+    }
+}
+
+pub struct HighestRequiredIter<'a> {
+    verneeds: VerneedIter<'a>,
+}
+
+impl<'a> Iterator for HighestRequiredIter<'a> {
+    /// The library and its highest required version.
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.verneeds
+            .find_map(|verneed| Some((verneed.file, verneed.highest_version()?)))
+    }
+}
@@ -0,0 +1,255 @@
+extern crate elf_parser;
+use elf_parser::{
+    coredump::{Aarch64Regs, TimeVal},
+    types::{
+        CoreNoteType, AT_ENTRY, AT_EXECFN, AT_HWCAP, AT_PAGESZ, AT_PHDR, AT_PHNUM, EM_AARCH64,
+    },
+    AuxEntry, Core, Elf64, Error, FileMapping, Registers,
+};
+
+#[test]
+fn x86_64() {
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/core_x86_64")).unwrap();
+    let core = Core::new(&elf64).unwrap();
+
+    let threads: Vec<_> = core.threads().map(Result::unwrap).collect();
+    assert_eq!(threads.len(), 1);
+    let thread = &threads[0];
+    assert_eq!(thread.signo, 11);
+    assert_eq!(thread.cursig, 11);
+    assert_eq!(thread.pid, 21780);
+    assert_eq!(thread.ppid, 21779);
+    assert!(thread.fpvalid);
+    let Registers::X86_64(regs) = thread.regs else {
+        panic!("{:?}", thread.regs)
+    };
+    // The store through the null pointer faulted in main, with r12 set just before.
+    assert_eq!(regs.r12, 0x1122334455667788);
+    assert_eq!(regs.rax, 0x10);
+    assert_eq!(thread.regs.pc(), 0x40116b);
+    assert_eq!(thread.regs.sp(), 0x7fff2ac494b0);
+
+    let info = core.process_info().unwrap();
+    assert_eq!(info.pid, 21780);
+    assert_eq!(info.sname, b'R');
+    assert_eq!(info.fname, b"crash");
+    assert_eq!(info.psargs, b"./crash a b ");
+
+    let auxv: Vec<_> = core.auxv().unwrap().collect();
+    assert_eq!(auxv.len(), 22);
+    let aux = |a_type| {
+        auxv.iter()
+            .find(|entry| entry.a_type == a_type)
+            .unwrap()
+            .a_val
+    };
+    assert_eq!(aux(AT_PAGESZ), 0x1000);
+    assert_eq!(aux(AT_PHDR), 0x400040);
+    assert_eq!(aux(AT_PHNUM), 13);
+    assert_eq!(aux(AT_ENTRY), 0x401040);
+
+    let files = core.mapped_files().unwrap();
+    assert_eq!(files.len(), 15);
+    assert_eq!(files.page_size(), 0x1000);
+    let mappings: Vec<_> = files.iter().collect();
+    assert_eq!(mappings.len(), 15);
+    assert_eq!(
+        mappings[1],
+        FileMapping {
+            start: 0x401000,
+            end: 0x402000,
+            offset: 0x1000,
+            name: b"/tmp/fx/core/crash",
+        }
+    );
+    let libc_text = files.find(0x7fe1d0560000).unwrap();
+    assert_eq!(libc_text.name, b"/usr/lib/x86_64-linux-gnu/libc.so.6");
+    assert_eq!(libc_text.offset, 0x26000);
+    // Anonymous mappings such as the heap are not listed.
+    assert_eq!(files.find(0x7fe1d0530000), None);
+}
+
+#[test]
+fn aarch64() {
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/core_aarch64")).unwrap();
+    let core = Core::new(&elf64).unwrap();
+    assert_eq!(core.machine(), EM_AARCH64);
+
+    let threads: Vec<_> = core.threads().map(Result::unwrap).collect();
+    assert_eq!(threads.len(), 2);
+    assert_eq!(threads[0].pid, 4242);
+    assert_eq!(threads[1].pid, 4243);
+    assert_eq!(threads[0].code, 1);
+    assert_eq!(threads[0].utime, TimeVal { sec: 1, usec: 500 });
+    assert_eq!(threads[0].stime, TimeVal { sec: 0, usec: 250 });
+    let mut regs = [0; 31];
+    for (i, reg) in regs.iter_mut().enumerate() {
+        *reg = 0x1000 + i as u64;
+    }
+    assert_eq!(
+        threads[0].regs,
+        Registers::Aarch64(Aarch64Regs {
+            regs,
+            sp: 0x7ffffe80,
+            pc: 0x401010,
+            pstate: 0x60000000,
+        })
+    );
+    assert_eq!(threads[1].regs.pc(), 0x401020);
+    assert_eq!(threads[1].regs.sp(), 0x7fffee80);
+
+    let info = core.process_info().unwrap();
+    assert_eq!((info.uid, info.gid), (1000, 1000));
+    assert_eq!(info.flag, 0x400600);
+    assert_eq!(info.psargs, b"./crash --arm64 ");
+
+    let auxv: Vec<_> = core.auxv().unwrap().collect();
+    assert_eq!(auxv.len(), 7);
+    assert_eq!(
+        auxv[5],
+        AuxEntry {
+            a_type: AT_HWCAP,
+            a_val: 0x8fff
+        }
+    );
+
+    let names: Vec<_> = core
+        .mapped_files()
+        .unwrap()
+        .iter()
+        .map(|mapping| (mapping.start, mapping.offset, mapping.name))
+        .collect();
+    assert_eq!(
+        names,
+        [
+            (0x400000, 0, &b"/usr/bin/crash"[..]),
+            (0x401000, 0x1000, &b"/usr/bin/crash"[..]),
+        ]
+    );
+    assert_eq!(
+        format!("{:?}", CoreNoteType(core.notes().last().unwrap().n_type)),
+        "NT_PRSTATUS"
+    );
+}
+
+#[test]
+fn read_memory_x86_64() {
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/core_x86_64")).unwrap();
+    let core = Core::new(&elf64).unwrap();
+
+    let mut marker = [0; 24];
+    core.read_memory(0x404040, &mut marker).unwrap();
+    assert_eq!(&marker, b"elf_parser core fixture\0");
+
+    // The string AT_EXECFN points to is on the stack.
+    let execfn = core
+        .auxv()
+        .unwrap()
+        .find(|entry| entry.a_type == AT_EXECFN)
+        .unwrap()
+        .a_val;
+    let mut name = [0; 8];
+    core.read_memory(execfn, &mut name).unwrap();
+    assert_eq!(&name, b"./crash\0");
+
+    // The .rodata page and the .data page are separate segments.
+    let mut bytes = [0; 16];
+    core.read_memory(0x403ff8, &mut bytes).unwrap();
+    assert_eq!(bytes[..8], 0x7fe1d0737290u64.to_le_bytes());
+    assert_eq!(bytes[8..], 0x7fe1d05c9930u64.to_le_bytes());
+
+    // The text was not dumped, but can be read from the executable.
+    let exe = include_bytes!("./data/core_x86_64_exe");
+    let mut text = [0; 0x20];
+    assert_eq!(
+        core.read_memory(0x401126, &mut text),
+        Err(Error::NotFileBacked)
+    );
+    let mut opened = Vec::new();
+    core.read_memory_with(0x401126, &mut text, |mapping| {
+        opened.push(mapping.name);
+        Some(&exe[..])
+    })
+    .unwrap();
+    assert_eq!(text, exe[0x1126..0x1146]);
+    assert_eq!(opened, [b"/tmp/fx/core/crash"]);
+    assert_eq!(
+        core.read_memory_with(0x401126, &mut text, |_| None),
+        Err(Error::NotFileBacked)
+    );
+
+    // .bss is writable and anonymous, so it is never read from the file.
+    let mut zeroes = [0xff; 16];
+    assert_eq!(
+        core.read_memory_with(0x405000, &mut zeroes, |_| Some(&exe[..])),
+        Err(Error::NotFileBacked)
+    );
+    assert_eq!(core.read_memory(0x10, &mut zeroes), Err(Error::NotFound));
+
+    // p_offset of the segment at 0x404000, the sixth program header, overflows.
+    let mut bytes = include_bytes!("./data/core_x86_64").to_vec();
+    bytes[0x158 + 8..0x158 + 16].copy_from_slice(&(u64::MAX - 0x10).to_le_bytes());
+    let elf64 = Elf64::from_bytes(&bytes).unwrap();
+    let core = Core::new(&elf64).unwrap();
+    assert_eq!(
+        core.read_memory(0x404040, &mut marker),
+        Err(Error::Corrupted)
+    );
+}
+
+#[test]
+fn read_memory_aarch64() {
+    let bytes = include_bytes!("./data/core_aarch64");
+    let elf64 = Elf64::from_bytes(bytes).unwrap();
+    let core = Core::new(&elf64).unwrap();
+
+    let mut stack = [0; 0x200];
+    core.read_memory(0x7ffff000, &mut stack).unwrap();
+    assert!(stack.iter().enumerate().all(|(i, &b)| b == i as u8));
+    // The stack is the last mapping.
+    assert_eq!(
+        core.read_memory(0x7ffffff0, &mut stack),
+        Err(Error::NotFound)
+    );
+
+    // Reads across the dumped first page and the second page, which is taken from the file.
+    let mut exe = vec![0; 0x2000];
+    exe[..4].copy_from_slice(b"\x7fELF");
+    for (i, byte) in exe[0x1000..].iter_mut().enumerate() {
+        *byte = (i % 251) as u8;
+    }
+    let mut memory = [0; 0x20];
+    assert_eq!(
+        core.read_memory(0x400ff0, &mut memory),
+        Err(Error::NotFileBacked)
+    );
+    core.read_memory_with(0x400ff0, &mut memory, |mapping| {
+        assert_eq!(mapping.name, b"/usr/bin/crash");
+        Some(&exe)
+    })
+    .unwrap();
+    assert_eq!(memory[..0x10], bytes[0x1ff0..0x2000]);
+    assert_eq!(memory[0x10..], exe[0x1000..0x1010]);
+
+    // A file too short for the mapping does not cover it.
+    assert_eq!(
+        core.read_memory_with(0x401000, &mut memory, |_| Some(&exe[..0x1008])),
+        Err(Error::NotFileBacked)
+    );
+
+    let mut header = [0; 4];
+    core.read_memory(0x400000, &mut header).unwrap();
+    assert_eq!(&header, b"\x7fELF");
+}
+
+#[test]
+fn not_core() {
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/core_x86_64_exe")).unwrap();
+    assert_eq!(Core::new(&elf64).err(), Some(Error::InvalidFileType));
+
+    // A core file of another machine.
+    let mut bytes = include_bytes!("./data/core_aarch64").to_vec();
+    bytes[18] = 40; // EM_ARM
+    let elf64 = Elf64::from_bytes(&bytes).unwrap();
+    assert_eq!(Core::new(&elf64).err(), Some(Error::UnsupportedMachine));
+}
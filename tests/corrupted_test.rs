@@ -0,0 +1,77 @@
+extern crate elf_parser;
+use elf_parser::{Elf, Elf32, Elf64, Error};
+
+fn touch_all(bytes: &[u8]) {
+    if let Ok(elf) = Elf::from_bytes(bytes) {
+        let _ = elf.ehdr();
+        for i in 0..=elf.phdr_num() {
+            let _ = elf.phdr_nth(i);
+        }
+        for i in 0..=elf.shdr_num() {
+            let _ = elf.shdr_nth(i);
+        }
+        let _ = elf.phdr_iter().count();
+        let _ = elf.shdr_iter().count();
+    }
+}
+
+#[test]
+fn elf32_phoff_overflow() {
+    let mut bytes = *include_bytes!("./data/elf32");
+    bytes[28..32].copy_from_slice(&0xffff_fff0u32.to_le_bytes());
+    assert_eq!(Elf32::from_bytes(&bytes).err(), Some(Error::Corrupted));
+}
+
+#[test]
+fn elf64_shoff_overflow() {
+    let mut bytes = *include_bytes!("./data/elf64");
+    bytes[40..48].copy_from_slice(&(u64::MAX - 0x10).to_le_bytes());
+    assert_eq!(Elf64::from_bytes(&bytes).err(), Some(Error::Corrupted));
+}
+
+#[test]
+fn elf64_small_phentsize() {
+    let mut bytes = *include_bytes!("./data/elf64");
+    bytes[54..56].copy_from_slice(&8u16.to_le_bytes());
+    assert_eq!(Elf64::from_bytes(&bytes).err(), Some(Error::Corrupted));
+}
+
+#[test]
+fn elf32_small_shentsize() {
+    let mut bytes = *include_bytes!("./data/elf32");
+    bytes[46..48].copy_from_slice(&0x20u16.to_le_bytes());
+    assert_eq!(Elf32::from_bytes(&bytes).err(), Some(Error::Corrupted));
+}
+
+#[test]
+fn truncated() {
+    let elf64 = include_bytes!("./data/elf64");
+    let elf32 = include_bytes!("./data/elf32");
+    for len in 0..0x100 {
+        touch_all(&elf64[..len]);
+        touch_all(&elf32[..len]);
+    }
+    assert_eq!(
+        Elf64::from_bytes(&elf64[..0x20]).err(),
+        Some(Error::Corrupted)
+    );
+    assert_eq!(
+        Elf64::from_bytes(&elf64[..0x54b0]).err(),
+        Some(Error::Corrupted)
+    );
+}
+
+#[test]
+fn mutated_headers() {
+    let elf64 = include_bytes!("./data/elf64");
+    let elf32 = include_bytes!("./data/elf32");
+    for original in [&elf64[..], &elf32[..]] {
+        for offset in 0..0x40 {
+            for value in [0x00, 0x01, 0x7f, 0x80, 0xff] {
+                let mut bytes = original.to_vec();
+                bytes[offset] = value;
+                touch_all(&bytes);
+            }
+        }
+    }
+}
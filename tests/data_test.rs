@@ -0,0 +1,81 @@
+extern crate elf_parser;
+use elf_parser::{
+    types::{PT_INTERP, PT_LOAD},
+    Elf, Elf32, Elf64, ElfFile, Error,
+};
+
+fn interp_section(bytes: &[u8]) -> &[u8] {
+    let elf64 = Elf64::from_bytes(bytes).unwrap();
+    let interp = elf64.section_by_name(".interp").unwrap();
+    elf64.section_data(&interp).unwrap()
+}
+
+#[test]
+fn elf64_section_data() {
+    let bytes = include_bytes!("./data/elf64");
+    assert_eq!(interp_section(bytes), b"/lib64/ld-linux-x86-64.so.2\0");
+
+    let elf64 = Elf64::from_bytes(bytes).unwrap();
+    let bss = elf64.section_by_name(".bss").unwrap();
+    assert_eq!(bss.sh_size, 0x4);
+    assert_eq!(elf64.section_data(&bss), Ok(&[][..]));
+
+    let data = elf64.section_by_name(".data").unwrap();
+    assert_eq!(elf64.section_data(&data), Ok(&bytes[0x3018..0x301c]));
+    let null = elf64.shdr_nth(0).unwrap();
+    assert_eq!(elf64.section_data(&null), Ok(&[][..]));
+}
+
+#[test]
+fn elf64_segment_data() {
+    let bytes = include_bytes!("./data/elf64");
+    let elf64 = Elf64::from_bytes(bytes).unwrap();
+    let interp = elf64
+        .phdr_iter()
+        .find(|phdr| phdr.p_type == PT_INTERP.0)
+        .unwrap();
+    assert_eq!(
+        elf64.segment_data(&interp),
+        Ok(&b"/lib64/ld-linux-x86-64.so.2\0"[..])
+    );
+
+    let rw = elf64
+        .phdr_iter()
+        .filter(|phdr| phdr.p_type == PT_LOAD.0)
+        .last()
+        .unwrap();
+    assert_eq!(elf64.segment_data(&rw).unwrap().len(), 0x1cc);
+}
+
+#[test]
+fn elf32_section_data() {
+    let bytes = include_bytes!("./data/elf32");
+    let elf32 = Elf32::from_bytes(bytes).unwrap();
+    let interp = elf32.section_by_name(".interp").unwrap();
+    assert_eq!(
+        elf32.section_data(&interp),
+        Ok(&b"/lib/ld-linux.so.2\0"[..])
+    );
+    let phdr1 = elf32.phdr_nth(1).unwrap();
+    assert_eq!(elf32.segment_data(&phdr1), Ok(&b"/lib/ld-linux.so.2\0"[..]));
+
+    let elf = Elf::from_bytes(bytes).unwrap();
+    let interp = elf.section_by_name(".interp").unwrap();
+    assert_eq!(elf.section_data(&interp), Ok(&b"/lib/ld-linux.so.2\0"[..]));
+}
+
+#[test]
+fn out_of_range_data() {
+    let bytes = include_bytes!("./data/elf64");
+    let elf64 = Elf64::from_bytes(bytes).unwrap();
+    let mut text = elf64.section_by_name(".text").unwrap();
+    text.sh_size = u64::MAX;
+    assert_eq!(elf64.section_data(&text), Err(Error::Corrupted));
+    text.sh_size = 0x10;
+    text.sh_offset = bytes.len() as u64 - 0x8;
+    assert_eq!(elf64.section_data(&text), Err(Error::Corrupted));
+
+    let mut phdr = elf64.phdr_nth(0).unwrap();
+    phdr.p_offset = u64::MAX;
+    assert_eq!(elf64.segment_data(&phdr), Err(Error::Corrupted));
+}
@@ -0,0 +1,77 @@
+extern crate elf_parser;
+use elf_parser::{DebugFileLocator, Elf64};
+use std::{fs, path::PathBuf};
+
+const LIB: &[u8] = include_bytes!("./data/debuglink64");
+const DEBUG: &[u8] = include_bytes!("./data/debuglink64.debug");
+
+/// Create an empty directory for a test.
+fn scratch(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("elf_parser-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write(path: PathBuf, bytes: &[u8]) -> PathBuf {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, bytes).unwrap();
+    path
+}
+
+#[test]
+fn build_id_path() {
+    let locator = DebugFileLocator::new("/usr/lib/debug");
+    assert_eq!(
+        locator.build_id_path(&[0x40, 0x70, 0xd9, 0x8a]),
+        Some(PathBuf::from("/usr/lib/debug/.build-id/40/70d98a.debug"))
+    );
+    assert_eq!(locator.build_id_path(&[0x40]), None);
+    assert_eq!(DebugFileLocator::default(), locator);
+}
+
+#[test]
+fn by_build_id() {
+    let dir = scratch("build-id");
+    let root = dir.join("debug");
+    let lib = write(dir.join("lib/libdyn.so"), LIB);
+    let debug = write(
+        root.join(".build-id/40/70d98a0ec1bcc258c4fa3a936507bba8f5c6c8.debug"),
+        DEBUG,
+    );
+    let elf64 = Elf64::from_bytes(LIB).unwrap();
+    assert_eq!(
+        DebugFileLocator::new(&root).locate(&elf64, &lib),
+        Some(debug)
+    );
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn by_debuglink() {
+    let dir = scratch("debuglink");
+    let root = dir.join("debug");
+    let lib = write(dir.join("lib/libdyn.so"), LIB);
+    let elf64 = Elf64::from_bytes(LIB).unwrap();
+    let locator = DebugFileLocator::new(&root);
+    assert_eq!(locator.locate(&elf64, &lib), None);
+
+    // A file at the build-id path whose build ID differs is not accepted.
+    write(
+        root.join(".build-id/40/70d98a0ec1bcc258c4fa3a936507bba8f5c6c8.debug"),
+        include_bytes!("./data/dyn32"),
+    );
+    // Neither is a file with the linked name whose CRC32 differs.
+    write(dir.join("lib/libdyn.so.debug"), b"not the debug file");
+    assert_eq!(locator.locate(&elf64, &lib), None);
+
+    let global = write(
+        root.join(dir.strip_prefix("/").unwrap())
+            .join("lib/libdyn.so.debug"),
+        DEBUG,
+    );
+    assert_eq!(locator.locate(&elf64, &lib), Some(global));
+    let local = write(dir.join("lib/.debug/libdyn.so.debug"), DEBUG);
+    assert_eq!(locator.locate(&elf64, &lib), Some(local));
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,75 @@
+extern crate elf_parser;
+use elf_parser::{
+    crc32,
+    types::{ELFDATA2LSB, ELFDATA2MSB},
+    DebugLink, Elf, Elf32, Elf64, ElfFile, Error,
+};
+
+const DYN64_BUILD_ID: [u8; 20] = [
+    0x40, 0x70, 0xd9, 0x8a, 0x0e, 0xc1, 0xbc, 0xc2, 0x58, 0xc4, 0xfa, 0x3a, 0x93, 0x65, 0x07, 0xbb,
+    0xa8, 0xf5, 0xc6, 0xc8,
+];
+
+#[test]
+fn build_id() {
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/dyn64")).unwrap();
+    assert_eq!(elf64.build_id(), Some(&DYN64_BUILD_ID[..]));
+    let elf = Elf::from_bytes(include_bytes!("./data/dyn64_be")).unwrap();
+    assert_eq!(elf.build_id(), Some(&DYN64_BUILD_ID[..]));
+
+    // objcopy --only-keep-debug keeps the note.
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/debuglink64.debug")).unwrap();
+    assert_eq!(elf64.build_id(), Some(&DYN64_BUILD_ID[..]));
+
+    let elf32 = Elf32::from_bytes(include_bytes!("./data/elf32")).unwrap();
+    assert_eq!(elf32.build_id().unwrap()[..4], [0xf4, 0xbe, 0x09, 0x03]);
+
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/rel64")).unwrap();
+    assert_eq!(elf64.build_id(), None);
+}
+
+#[test]
+fn debuglink() {
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/debuglink64")).unwrap();
+    let link = elf64.debuglink().unwrap();
+    assert_eq!(
+        link,
+        DebugLink {
+            name: "libdyn.so.debug",
+            crc: 0xef156e81
+        }
+    );
+    assert!(link.matches(include_bytes!("./data/debuglink64.debug")));
+    assert!(!link.matches(include_bytes!("./data/dyn64")));
+
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/dyn64")).unwrap();
+    assert_eq!(elf64.debuglink(), Err(Error::NotFound));
+}
+
+#[test]
+fn debuglink_parse() {
+    let bytes = b"a.debug\0\x78\x56\x34\x12";
+    let link = DebugLink::parse(bytes, ELFDATA2LSB).unwrap();
+    assert_eq!(link.name, "a.debug");
+    assert_eq!(link.crc, 0x12345678);
+    let link = DebugLink::parse(bytes, ELFDATA2MSB).unwrap();
+    assert_eq!(link.crc, 0x78563412);
+    // The name is padded to 4 bytes before the CRC.
+    assert_eq!(
+        DebugLink::parse(b"ab.debug\0\0\0\0\x01\0\0\0", ELFDATA2LSB),
+        Ok(DebugLink {
+            name: "ab.debug",
+            crc: 1
+        })
+    );
+    assert_eq!(
+        DebugLink::parse(b"a.debug\0\x78", ELFDATA2LSB),
+        Err(Error::Corrupted)
+    );
+}
+
+#[test]
+fn crc() {
+    assert_eq!(crc32(b""), 0);
+    assert_eq!(crc32(b"123456789"), 0xcbf43926);
+}
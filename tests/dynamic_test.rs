@@ -0,0 +1,94 @@
+extern crate elf_parser;
+use elf_parser::{
+    types::{
+        DynFlags, DynFlags1, DynTag, DF_1_NOW, DF_1_PIE, DF_BIND_NOW, DT_GNU_HASH, DT_NEEDED,
+        DT_NULL, DT_STRSZ,
+    },
+    Dynamic, DynamicEntry, Elf, Elf32, Elf64, ElfFile, Error,
+};
+
+fn check_dyn64<D: DynamicEntry>(dynamic: &Dynamic<D>) {
+    let needed: Vec<_> = dynamic.needed().map(Result::unwrap).collect();
+    assert_eq!(needed, ["libm.so.6", "libc.so.6"]);
+    assert_eq!(dynamic.soname(), Ok("libdyn.so.1"));
+    assert_eq!(dynamic.runpath(), Ok("/opt/dyn/lib:$ORIGIN"));
+    assert_eq!(dynamic.rpath(), Err(Error::NotFound));
+    assert_eq!(dynamic.get(DT_STRSZ), Some(194));
+    assert_eq!(dynamic.get(DT_GNU_HASH), Some(0x260));
+    assert!(dynamic.flags().contains(DF_BIND_NOW));
+    assert!(dynamic.flags_1().contains(DF_1_NOW));
+    assert!(!dynamic.flags_1().contains(DF_1_PIE));
+    assert_eq!(dynamic.iter().count(), 28);
+}
+
+#[test]
+fn dyn64() {
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/dyn64")).unwrap();
+    check_dyn64(&elf64.dynamic().unwrap());
+}
+
+#[test]
+fn dyn64_be() {
+    let elf = Elf::from_bytes(include_bytes!("./data/dyn64_be")).unwrap();
+    check_dyn64(&elf.dynamic().unwrap());
+}
+
+#[test]
+fn dyn64_stripped() {
+    // Without section headers the dynamic section is found through PT_DYNAMIC alone.
+    let mut bytes = include_bytes!("./data/dyn64").to_vec();
+    bytes[0x28..0x30].fill(0); // e_shoff
+    bytes[0x3c..0x40].fill(0); // e_shnum, e_shstrndx
+    let elf64 = Elf64::from_bytes(&bytes).unwrap();
+    assert_eq!(elf64.shdr_iter().count(), 0);
+    check_dyn64(&elf64.dynamic().unwrap());
+}
+
+#[test]
+fn dyn32() {
+    let elf32 = Elf32::from_bytes(include_bytes!("./data/dyn32")).unwrap();
+    let dynamic = elf32.dynamic().unwrap();
+    let needed: Vec<_> = dynamic.needed().map(Result::unwrap).collect();
+    assert_eq!(needed, ["libm.so.6", "libc.so.6"]);
+    assert_eq!(dynamic.soname(), Ok("libdyn.so.1"));
+    assert_eq!(dynamic.rpath(), Ok("/opt/dyn/lib"));
+    assert_eq!(dynamic.runpath(), Err(Error::NotFound));
+    assert_eq!(dynamic.flags(), DynFlags(0));
+    assert_eq!(dynamic.flags_1(), DynFlags1(0));
+
+    // Iteration stops at the first DT_NULL even though padding entries follow it.
+    assert_eq!(dynamic.iter().count(), 16);
+    assert!(dynamic.iter().all(|entry| entry.d_tag() != DT_NULL));
+}
+
+#[test]
+fn elf_flags() {
+    let elf32 = Elf32::from_bytes(include_bytes!("./data/elf32")).unwrap();
+    let dynamic = elf32.dynamic().unwrap();
+    assert!(dynamic.flags().contains(DF_BIND_NOW));
+    assert!(dynamic.flags_1().contains(DF_1_NOW));
+    assert!(dynamic.flags_1().contains(DF_1_PIE));
+
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/elf64")).unwrap();
+    let dynamic = elf64.dynamic().unwrap();
+    let needed: Vec<_> = dynamic.needed().map(Result::unwrap).collect();
+    assert_eq!(needed, ["libc.so.6"]);
+    assert_eq!(dynamic.soname(), Err(Error::NotFound));
+}
+
+#[test]
+fn no_dynamic() {
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/rel64")).unwrap();
+    assert_eq!(elf64.dynamic().err(), Some(Error::NotFound));
+}
+
+#[test]
+fn dyn_tag_debug() {
+    assert_eq!(format!("{:?}", DT_NEEDED), "DT_NEEDED");
+    assert_eq!(format!("{:?}", DT_GNU_HASH), "DT_GNU_HASH");
+    assert_eq!(format!("{:?}", DynTag(0x12345)), "UNKNOWN");
+    assert_eq!(
+        format!("{:?}", DynFlags1(DF_1_NOW.0 | DF_1_PIE.0)),
+        "DF_1_NOW DF_1_PIE "
+    );
+}
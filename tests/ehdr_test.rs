@@ -1,5 +1,5 @@
 extern crate elf_parser;
-use elf_parser::{Elf32, Elf64};
+use elf_parser::{types::ELFDATA2MSB, Elf32, Elf64, Error};
 
 #[test]
 fn not_elf() {
@@ -69,3 +69,61 @@ fn elf32_ehdr() {
     assert_eq!(ehdr.e_shnum, 0x1d);
     assert_eq!(ehdr.e_shstrndx, 0x1c);
 }
+
+#[test]
+fn invalid_data() {
+    let mut bytes = *include_bytes!("./data/elf64");
+    bytes[5] = 0;
+    let elf64 = Elf64::from_bytes(&bytes);
+    assert_eq!(elf64.err(), Some(Error::InvalidData));
+}
+
+#[test]
+fn elf64_be_ehdr() {
+    let bytes = include_bytes!("./data/elf64_be");
+    let elf64 = Elf64::from_bytes(bytes).unwrap();
+    assert!(elf64.data() == ELFDATA2MSB);
+    let ehdr = elf64.ehdr();
+    assert_eq!(
+        ehdr.e_ident,
+        [0x7f, 0x45, 0x4c, 0x46, 0x02, 0x02, 0x01, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]
+    );
+    assert_eq!(ehdr.e_type, 0x2);
+    assert_eq!(ehdr.e_machine, 0x3e);
+    assert_eq!(ehdr.e_version, 0x1);
+    assert_eq!(ehdr.e_entry, 0x401020);
+    assert_eq!(ehdr.e_phoff, 0x40);
+    assert_eq!(ehdr.e_shoff, 0x54b0);
+    assert_eq!(ehdr.e_flags, 0x0);
+    assert_eq!(ehdr.e_ehsize, 0x40);
+    assert_eq!(ehdr.e_phentsize, 0x38);
+    assert_eq!(ehdr.e_phnum, 0xd);
+    assert_eq!(ehdr.e_shentsize, 0x40);
+    assert_eq!(ehdr.e_shnum, 0x1d);
+    assert_eq!(ehdr.e_shstrndx, 0x1c);
+}
+
+#[test]
+fn elf32_be_ehdr() {
+    let bytes = include_bytes!("./data/elf32_be");
+    let elf32 = Elf32::from_bytes(bytes).unwrap();
+    assert!(elf32.data() == ELFDATA2MSB);
+    let ehdr = elf32.ehdr();
+    assert_eq!(
+        ehdr.e_ident,
+        [0x7f, 0x45, 0x4c, 0x46, 0x01, 0x02, 0x01, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]
+    );
+    assert_eq!(ehdr.e_type, 0x3);
+    assert_eq!(ehdr.e_machine, 0x3);
+    assert_eq!(ehdr.e_version, 0x1);
+    assert_eq!(ehdr.e_entry, 0x3b0);
+    assert_eq!(ehdr.e_phoff, 0x34);
+    assert_eq!(ehdr.e_shoff, 0x1788);
+    assert_eq!(ehdr.e_flags, 0x0);
+    assert_eq!(ehdr.e_ehsize, 0x34);
+    assert_eq!(ehdr.e_phentsize, 0x20);
+    assert_eq!(ehdr.e_phnum, 0x9);
+    assert_eq!(ehdr.e_shentsize, 0x28);
+    assert_eq!(ehdr.e_shnum, 0x1d);
+    assert_eq!(ehdr.e_shstrndx, 0x1c);
+}
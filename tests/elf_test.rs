@@ -0,0 +1,68 @@
+extern crate elf_parser;
+use elf_parser::{
+    types::{ELFCLASS32, ELFCLASS64},
+    Elf, Elf32, Elf64, Error, Phdr, Shdr,
+};
+
+#[test]
+fn not_elf() {
+    let bytes = include_bytes!("./data/not_elf");
+    let elf = Elf::from_bytes(bytes);
+    assert_eq!(elf.err(), Some(Error::InvalidMagicNumber));
+}
+
+#[test]
+fn invalid_class() {
+    let mut bytes = *include_bytes!("./data/elf64");
+    bytes[4] = 3;
+    let elf = Elf::from_bytes(&bytes);
+    assert_eq!(elf.err(), Some(Error::InvalidClass));
+}
+
+#[test]
+fn elf_detect_elf64() {
+    let bytes = include_bytes!("./data/elf64");
+    let elf = Elf::from_bytes(bytes).unwrap();
+    let elf64 = Elf64::from_bytes(bytes).unwrap();
+    assert!(elf.class() == ELFCLASS64);
+    assert!(matches!(elf, Elf::Elf64(_)));
+
+    let ehdr = elf.ehdr();
+    assert_eq!(ehdr.e_entry, elf64.ehdr().e_entry);
+    assert_eq!(ehdr.e_shoff, 0x54b0);
+    assert_eq!(elf.entry_point(), 0x401020);
+    assert_eq!(elf.phdr_num(), elf64.phdr_num());
+    assert_eq!(elf.shdr_num(), elf64.shdr_num());
+    assert_eq!(elf.phdr_iter().count(), elf64.phdr_num() as usize);
+    for (phdr, phdr64) in elf.phdr_iter().zip(elf64.phdr_iter()) {
+        assert_eq!(phdr, Phdr::from(phdr64));
+    }
+    for (shdr, shdr64) in elf.shdr_iter().zip(elf64.shdr_iter()) {
+        assert_eq!(shdr, Shdr::from(shdr64));
+    }
+}
+
+#[test]
+fn elf_detect_elf32() {
+    let bytes = include_bytes!("./data/elf32");
+    let elf = Elf::from_bytes(bytes).unwrap();
+    let elf32 = Elf32::from_bytes(bytes).unwrap();
+    assert!(elf.class() == ELFCLASS32);
+    assert!(matches!(elf, Elf::Elf32(_)));
+
+    let ehdr = elf.ehdr();
+    assert_eq!(ehdr.e_entry, 0x3b0);
+    assert_eq!(ehdr.e_shoff, 0x1788);
+    assert_eq!(elf.phdr_num(), elf32.phdr_num());
+    assert_eq!(elf.shdr_iter().count(), elf32.shdr_num() as usize);
+
+    let phdr4 = elf.phdr_nth(4).unwrap();
+    assert_eq!(phdr4.p_type, 0x2);
+    assert_eq!(phdr4.p_vaddr, 0x1ee4);
+    assert_eq!(phdr4.p_filesz, 0xf8);
+
+    let shdr10 = elf.shdr_nth(10).unwrap();
+    assert_eq!(shdr10, Shdr::from(elf32.shdr_nth(10).unwrap()));
+    assert_eq!(shdr10.sh_flags, 0x42);
+    assert_eq!(elf.shdr_nth(elf.shdr_num()), Err(Error::InvalidIndex));
+}
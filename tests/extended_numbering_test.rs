@@ -0,0 +1,71 @@
+extern crate elf_parser;
+use elf_parser::{Elf, Elf32, Elf64, Error};
+
+fn extend_elf64(bytes: &mut [u8]) {
+    let shoff = 0x54b0;
+    bytes[56..58].copy_from_slice(&0xffffu16.to_le_bytes());
+    bytes[60..62].copy_from_slice(&0u16.to_le_bytes());
+    bytes[62..64].copy_from_slice(&0xffffu16.to_le_bytes());
+    bytes[shoff + 32..shoff + 40].copy_from_slice(&0x1du64.to_le_bytes());
+    bytes[shoff + 40..shoff + 44].copy_from_slice(&0x1cu32.to_le_bytes());
+    bytes[shoff + 44..shoff + 48].copy_from_slice(&0xdu32.to_le_bytes());
+}
+
+#[test]
+fn elf64_extended_numbering() {
+    let original = include_bytes!("./data/elf64");
+    let mut bytes = *original;
+    extend_elf64(&mut bytes);
+
+    let elf64 = Elf64::from_bytes(&bytes).unwrap();
+    let ehdr = elf64.ehdr();
+    assert_eq!(ehdr.e_phnum, 0xffff);
+    assert_eq!(ehdr.e_shnum, 0x0);
+    assert_eq!(ehdr.e_shstrndx, 0xffff);
+    assert_eq!(elf64.phdr_num(), 0xd);
+    assert_eq!(elf64.shdr_num(), 0x1d);
+    assert_eq!(elf64.shstrndx(), 0x1c);
+
+    let original = Elf64::from_bytes(original).unwrap();
+    for (phdr, original_phdr) in elf64.phdr_iter().zip(original.phdr_iter()) {
+        assert_eq!(phdr, original_phdr);
+    }
+    assert_eq!(elf64.shdr_iter().count(), 0x1d);
+    assert_eq!(elf64.shdr_nth(0x1c), original.shdr_nth(0x1c));
+    assert_eq!(elf64.shdr_nth(0x1d), Err(Error::InvalidIndex));
+
+    let elf = Elf::from_bytes(&bytes).unwrap();
+    assert_eq!(elf.shdr_num(), 0x1d);
+    assert_eq!(elf.shstrndx(), 0x1c);
+}
+
+#[test]
+fn elf32_extended_numbering() {
+    let original = include_bytes!("./data/elf32");
+    let mut bytes = *original;
+    let shoff = 0x1788;
+    bytes[48..50].copy_from_slice(&0u16.to_le_bytes());
+    bytes[50..52].copy_from_slice(&0xffffu16.to_le_bytes());
+    bytes[shoff + 20..shoff + 24].copy_from_slice(&0x1du32.to_le_bytes());
+    bytes[shoff + 24..shoff + 28].copy_from_slice(&0x1cu32.to_le_bytes());
+
+    let elf32 = Elf32::from_bytes(&bytes).unwrap();
+    assert_eq!(elf32.phdr_num(), 0x9);
+    assert_eq!(elf32.shdr_num(), 0x1d);
+    assert_eq!(elf32.shstrndx(), 0x1c);
+    assert_eq!(elf32.shdr_iter().count(), 0x1d);
+}
+
+#[test]
+fn extended_count_out_of_file() {
+    let mut bytes = *include_bytes!("./data/elf64");
+    extend_elf64(&mut bytes);
+    let shoff = 0x54b0;
+    bytes[shoff + 32..shoff + 40].copy_from_slice(&0x10000u64.to_le_bytes());
+    assert_eq!(Elf64::from_bytes(&bytes).err(), Some(Error::Corrupted));
+
+    let mut bytes = *include_bytes!("./data/elf64");
+    bytes[60..62].copy_from_slice(&0u16.to_le_bytes());
+    bytes[40..48].copy_from_slice(&0x5bc0u64.to_le_bytes());
+    assert_eq!(Elf64::from_bytes(&bytes).err(), Some(Error::Corrupted));
+}
@@ -0,0 +1,44 @@
+extern crate elf_parser;
+use elf_parser::{
+    types::{PT_LOAD, SHT_NOBITS},
+    Elf, Elf32, Elf64, ElfFile, FileHeader, ProgramHeader, SectionHeader,
+};
+
+fn load_memsz<'a, F: ElfFile<'a>>(file: &F) -> u64 {
+    file.phdr_iter()
+        .filter(|phdr| phdr.p_type() == PT_LOAD.0)
+        .map(|phdr| phdr.p_memsz())
+        .sum()
+}
+
+fn nobits_size<'a, F: ElfFile<'a>>(file: &F) -> u64 {
+    file.shdr_iter()
+        .filter(|shdr| shdr.sh_type() == SHT_NOBITS.0)
+        .map(|shdr| shdr.sh_size())
+        .sum()
+}
+
+#[test]
+fn elf64_file() {
+    let bytes = include_bytes!("./data/elf64");
+    let elf64 = Elf64::from_bytes(bytes).unwrap();
+    let elf = Elf::from_bytes(bytes).unwrap();
+    assert_eq!(ElfFile::ehdr(&elf64).e_phoff(), 0x40);
+    assert_eq!(ElfFile::entry_point(&elf64), 0x401020);
+    assert_eq!(load_memsz(&elf64), 0x4b8 + 0x125 + 0x9c + 0x1d0);
+    assert_eq!(load_memsz(&elf64), load_memsz(&elf));
+    assert_eq!(nobits_size(&elf64), 0x4);
+    assert_eq!(nobits_size(&elf64), nobits_size(&elf));
+}
+
+#[test]
+fn elf32_file() {
+    let bytes = include_bytes!("./data/elf32");
+    let elf32 = Elf32::from_bytes(bytes).unwrap();
+    let elf = Elf::from_bytes(bytes).unwrap();
+    assert_eq!(ElfFile::ehdr(&elf32).e_shoff(), 0x1788);
+    assert_eq!(ElfFile::entry_point(&elf32), 0x3b0);
+    assert_eq!(load_memsz(&elf32), load_memsz(&elf));
+    assert_eq!(nobits_size(&elf32), 0x4);
+    assert_eq!(elf32.phdr_iter().count(), elf32.phdr_num() as usize);
+}
@@ -0,0 +1,112 @@
+extern crate elf_parser;
+use elf_parser::{
+    gnu_hash, sysv_hash,
+    types::{ShType, ELFCLASS64, ELFDATA2LSB, SHN_UNDEF, SHT_GNU_HASH, SHT_HASH},
+    Elf, Elf32, Elf64, ElfFile, Error, GnuHashTable, HashTable, SectionHeader, Symbol,
+};
+
+#[test]
+fn hash_functions() {
+    assert_eq!(sysv_hash(b""), 0);
+    assert_eq!(sysv_hash(b"printf"), 0x077905a6);
+    assert_eq!(sysv_hash(b"freelocale"), 0x0c335095);
+    assert_eq!(gnu_hash(b""), 0x1505);
+    assert_eq!(gnu_hash(b"printf"), 0x156b2bb8);
+}
+
+/// Look up every dynamic symbol through both hash tables.
+fn check_lookup<'a, F: ElfFile<'a>>(file: &F) {
+    let symtab = file.dynsym().unwrap();
+    let sysv = file
+        .hash_table(&file.section_by_type(SHT_HASH).unwrap())
+        .unwrap();
+    let gnu = file
+        .hash_table(&file.section_by_type(SHT_GNU_HASH).unwrap())
+        .unwrap();
+    assert!(matches!(sysv, HashTable::Sysv(table) if table.nchain() == 304));
+    assert!(matches!(gnu, HashTable::Gnu(table) if table.symoffset() == 2));
+
+    let mut defined = 0;
+    for entry in symtab.iter() {
+        let (sym, name) = entry.unwrap();
+        if sym.st_shndx() == SHN_UNDEF.0 {
+            continue;
+        }
+        defined += 1;
+        let name_of = |sym: Option<F::Sym>| sym.map(|sym| sym.st_name());
+        assert_eq!(name_of(sysv.lookup(&symtab, name)), Some(sym.st_name()));
+        assert_eq!(name_of(gnu.lookup(&symtab, name)), Some(sym.st_name()));
+        assert_eq!(name_of(file.lookup_dynsym(name)), Some(sym.st_name()));
+    }
+    assert_eq!(defined, 302);
+
+    let counter = file.lookup_dynsym("counter").unwrap();
+    assert_eq!(counter.st_size(), 4);
+
+    // Undefined symbols are not definitions.
+    for table in [sysv, gnu] {
+        assert!(table.lookup(&symtab, "puts").is_none());
+        assert!(table.lookup(&symtab, "func_300").is_none());
+        assert!(table.lookup(&symtab, "").is_none());
+    }
+
+    let dynsym = file.section_by_name(".dynsym").unwrap();
+    assert!(file.hash_table(&dynsym).err() == Some(Error::NotFound));
+}
+
+#[test]
+fn hash64() {
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/hash64")).unwrap();
+    check_lookup(&elf64);
+    assert_eq!(elf64.lookup_dynsym("func_0").unwrap().st_value, 0x4020);
+    assert_eq!(elf64.lookup_dynsym("call_puts").unwrap().st_value, 0x46d3);
+}
+
+#[test]
+fn hash64_be() {
+    let elf = Elf::from_bytes(include_bytes!("./data/hash64_be")).unwrap();
+    check_lookup(&elf);
+    assert_eq!(elf.lookup_dynsym("func_0").unwrap().st_value(), 0x4020);
+}
+
+#[test]
+fn hash32() {
+    let elf32 = Elf32::from_bytes(include_bytes!("./data/hash32")).unwrap();
+    check_lookup(&elf32);
+}
+
+#[test]
+fn gnu_hash_only() {
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/dyn64")).unwrap();
+    let shdr = elf64.section_by_name(".gnu.hash").unwrap();
+    assert_eq!(ShType(shdr.sh_type()), SHT_GNU_HASH);
+    assert_eq!(format!("{:?}", ShType(shdr.sh_type())), "SHT_GNU_HASH");
+    assert_eq!(elf64.lookup_dynsym("dyn_add").unwrap().st_value, 0x1119);
+    assert_eq!(elf64.lookup_dynsym("dyn_counter").unwrap().st_value, 0x400c);
+    assert!(elf64.lookup_dynsym("__cxa_finalize").is_none());
+
+    // Without a hash table there is nothing to look symbols up in.
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/rel64")).unwrap();
+    assert!(elf64.section_by_type(SHT_HASH).is_none());
+    assert!(elf64.section_by_type(SHT_GNU_HASH).is_none());
+    assert!(elf64.lookup_dynsym("add").is_none());
+}
+
+#[test]
+fn corrupted() {
+    let bytes = include_bytes!("./data/dyn64");
+    let elf64 = Elf64::from_bytes(bytes).unwrap();
+    let offset = elf64.section_by_name(".gnu.hash").unwrap().sh_offset() as usize;
+
+    // The second bloom filter bit is hash >> bloom_shift, so the shift has to be below 32.
+    let mut bytes = bytes.to_vec();
+    bytes[offset + 12..offset + 16].copy_from_slice(&32u32.to_le_bytes());
+    assert_eq!(
+        GnuHashTable::parse(&bytes[offset..], ELFCLASS64, ELFDATA2LSB).err(),
+        Some(Error::Corrupted)
+    );
+    let elf64 = Elf64::from_bytes(&bytes).unwrap();
+    let shdr = elf64.section_by_name(".gnu.hash").unwrap();
+    assert_eq!(elf64.hash_table(&shdr).err(), Some(Error::Corrupted));
+    assert!(elf64.lookup_dynsym("dyn_add").is_none());
+}
@@ -0,0 +1,177 @@
+extern crate elf_parser;
+use elf_parser::{
+    types::{PFlag, PF_R, PF_W, PF_X},
+    Elf, Elf32, Elf64, ElfFile, Error, LoadedImage, Loader, Region,
+};
+
+/// Load `file` at `bias` into one buffer covering its extent, which starts out filled with
+/// 0xff so that zeroing shows. Returns the buffer and the regions that were mapped.
+fn load<'a, F: ElfFile<'a>>(
+    file: &F,
+    bias: u64,
+) -> (Result<LoadedImage<'a>, Error>, Vec<u8>, Vec<Region>) {
+    let loader = Loader::new(file, 0x1000).unwrap();
+    let (start, end) = loader.extent().unwrap();
+    let mut image = vec![0xff; (end - start) as usize];
+    let mut regions = Vec::new();
+    let mut rest = Some(&mut image[..]);
+    let mut cursor = start + bias;
+    let result = loader.load(bias, |region| {
+        regions.push(*region);
+        let memory = rest.take().unwrap();
+        let (_, memory) = memory.split_at_mut((region.vaddr - cursor) as usize);
+        let (memory, tail) = memory.split_at_mut(region.size as usize);
+        rest = Some(tail);
+        cursor = region.vaddr + region.size;
+        Ok(memory)
+    });
+    (result, image, regions)
+}
+
+#[test]
+fn executable() {
+    let bytes = include_bytes!("./data/core_x86_64_exe");
+    let elf64 = Elf64::from_bytes(bytes).unwrap();
+    let loader = Loader::new(&elf64, 0x1000).unwrap();
+    assert_eq!(loader.extent(), Ok((0x400000, 0x407000)));
+    assert_eq!(loader.align(), 0x1000);
+
+    let (result, image, regions) = load(&elf64, 0);
+    assert_eq!(
+        result,
+        Ok(LoadedImage {
+            bias: 0,
+            entry: 0x401040,
+            phdr: Some(0x400040),
+            interp: Some("/lib64/ld-linux-x86-64.so.2"),
+        })
+    );
+    assert_eq!(
+        regions,
+        [
+            Region {
+                vaddr: 0x400000,
+                size: 0x1000,
+                flags: PF_R,
+            },
+            Region {
+                vaddr: 0x401000,
+                size: 0x1000,
+                flags: PFlag(PF_R.0 | PF_X.0),
+            },
+            Region {
+                vaddr: 0x402000,
+                size: 0x1000,
+                flags: PF_R,
+            },
+            Region {
+                vaddr: 0x403000,
+                size: 0x4000,
+                flags: PFlag(PF_R.0 | PF_W.0),
+            },
+        ]
+    );
+    assert!(regions[3].flags.contains(PF_W));
+    assert!(!regions[2].flags.contains(PF_W));
+
+    // The file contents, and zeroes around them and in .bss.
+    assert_eq!(image[..0x4f0], bytes[..0x4f0]);
+    assert!(image[0x4f0..0x1000].iter().all(|&b| b == 0));
+    assert_eq!(image[0x1000..0x118d], bytes[0x1000..0x118d]);
+    assert!(image[0x3000..0x3df8].iter().all(|&b| b == 0));
+    assert_eq!(image[0x3df8..0x4080], bytes[0x2df8..0x3080]);
+    assert_eq!(&image[0x4040..0x4057], b"elf_parser core fixture");
+    assert!(image[0x4080..].iter().all(|&b| b == 0));
+
+    // An executable is linked for its addresses.
+    let (result, _, _) = load(&elf64, 0x1000_0000);
+    assert_eq!(result, Err(Error::InvalidFileType));
+}
+
+#[test]
+fn position_independent() {
+    let bytes = include_bytes!("./data/elf32");
+    let elf32 = Elf32::from_bytes(bytes).unwrap();
+    let (result, image, regions) = load(&elf32, 0x5655_5000);
+    assert_eq!(
+        result,
+        Ok(LoadedImage {
+            bias: 0x5655_5000,
+            entry: 0x5655_53b0,
+            phdr: Some(0x5655_5034),
+            interp: Some("/lib/ld-linux.so.2"),
+        })
+    );
+    assert_eq!(regions.len(), 2);
+    assert_eq!(regions[1].vaddr, 0x5655_6000);
+    assert_eq!(image[..0x6b4], bytes[..0x6b4]);
+    assert_eq!(image[0x1edc..0x2008], bytes[0xedc..0x1008]);
+    assert!(image[0x2008..].iter().all(|&b| b == 0));
+
+    let (result, _, _) = load(&elf32, 0x5655_5800);
+    assert_eq!(result, Err(Error::InvalidAlignment));
+}
+
+#[test]
+fn shared_object() {
+    // Without PT_PHDR, the program headers are found in the first PT_LOAD segment.
+    let elf = Elf::from_bytes(include_bytes!("./data/dyn64")).unwrap();
+    let (result, _, regions) = load(&elf, 0x7f00_0000_0000);
+    let image = result.unwrap();
+    assert_eq!(image.phdr, Some(0x7f00_0000_0040));
+    assert_eq!(image.interp, None);
+    assert_eq!(regions[0].vaddr, 0x7f00_0000_0000);
+}
+
+#[test]
+fn shared_page() {
+    // The executable is linked for 4K pages, so with 64K pages all its segments share the
+    // page at 0x400000, and loading one would zero the others.
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/core_x86_64_exe")).unwrap();
+    let loader = Loader::new(&elf64, 0x10000).unwrap();
+    assert_eq!(loader.extent(), Err(Error::InvalidAlignment));
+    let mut mapped = 0;
+    assert_eq!(
+        loader.load(0, |_| {
+            mapped += 1;
+            Err(Error::NotFound)
+        }),
+        Err(Error::InvalidAlignment)
+    );
+    assert_eq!(mapped, 0);
+}
+
+#[test]
+fn errors() {
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/rel64")).unwrap();
+    assert_eq!(
+        Loader::new(&elf64, 0x1000).err(),
+        Some(Error::InvalidFileType)
+    );
+
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/elf64")).unwrap();
+    assert_eq!(
+        Loader::new(&elf64, 3000).err(),
+        Some(Error::InvalidAlignment)
+    );
+    let loader = Loader::new(&elf64, 0x1000).unwrap();
+    let mut small = [0; 0x100];
+    let mut small = Some(&mut small[..]);
+    assert_eq!(
+        loader.load(0, |_| small.take().ok_or(Error::NotFound)),
+        Err(Error::BufferTooSmall)
+    );
+    assert_eq!(
+        loader.load(0, |_| Err(Error::NotFound)),
+        Err(Error::NotFound)
+    );
+
+    // p_vaddr of the second PT_LOAD no longer agrees with p_offset modulo p_align.
+    let mut bytes = include_bytes!("./data/elf64").to_vec();
+    let phdr = 0x40 + 56 * 3;
+    assert_eq!(bytes[phdr..phdr + 4], [1, 0, 0, 0]);
+    bytes[phdr + 16] = 0x10;
+    let elf64 = Elf64::from_bytes(&bytes).unwrap();
+    let loader = Loader::new(&elf64, 0x1000).unwrap();
+    assert_eq!(loader.extent().err(), Some(Error::InvalidAlignment));
+}
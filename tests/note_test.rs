@@ -0,0 +1,106 @@
+extern crate elf_parser;
+use elf_parser::{
+    types::{
+        GnuNoteType, ELFDATA2LSB, ELF_NOTE_OS_LINUX, NT_GNU_ABI_TAG, NT_GNU_BUILD_ID,
+        NT_GNU_PROPERTY_TYPE_0, PT_NOTE,
+    },
+    AbiTag, Elf, Elf32, Elf64, ElfFile, Error, NoteIter, ProgramHeader,
+};
+
+const ELF64_BUILD_ID: [u8; 20] = [
+    0xd6, 0x3c, 0xc8, 0xd5, 0x57, 0x1f, 0xc5, 0x38, 0x3d, 0x73, 0x27, 0x8a, 0x95, 0xeb, 0x25, 0x7b,
+    0xa3, 0x20, 0x1c, 0xf4,
+];
+
+fn check_elf64<'a, F: ElfFile<'a>>(file: &F) {
+    let segments: Vec<_> = file
+        .phdr_iter()
+        .filter(|phdr| phdr.p_type() == PT_NOTE.0)
+        .collect();
+    assert_eq!(segments.len(), 2);
+
+    // .note.gnu.property is 8-byte aligned.
+    assert_eq!(segments[0].p_align(), 8);
+    let notes: Vec<_> = file.segment_notes(&segments[0]).unwrap().collect();
+    assert_eq!(notes.len(), 1);
+    assert_eq!(notes[0].name, b"GNU");
+    assert_eq!(notes[0].gnu_type(), Some(NT_GNU_PROPERTY_TYPE_0));
+    assert_eq!(notes[0].desc.len(), 0x30);
+
+    // .note.gnu.build-id and .note.ABI-tag share a 4-byte aligned segment.
+    let notes: Vec<_> = file.segment_notes(&segments[1]).unwrap().collect();
+    assert_eq!(notes.len(), 2);
+    assert_eq!(notes[0].gnu_type(), Some(NT_GNU_BUILD_ID));
+    assert_eq!(notes[0].build_id(), Ok(&ELF64_BUILD_ID[..]));
+    assert_eq!(notes[1].n_type, NT_GNU_ABI_TAG.0);
+    assert_eq!(notes[1].build_id(), Err(Error::NotFound));
+    assert_eq!(
+        notes[1].abi_tag(file.data()),
+        Ok(AbiTag {
+            os: ELF_NOTE_OS_LINUX,
+            major: 3,
+            minor: 2,
+            patch: 0
+        })
+    );
+
+    let shdr = file.section_by_name(".gnu.build.attributes").unwrap();
+    let notes: Vec<_> = file.section_notes(&shdr).unwrap().collect();
+    assert_eq!(notes.len(), 221);
+    assert!(notes.iter().all(|note| note.name.starts_with(b"GA")));
+    assert!(notes.iter().all(|note| !note.is_gnu()));
+}
+
+#[test]
+fn elf64() {
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/elf64")).unwrap();
+    check_elf64(&elf64);
+}
+
+#[test]
+fn elf64_be() {
+    let elf = Elf::from_bytes(include_bytes!("./data/elf64_be")).unwrap();
+    check_elf64(&elf);
+}
+
+#[test]
+fn elf32() {
+    let elf32 = Elf32::from_bytes(include_bytes!("./data/elf32")).unwrap();
+    let shdr = elf32.section_by_name(".note.gnu.build-id").unwrap();
+    let note = elf32.section_notes(&shdr).unwrap().next().unwrap();
+    assert_eq!(note.build_id().unwrap()[..4], [0xf4, 0xbe, 0x09, 0x03]);
+
+    let shdr = elf32.section_by_name(".note.ABI-tag").unwrap();
+    let note = elf32.section_notes(&shdr).unwrap().next().unwrap();
+    assert_eq!(note.abi_tag(elf32.data()).unwrap().major, 3);
+
+    let shdr = elf32.section_by_name(".text").unwrap();
+    assert_eq!(elf32.section_notes(&shdr).err(), Some(Error::NotFound));
+    let phdr = elf32.phdr_nth(0).unwrap();
+    assert_eq!(elf32.segment_notes(&phdr).err(), Some(Error::NotFound));
+}
+
+#[test]
+fn alignment() {
+    // The name ends 17 bytes into the note, so the descriptor starts at offset 20, or at
+    // offset 24 when the notes are 8-byte aligned.
+    #[rustfmt::skip]
+    let bytes = [
+        5, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0,
+        b'A', b'B', b'C', b'D', 0, 0, 0, 0, 0, 0, 0, 0,
+        0xaa, 0xbb, 0xcc, 0xdd, 0, 0, 0, 0,
+    ];
+    let note = NoteIter::new(&bytes, 4, ELFDATA2LSB).next().unwrap();
+    assert_eq!(note.name, b"ABCD");
+    assert_eq!(note.desc, [0, 0, 0, 0]);
+
+    let mut notes = NoteIter::new(&bytes, 8, ELFDATA2LSB);
+    let note = notes.next().unwrap();
+    assert_eq!(note.name, b"ABCD");
+    assert_eq!(note.desc, [0xaa, 0xbb, 0xcc, 0xdd]);
+    assert_eq!(notes.next(), None);
+
+    // A descriptor running past the end stops the iteration.
+    assert_eq!(NoteIter::new(&bytes[..26], 8, ELFDATA2LSB).next(), None);
+    assert_eq!(format!("{:?}", GnuNoteType(3)), "NT_GNU_BUILD_ID");
+}
@@ -7,9 +7,9 @@ fn elf64_phdr() {
     let elf64 = Elf64::from_bytes(bytes).unwrap();
     let ehdr = elf64.ehdr();
     let phdr_iter = elf64.phdr_iter();
-    assert_eq!(elf64.phdr_num(), ehdr.e_phnum);
+    assert_eq!(elf64.phdr_num(), ehdr.e_phnum as u32);
     for (i, phdr) in phdr_iter.enumerate() {
-        assert_eq!(elf64.phdr_nth(i as u16).unwrap(), phdr);
+        assert_eq!(elf64.phdr_nth(i as u32).unwrap(), phdr);
     }
     let phdr0 = elf64.phdr_nth(0).unwrap();
     assert_eq!(phdr0.p_type, 0x6);
@@ -38,9 +38,9 @@ fn elf32_phdr() {
     let elf32 = Elf32::from_bytes(bytes).unwrap();
     let ehdr = elf32.ehdr();
     let phdr_iter = elf32.phdr_iter();
-    assert_eq!(elf32.phdr_num(), ehdr.e_phnum);
+    assert_eq!(elf32.phdr_num(), ehdr.e_phnum as u32);
     for (i, phdr) in phdr_iter.enumerate() {
-        assert_eq!(elf32.phdr_nth(i as u16).unwrap(), phdr.unwrap());
+        assert_eq!(elf32.phdr_nth(i as u32).unwrap(), phdr);
     }
     let phdr0 = elf32.phdr_nth(0).unwrap();
     assert_eq!(phdr0.p_type, 0x6);
@@ -62,3 +62,23 @@ fn elf32_phdr() {
     assert_eq!(phdr4.p_memsz, 0xf8);
     assert_eq!(phdr4.p_align, 0x4);
 }
+
+#[test]
+fn elf64_be_phdr() {
+    let le = Elf64::from_bytes(include_bytes!("./data/elf64")).unwrap();
+    let be = Elf64::from_bytes(include_bytes!("./data/elf64_be")).unwrap();
+    assert_eq!(le.phdr_num(), be.phdr_num());
+    for (le_phdr, be_phdr) in le.phdr_iter().zip(be.phdr_iter()) {
+        assert_eq!(le_phdr, be_phdr);
+    }
+}
+
+#[test]
+fn elf32_be_phdr() {
+    let le = Elf32::from_bytes(include_bytes!("./data/elf32")).unwrap();
+    let be = Elf32::from_bytes(include_bytes!("./data/elf32_be")).unwrap();
+    assert_eq!(le.phdr_num(), be.phdr_num());
+    for (le_phdr, be_phdr) in le.phdr_iter().zip(be.phdr_iter()) {
+        assert_eq!(le_phdr, be_phdr);
+    }
+}
@@ -0,0 +1,104 @@
+extern crate elf_parser;
+use elf_parser::{
+    types::{
+        GnuPropertyType, X86Feature1, X86Isa1, ELFCLASS32, ELFDATA2LSB, EM_ARM,
+        GNU_PROPERTY_AARCH64_FEATURE_1_AND, GNU_PROPERTY_AARCH64_FEATURE_1_BTI,
+        GNU_PROPERTY_AARCH64_FEATURE_1_PAC, GNU_PROPERTY_X86_FEATURE_1_IBT,
+        GNU_PROPERTY_X86_FEATURE_1_SHSTK, GNU_PROPERTY_X86_ISA_1_BASELINE,
+        GNU_PROPERTY_X86_ISA_1_V2, GNU_PROPERTY_X86_ISA_1_V3,
+    },
+    Elf, Elf32, Elf64, ElfFile, Error, GnuProperty, GnuPropertyIter,
+};
+
+fn check_prop64<'a, F: ElfFile<'a>>(file: &F) {
+    let properties: Vec<_> = file.gnu_properties().unwrap().collect();
+    assert_eq!(
+        properties,
+        [
+            GnuProperty::StackSize(0x10000),
+            GnuProperty::NoCopyOnProtected,
+            GnuProperty::X86Isa1Needed(X86Isa1(6)),
+            GnuProperty::X86Feature1And(X86Feature1(3)),
+        ]
+    );
+    let GnuProperty::X86Feature1And(features) = properties[3] else {
+        unreachable!()
+    };
+    assert!(features.contains(GNU_PROPERTY_X86_FEATURE_1_IBT));
+    assert!(features.contains(GNU_PROPERTY_X86_FEATURE_1_SHSTK));
+    let GnuProperty::X86Isa1Needed(isa) = properties[2] else {
+        unreachable!()
+    };
+    assert!(isa.contains(GNU_PROPERTY_X86_ISA_1_V2));
+    assert!(isa.contains(GNU_PROPERTY_X86_ISA_1_V3));
+    assert!(!isa.contains(GNU_PROPERTY_X86_ISA_1_BASELINE));
+}
+
+#[test]
+fn prop64() {
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/prop64")).unwrap();
+    check_prop64(&elf64);
+}
+
+#[test]
+fn prop64_be() {
+    let elf = Elf::from_bytes(include_bytes!("./data/prop64_be")).unwrap();
+    check_prop64(&elf);
+}
+
+#[test]
+fn prop32() {
+    // In ELF32 the property data are padded to 4 bytes.
+    let elf32 = Elf32::from_bytes(include_bytes!("./data/prop32")).unwrap();
+    let properties: Vec<_> = elf32.gnu_properties().unwrap().collect();
+    assert_eq!(properties, [GnuProperty::X86Feature1And(X86Feature1(3))]);
+}
+
+#[test]
+fn aarch64() {
+    let elf = Elf::from_bytes(include_bytes!("./data/prop_aarch64")).unwrap();
+    let properties: Vec<_> = elf.gnu_properties().unwrap().collect();
+    let [GnuProperty::Aarch64Feature1And(features)] = properties[..] else {
+        panic!("{:?}", properties)
+    };
+    assert!(features.contains(GNU_PROPERTY_AARCH64_FEATURE_1_BTI));
+    assert!(features.contains(GNU_PROPERTY_AARCH64_FEATURE_1_PAC));
+    assert_eq!(format!("{:?}", features), "BTI PAC ");
+}
+
+#[test]
+fn executable() {
+    // An executable is read through PT_GNU_PROPERTY.
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/elf64")).unwrap();
+    let properties: Vec<_> = elf64.gnu_properties().unwrap().collect();
+    assert_eq!(
+        properties,
+        [
+            GnuProperty::X86Isa1Needed(GNU_PROPERTY_X86_ISA_1_BASELINE),
+            GnuProperty::X86Feature2Used(1),
+            GnuProperty::X86Isa1Used(X86Isa1(0)),
+        ]
+    );
+
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/dyn64")).unwrap();
+    assert_eq!(elf64.gnu_properties().err(), Some(Error::NotFound));
+}
+
+#[test]
+fn other_machine() {
+    // Processor-specific properties are left undecoded for other machines.
+    let desc = [0x00, 0x00, 0x00, 0xc0, 4, 0, 0, 0, 3, 0, 0, 0];
+    let mut properties = GnuPropertyIter::new(&desc, EM_ARM, ELFCLASS32, ELFDATA2LSB);
+    assert_eq!(
+        properties.next(),
+        Some(GnuProperty::Unknown {
+            pr_type: GNU_PROPERTY_AARCH64_FEATURE_1_AND,
+            data: &[3, 0, 0, 0],
+        })
+    );
+    assert_eq!(properties.next(), None);
+    assert_eq!(
+        format!("{:?}", GnuPropertyType(0xc0000002)),
+        "GNU_PROPERTY_X86_FEATURE_1_AND"
+    );
+}
@@ -0,0 +1,143 @@
+extern crate elf_parser;
+use elf_parser::{
+    types::{ELFCLASS64, ELFDATA2LSB},
+    Elf, Elf32, Elf32Rel, Elf64, Elf64Rela, ElfFile, Entry, Error, Reloc, Relocation,
+    RelocationTable, SectionHeader, Table,
+};
+
+const R_X86_64_64: u32 = 1;
+const R_X86_64_PC32: u32 = 2;
+const R_X86_64_PLT32: u32 = 4;
+const R_386_32: u32 = 1;
+const R_386_PC32: u32 = 2;
+
+#[test]
+fn rel64_rela_text() {
+    let bytes = include_bytes!("./data/rel64");
+    let elf64 = Elf64::from_bytes(bytes).unwrap();
+    let shdr = elf64.section_by_name(".rela.text").unwrap();
+    let relocs = elf64.relocation_table(&shdr).unwrap();
+    assert!(relocs.is_rela());
+    assert_eq!(relocs.len(), 7);
+
+    let expected = [
+        (0x02, 2, R_X86_64_PC32),
+        (0x0b, 2, R_X86_64_PC32),
+        (0x12, 6, R_X86_64_PLT32),
+        (0x18, 7, R_X86_64_PC32),
+        (0x20, 8, R_X86_64_PC32),
+        (0x26, 9, R_X86_64_PC32),
+        (0x2b, 4, R_X86_64_PLT32),
+    ];
+    for (rel, (r_offset, r_sym, r_type)) in relocs.iter().zip(expected) {
+        assert_eq!(
+            rel,
+            Reloc {
+                r_offset,
+                r_sym,
+                r_type,
+                r_addend: Some(-4),
+            }
+        );
+    }
+
+    let target = elf64.relocation_target(&shdr).unwrap();
+    assert_eq!(elf64.section_name(&target), Ok(".text"));
+    let symtab = elf64.relocation_symtab(&shdr).unwrap();
+    let sym = symtab.get(relocs.get(2).unwrap().r_sym as usize).unwrap();
+    assert_eq!(symtab.name(&sym), Ok("external_func"));
+}
+
+#[test]
+fn rel32_rel_text() {
+    let bytes = include_bytes!("./data/rel32");
+    let elf32 = Elf32::from_bytes(bytes).unwrap();
+    let shdr = elf32.section_by_name(".rel.text").unwrap();
+    let relocs = elf32.relocation_table(&shdr).unwrap();
+    assert!(!relocs.is_rela());
+    assert_eq!(relocs.len(), 7);
+    assert_eq!(
+        relocs.get(2),
+        Ok(Reloc {
+            r_offset: 0x18,
+            r_sym: 6,
+            r_type: R_386_PC32,
+            r_addend: None,
+        })
+    );
+    assert_eq!(relocs.get(7).err(), Some(Error::InvalidIndex));
+
+    let target = elf32.relocation_target(&shdr).unwrap();
+    assert_eq!(elf32.section_name(&target), Ok(".text"));
+    let symtab = elf32.relocation_symtab(&shdr).unwrap();
+    let sym = symtab.get(relocs.get(6).unwrap().r_sym as usize).unwrap();
+    assert_eq!(symtab.name(&sym), Ok("hidden_func"));
+
+    // The raw entries decode r_info with the ELF32 layout.
+    let table = Table::<Elf32Rel>::new(
+        elf32.section_data(&shdr).unwrap(),
+        shdr.sh_entsize(),
+        elf32.class(),
+        elf32.data(),
+    )
+    .unwrap();
+    let rel = table.get(0).unwrap();
+    assert_eq!(rel.r_info, 0x201);
+    assert_eq!(rel.r_sym(), 2);
+    assert_eq!(rel.r_type(), R_386_32);
+    assert_eq!(rel.r_addend(), None);
+}
+
+#[test]
+fn be_relocations() {
+    let le = Elf::from_bytes(include_bytes!("./data/rel64")).unwrap();
+    let be = Elf::from_bytes(include_bytes!("./data/rel64_be")).unwrap();
+    for name in [".rela.text", ".rela.rodata"] {
+        let le_relocs = le.relocation_table(&le.section_by_name(name).unwrap());
+        let be_relocs = be.relocation_table(&be.section_by_name(name).unwrap());
+        assert!(le_relocs.unwrap().iter().eq(be_relocs.unwrap().iter()));
+    }
+
+    let le = Elf::from_bytes(include_bytes!("./data/rel32")).unwrap();
+    let be = Elf::from_bytes(include_bytes!("./data/rel32_be")).unwrap();
+    let shdr = be.section_by_name(".rel.rodata").unwrap();
+    let relocs = be.relocation_table(&shdr).unwrap();
+    assert_eq!(relocs.get(0).unwrap().r_type, R_386_32);
+    let le_relocs = le.relocation_table(&le.section_by_name(".rel.text").unwrap());
+    let be_relocs = be.relocation_table(&be.section_by_name(".rel.text").unwrap());
+    assert!(le_relocs.unwrap().iter().eq(be_relocs.unwrap().iter()));
+}
+
+#[test]
+fn dynamic_relocations() {
+    let bytes = include_bytes!("./data/elf64");
+    let elf64 = Elf64::from_bytes(bytes).unwrap();
+    let shdr = elf64.section_by_name(".rela.dyn").unwrap();
+    assert_eq!(elf64.relocation_table(&shdr).unwrap().len(), 2);
+    // .rela.dyn applies to the whole image rather than to one section.
+    assert_eq!(elf64.relocation_target(&shdr).err(), Some(Error::NotFound));
+    let symtab = elf64.relocation_symtab(&shdr).unwrap();
+    assert_eq!(symtab.len(), elf64.dynsym().unwrap().len());
+
+    let text = elf64.section_by_name(".text").unwrap();
+    assert_eq!(elf64.relocation_table(&text).err(), Some(Error::NotFound));
+}
+
+#[test]
+fn rela_entry() {
+    let mut bytes = [0u8; 24];
+    bytes[0..8].copy_from_slice(&0x1000u64.to_le_bytes());
+    bytes[8..16].copy_from_slice(&((5u64 << 32) | R_X86_64_64 as u64).to_le_bytes());
+    bytes[16..24].copy_from_slice(&(-8i64).to_le_bytes());
+    let rela = Elf64Rela::parse(&bytes, ELFCLASS64, ELFDATA2LSB).unwrap();
+    assert_eq!(rela.r_sym(), 5);
+    assert_eq!(rela.r_type(), R_X86_64_64);
+    assert_eq!(rela.r_addend(), Some(-8));
+
+    let relocs = RelocationTable::new(&bytes, 24, true, ELFCLASS64, ELFDATA2LSB).unwrap();
+    assert_eq!(relocs.get(0), Ok(Reloc::from(rela)));
+    assert_eq!(
+        RelocationTable::new(&bytes, 8, true, ELFCLASS64, ELFDATA2LSB).err(),
+        Some(Error::Corrupted)
+    );
+}
@@ -0,0 +1,182 @@
+extern crate elf_parser;
+use elf_parser::{
+    elf64::Elf64Phdr,
+    types::{
+        Machine, ELFCLASS32, ELFCLASS64, ELFDATA2LSB, EM_AARCH64, EM_ARM, PT_DYNAMIC, PT_LOAD,
+    },
+    Elf32, Elf64, ElfFile, Error, FileHeader, Loader, RelativeRelocator,
+};
+
+/// Load `file`, which is linked at address 0, and relocate it for `base`.
+fn relocate<'a, F: ElfFile<'a>>(file: &F, base: u64) -> Result<Vec<u8>, Error> {
+    let loader = Loader::new(file, 0x1000).unwrap();
+    let (start, end) = loader.extent().unwrap();
+    assert_eq!(start, 0);
+    let mut image = vec![0; end as usize];
+    let mut rest = Some(&mut image[..]);
+    let mut cursor = 0;
+    loader
+        .load(0, |region| {
+            let memory = rest.take().unwrap();
+            let (_, memory) = memory.split_at_mut((region.vaddr - cursor) as usize);
+            let (memory, tail) = memory.split_at_mut(region.size as usize);
+            rest = Some(tail);
+            cursor = region.vaddr + region.size;
+            Ok(memory)
+        })
+        .unwrap();
+
+    let relocator =
+        RelativeRelocator::new(Machine(file.ehdr().e_machine()), file.class(), file.data())?;
+    relocator.relocate(&mut image, file.phdr_iter(), 0, base)?;
+    Ok(image)
+}
+
+/// Check the pointers in the data of the fixture: `table` points to the first 70 ints of
+/// `values`, and `sparse` to two more with null pointers in between.
+fn check_pointers(image: &[u8], word: usize, base: u64, values: u64, table: usize) {
+    let read = |offset: usize| {
+        let mut bytes = [0; 8];
+        bytes[..word].copy_from_slice(&image[offset..offset + word]);
+        u64::from_le_bytes(bytes)
+    };
+    for i in 0..70 {
+        assert_eq!(read(table + i * word), base + values + i as u64 * 4);
+    }
+    let sparse = 0x4040;
+    assert_eq!(read(sparse), base + values + 70 * 4);
+    for i in 1..7 {
+        assert_eq!(read(sparse + i * word), 0);
+    }
+    assert_eq!(read(sparse + 7 * word), base + values + 79 * 4);
+    // greeting points into .rodata, and plain holds no address.
+    assert_eq!(read(0x4020), base + 0x2000);
+    assert_eq!(&image[0x2000..0x2006], b"hello\0");
+    assert_eq!(read(0x4000), 0x1234);
+}
+
+#[test]
+fn rela() {
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/pie64")).unwrap();
+    let base = 0x5555_5555_4000;
+    let image = relocate(&elf64, base).unwrap();
+    check_pointers(&image, 8, base, 0x42c0, 0x4080);
+
+    // At base 0 nothing changes.
+    let image = relocate(&elf64, 0).unwrap();
+    check_pointers(&image, 8, 0, 0x42c0, 0x4080);
+}
+
+#[test]
+fn relr() {
+    let rela = Elf64::from_bytes(include_bytes!("./data/pie64")).unwrap();
+    let relr = Elf64::from_bytes(include_bytes!("./data/pie64_relr")).unwrap();
+    let base = 0x7f12_3456_0000;
+    let image = relocate(&relr, base).unwrap();
+    check_pointers(&image, 8, base, 0x42c0, 0x4080);
+
+    // The packed relocations give the same data as the RELA ones.
+    assert_eq!(
+        image[0x4000..0x42b0],
+        relocate(&rela, base).unwrap()[0x4000..0x42b0]
+    );
+}
+
+#[test]
+fn rel() {
+    let elf32 = Elf32::from_bytes(include_bytes!("./data/pie32")).unwrap();
+    let base = 0x5655_5000;
+    let image = relocate(&elf32, base).unwrap();
+    check_pointers(&image, 4, base, 0x4180, 0x4060);
+}
+
+#[test]
+fn symbolic_relocations() {
+    // A PIE linked against libc needs symbols resolved, which self-relocation cannot do.
+    let elf32 = Elf32::from_bytes(include_bytes!("./data/elf32")).unwrap();
+    assert_eq!(
+        relocate(&elf32, 0x1000).err(),
+        Some(Error::UnsupportedRelocation)
+    );
+    assert_eq!(
+        RelativeRelocator::new(EM_ARM, ELFCLASS32, ELFDATA2LSB).err(),
+        Some(Error::UnsupportedRelocation)
+    );
+}
+
+#[test]
+fn aarch64_linked_high() {
+    // A kernel linked at LINK, with the dynamic array at 0x100.
+    const LINK: u64 = 0xffff_ffff_8000_0000;
+    let phdr = |p_type: u32| Elf64Phdr {
+        p_type,
+        p_flags: 0,
+        p_offset: 0,
+        p_vaddr: LINK + 0x100,
+        p_paddr: 0,
+        p_filesz: 0x30,
+        p_memsz: 0x30,
+        p_align: 8,
+    };
+    let phdrs = [phdr(PT_LOAD.0), phdr(PT_DYNAMIC.0)];
+
+    let mut image = vec![0; 0x400];
+    let mut put = |offset: usize, value: u64| {
+        image[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+    };
+    // The dynamic array: DT_RELA, DT_RELASZ, DT_RELAENT, DT_RELR, DT_RELRSZ, DT_NULL.
+    put(0x100, 7);
+    put(0x108, LINK + 0x200);
+    put(0x110, 8);
+    put(0x118, 48);
+    put(0x120, 9);
+    put(0x128, 24);
+    put(0x130, 36);
+    put(0x138, LINK + 0x230);
+    put(0x140, 35);
+    put(0x148, 16);
+    // R_AARCH64_RELATIVE at 0x300 and R_AARCH64_NONE at 0x308, with the linked values in
+    // place.
+    put(0x200, LINK + 0x300);
+    put(0x208, 1027);
+    put(0x210, LINK + 0x40);
+    put(0x218, LINK + 0x308);
+    put(0x300, LINK + 0x40);
+    put(0x308, 0xdead);
+    // RELR: the words at 0x310 and 0x320.
+    put(0x230, LINK + 0x310);
+    put(0x238, 0b101);
+    put(0x310, LINK + 0x80);
+    put(0x318, 0xbeef);
+    put(0x320, LINK + 0x90);
+
+    // Loaded where it is linked, nothing changes.
+    let relocator = RelativeRelocator::new(EM_AARCH64, ELFCLASS64, ELFDATA2LSB).unwrap();
+    let linked = image.clone();
+    relocator.relocate(&mut image, phdrs, LINK, LINK).unwrap();
+    assert_eq!(image, linked);
+
+    let base = 0xffff_ffff_c120_0000;
+    relocator.relocate(&mut image, phdrs, LINK, base).unwrap();
+    assert_eq!(image[0x300..0x308], (base + 0x40).to_le_bytes());
+    assert_eq!(image[0x308..0x310], 0xdeadu64.to_le_bytes());
+    assert_eq!(image[0x310..0x318], (base + 0x80).to_le_bytes());
+    assert_eq!(image[0x318..0x320], 0xbeefu64.to_le_bytes());
+    assert_eq!(image[0x320..0x328], (base + 0x90).to_le_bytes());
+
+    // R_AARCH64_ABS64 needs a symbol.
+    image[0x208..0x210].copy_from_slice(&257u64.to_le_bytes());
+    assert_eq!(
+        relocator.relocate(&mut image, phdrs, LINK, base),
+        Err(Error::UnsupportedRelocation)
+    );
+    // The dynamic array is below the image, and there is none.
+    assert_eq!(
+        relocator.relocate(&mut image, phdrs, LINK + 0x200, base),
+        Err(Error::Corrupted)
+    );
+    assert_eq!(
+        relocator.relocate(&mut image, [phdrs[0]], LINK, base),
+        Err(Error::NotFound)
+    );
+}
@@ -0,0 +1,206 @@
+extern crate elf_parser;
+use elf_parser::{Elf, Elf64, ElfFile, Error, RelocationError, Relocator, SectionHeader};
+
+/// Relocate the section that `rela` applies to, loading every section at `section_addr` and
+/// resolving undefined symbols through `resolve`.
+fn relocate<'a, F: ElfFile<'a>>(
+    file: &F,
+    rela: &str,
+    section_addr: fn(u32) -> Option<u64>,
+    resolve: fn(&str) -> Option<u64>,
+) -> (Vec<u8>, Result<(), RelocationError>) {
+    let shdr = file.section_by_name(rela).unwrap();
+    let target = file.relocation_target(&shdr).unwrap();
+    let mut buf = file.section_data(&target).unwrap().to_vec();
+    let relocator = Relocator::new(file, &shdr).unwrap();
+    let result = relocator.apply(
+        &mut buf,
+        section_addr(shdr.sh_info()).unwrap(),
+        section_addr,
+        |_, name| resolve(name),
+    );
+    (buf, result)
+}
+
+#[test]
+fn x86_64() {
+    let bytes = include_bytes!("./data/rel64");
+    let elf64 = Elf64::from_bytes(bytes).unwrap();
+    let section_addr = |index| match index {
+        1 => Some(0x10000),
+        3 => Some(0x20000),
+        4 => Some(0x30000),
+        5 => Some(0x40000),
+        _ => None,
+    };
+    let resolve = |name: &str| match name {
+        "external_func" => Some(0x50000),
+        "external_value" => Some(0x60000),
+        "common_var" => Some(0x30004),
+        _ => None,
+    };
+
+    // The same object linked by ld with these addresses.
+    let (text, result) = relocate(&elf64, ".rela.text", section_addr, resolve);
+    assert_eq!(result, Ok(()));
+    assert_eq!(
+        text,
+        [
+            0x8b, 0x05, 0xfa, 0xff, 0x01, 0x00, 0x8d, 0x50, 0x01, 0x89, 0x15, 0xf1, 0xff, 0x01,
+            0x00, 0xc3, 0x53, 0xe8, 0xea, 0xff, 0x03, 0x00, 0x03, 0x05, 0xe4, 0xff, 0x04, 0x00,
+            0x89, 0xc3, 0x03, 0x1d, 0xdc, 0xff, 0x00, 0x00, 0x03, 0x1d, 0xda, 0xff, 0x01, 0x00,
+            0xe8, 0xd1, 0xff, 0xff, 0xff, 0x01, 0xd8, 0x5b, 0xc3
+        ]
+    );
+    let (rodata, result) = relocate(&elf64, ".rela.rodata", section_addr, resolve);
+    assert_eq!(result, Ok(()));
+    assert_eq!(rodata, [0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00]);
+
+    let (_, result) = relocate(&elf64, ".rela.text", section_addr, |_| None);
+    let err = result.unwrap_err();
+    assert_eq!(err.error, Error::UndefinedSymbol);
+    assert_eq!(err.reloc.r_offset, 0x12);
+
+    // PC32 cannot reach a symbol 4GiB away.
+    let (_, result) = relocate(&elf64, ".rela.text", section_addr, |_| Some(0x1_0000_0000));
+    assert_eq!(result.unwrap_err().error, Error::RelocationOverflow);
+}
+
+fn section_addr(index: u32) -> Option<u64> {
+    match index {
+        2 => Some(0x10000),
+        4 => Some(0x11000),
+        5 => Some(0x12000),
+        7 => Some(0x13000),
+        9 => Some(0x20000),
+        _ => None,
+    }
+}
+
+fn resolve(name: &str) -> Option<u64> {
+    match name {
+        "ext_func" => Some(0x14000),
+        "ext_data" => Some(0x30010),
+        "near_func" => Some(0x10040),
+        "far_func" => Some(0x1_0000_0000),
+        _ => None,
+    }
+}
+
+#[test]
+fn aarch64() {
+    let elf = Elf::from_bytes(include_bytes!("./data/rel_aarch64")).unwrap();
+
+    let (text, result) = relocate(&elf, ".rela.text", section_addr, resolve);
+    assert_eq!(result, Ok(()));
+    let insns: Vec<u32> = text
+        .chunks(4)
+        .map(|insn| u32::from_le_bytes(insn.try_into().unwrap()))
+        .collect();
+    assert_eq!(
+        insns,
+        [
+            0x94001000, // bl 0x14000
+            0x14000fff, // b 0x14000
+            0x90000100, // adrp x0, 0x30000
+            0x91004000, // add x0, x0, #0x10
+            0xf9400801, // ldr x1, [x0, #0x10]
+            0xb9401002, // ldr w2, [x0, #0x10]
+            0x79402002, // ldrh w2, [x0, #0x10]
+            0x39404003, // ldrb w3, [x0, #0x10]
+            0x3dc00404, // ldr q4, [x0, #0x10]
+            0x1007fee4, // adr x4, 0x20000
+            0xb401fec0, // cbz x0, 0x14000
+            0x3619fea0, // tbz w0, #3, 0x14000
+            0x5807fe85, // ldr x5, 0x20000
+            0xd2e00006, // movz x6, #0x0, lsl #48
+            0xf2c00006, // movk x6, #0x0, lsl #32
+            0xf2a00066, // movk x6, #0x3, lsl #16
+            0xf2800206, // movk x6, #0x10
+            0x940003ef, // bl 0x11000
+            0xd65f03c0, // ret
+        ]
+    );
+
+    let (data, result) = relocate(&elf, ".rela.data", section_addr, resolve);
+    assert_eq!(result, Ok(()));
+    assert_eq!(u64::from_le_bytes(data[0..8].try_into().unwrap()), 0x30010);
+    assert_eq!(u32::from_le_bytes(data[8..12].try_into().unwrap()), 0x30010);
+    assert_eq!(
+        i32::from_le_bytes(data[12..16].try_into().unwrap()),
+        0x14000 - 0x2000c
+    );
+    assert_eq!(
+        i64::from_le_bytes(data[16..24].try_into().unwrap()),
+        0x14000 - 0x20010
+    );
+
+    let (_, result) = relocate(&elf, ".rela.text.far", section_addr, resolve);
+    assert_eq!(result.unwrap_err().error, Error::RelocationOverflow);
+    let (_, result) = relocate(&elf, ".rela.text.got", section_addr, resolve);
+    assert_eq!(result.unwrap_err().error, Error::UnsupportedRelocation);
+
+    // LDST64 needs an 8-byte aligned address.
+    let (_, result) = relocate(&elf, ".rela.text", section_addr, |name| {
+        resolve(name).map(|addr| addr + 4)
+    });
+    let err = result.unwrap_err();
+    assert_eq!(err.error, Error::MisalignedRelocation);
+    assert_eq!(err.reloc.r_offset, 0x10);
+}
+
+#[test]
+fn riscv64() {
+    let elf = Elf::from_bytes(include_bytes!("./data/rel_riscv64")).unwrap();
+
+    let (text, result) = relocate(&elf, ".rela.text", section_addr, resolve);
+    assert_eq!(result, Ok(()));
+    let insns: Vec<u32> = text[..0x34]
+        .chunks(4)
+        .map(|insn| u32::from_le_bytes(insn.try_into().unwrap()))
+        .collect();
+    assert_eq!(
+        insns,
+        [
+            0x00004097, // auipc ra, 0x4
+            0x000080e7, // jalr ra
+            0x00020517, // auipc a0, 0x20
+            0x00850513, // addi a0, a0, 0x8
+            0x00020597, // auipc a1, 0x20
+            0x00c5b023, // sd a2, 0x0(a1)
+            0x000306b7, // lui a3, 0x30
+            0x01068693, // addi a3, a3, 0x10
+            0x00e6a823, // sw a4, 0x10(a3)
+            0x00b50e63, // beq a0, a1, 0x10040
+            0x018000ef, // jal 0x10040
+            0x00001097, // auipc ra, 0x1
+            0xfd4080e7, // jalr -0x2c(ra)
+        ]
+    );
+
+    let (data, result) = relocate(&elf, ".rela.data", section_addr, resolve);
+    assert_eq!(result, Ok(()));
+    assert_eq!(
+        data,
+        [
+            0x10, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x03, 0x00, 0xf4, 0x3f,
+            0xff, 0xff, 0x36, 0x00, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x36, 0x00, 0x36, 0x36
+        ]
+    );
+
+    let (_, result) = relocate(&elf, ".rela.text.far", section_addr, resolve);
+    assert_eq!(result.unwrap_err().error, Error::RelocationOverflow);
+    let (_, result) = relocate(&elf, ".rela.text.got", section_addr, resolve);
+    assert_eq!(result.unwrap_err().error, Error::UnsupportedRelocation);
+}
+
+#[test]
+fn unsupported_machine() {
+    let elf = Elf::from_bytes(include_bytes!("./data/rel32")).unwrap();
+    let shdr = elf.section_by_name(".rel.text").unwrap();
+    assert_eq!(
+        Relocator::new(&elf, &shdr).err(),
+        Some(Error::UnsupportedRelocation)
+    );
+}
@@ -0,0 +1,69 @@
+extern crate elf_parser;
+use elf_parser::{
+    relocation_type_name,
+    reltype::{
+        Aarch64RelType, ArmRelType, X86_64RelType, R_AARCH64_JUMP_SLOT, R_ARM_SWI24,
+        R_ARM_TLS_DESC, R_X86_64_JUMP_SLOT,
+    },
+    types::{Machine, EM_386, EM_AARCH64, EM_MIPS, EM_PPC64, EM_RISCV, EM_S390, EM_X86_64},
+    Elf, ElfFile, FileHeader,
+};
+
+#[test]
+fn names() {
+    assert_eq!(
+        relocation_type_name(EM_X86_64, 7),
+        Some("R_X86_64_JUMP_SLOT")
+    );
+    assert_eq!(relocation_type_name(EM_386, 7), Some("R_386_JMP_SLOT"));
+    assert_eq!(
+        relocation_type_name(EM_AARCH64, 1026),
+        Some("R_AARCH64_JUMP_SLOT")
+    );
+    assert_eq!(relocation_type_name(EM_RISCV, 5), Some("R_RISCV_JUMP_SLOT"));
+    assert_eq!(relocation_type_name(EM_PPC64, 21), Some("R_PPC64_JMP_SLOT"));
+    assert_eq!(relocation_type_name(EM_MIPS, 127), Some("R_MIPS_JUMP_SLOT"));
+    assert_eq!(relocation_type_name(EM_S390, 11), Some("R_390_JMP_SLOT"));
+    assert_eq!(relocation_type_name(EM_X86_64, 0x1000), None);
+    assert_eq!(relocation_type_name(Machine(0x1234), 7), None);
+}
+
+#[test]
+fn debug() {
+    assert_eq!(format!("{:?}", R_X86_64_JUMP_SLOT), "R_X86_64_JUMP_SLOT");
+    assert_eq!(format!("{:?}", R_AARCH64_JUMP_SLOT), "R_AARCH64_JUMP_SLOT");
+    assert_eq!(format!("{:?}", X86_64RelType(0x1000)), "UNKNOWN");
+    assert_eq!(X86_64RelType(7), R_X86_64_JUMP_SLOT);
+    assert_eq!(Aarch64RelType(1026), R_AARCH64_JUMP_SLOT);
+    assert_eq!(
+        format!("{:?}", Aarch64RelType(256)),
+        "R_AARCH64_NONE_WITHDRAWN"
+    );
+    // An obsolete name shares its value with the current one.
+    assert_eq!(R_ARM_SWI24, R_ARM_TLS_DESC);
+    assert_eq!(format!("{:?}", ArmRelType(13)), "R_ARM_TLS_DESC");
+}
+
+#[test]
+fn fixture_names() {
+    let elf = Elf::from_bytes(include_bytes!("./data/rel64")).unwrap();
+    let machine = Machine(elf.ehdr().e_machine());
+    let shdr = elf.section_by_name(".rela.text").unwrap();
+    let relocs = elf.relocation_table(&shdr).unwrap();
+    let names: Vec<_> = relocs
+        .iter()
+        .map(|rel| relocation_type_name(machine, rel.r_type).unwrap())
+        .collect();
+    assert_eq!(names[0], "R_X86_64_PC32");
+    assert_eq!(names[2], "R_X86_64_PLT32");
+
+    let elf = Elf::from_bytes(include_bytes!("./data/rel32")).unwrap();
+    let machine = Machine(elf.ehdr().e_machine());
+    let shdr = elf.section_by_name(".rel.text").unwrap();
+    let relocs = elf.relocation_table(&shdr).unwrap();
+    let rel = relocs.get(2).unwrap();
+    assert_eq!(
+        relocation_type_name(machine, rel.r_type),
+        Some("R_386_PC32")
+    );
+}
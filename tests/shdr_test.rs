@@ -7,9 +7,9 @@ fn elf64_shdr() {
     let elf64 = Elf64::from_bytes(bytes).unwrap();
     let ehdr = elf64.ehdr();
     let shdr_iter = elf64.shdr_iter();
-    assert_eq!(elf64.shdr_num(), ehdr.e_shnum);
+    assert_eq!(elf64.shdr_num(), ehdr.e_shnum as u32);
     for (i, shdr) in shdr_iter.enumerate() {
-        assert_eq!(elf64.shdr_nth(i as u16).unwrap(), shdr);
+        assert_eq!(elf64.shdr_nth(i as u32).unwrap(), shdr);
     }
     let shdr1 = elf64.shdr_nth(1).unwrap();
     assert_eq!(shdr1.sh_name, 0x1b);
@@ -42,9 +42,9 @@ fn elf32_shdr() {
     let elf32 = Elf32::from_bytes(bytes).unwrap();
     let ehdr = elf32.ehdr();
     let shdr_iter = elf32.shdr_iter();
-    assert_eq!(elf32.shdr_num(), ehdr.e_shnum);
+    assert_eq!(elf32.shdr_num(), ehdr.e_shnum as u32);
     for (i, shdr) in shdr_iter.enumerate() {
-        assert_eq!(elf32.shdr_nth(i as u16).unwrap(), shdr.unwrap());
+        assert_eq!(elf32.shdr_nth(i as u32).unwrap(), shdr);
     }
 
     let shdr10 = elf32.shdr_nth(10).unwrap();
@@ -71,3 +71,23 @@ fn elf32_shdr() {
     assert_eq!(shdr11.sh_addralign, 0x4);
     assert_eq!(shdr11.sh_entsize, 0x0);
 }
+
+#[test]
+fn elf64_be_shdr() {
+    let le = Elf64::from_bytes(include_bytes!("./data/elf64")).unwrap();
+    let be = Elf64::from_bytes(include_bytes!("./data/elf64_be")).unwrap();
+    assert_eq!(le.shdr_num(), be.shdr_num());
+    for (le_shdr, be_shdr) in le.shdr_iter().zip(be.shdr_iter()) {
+        assert_eq!(le_shdr, be_shdr);
+    }
+}
+
+#[test]
+fn elf32_be_shdr() {
+    let le = Elf32::from_bytes(include_bytes!("./data/elf32")).unwrap();
+    let be = Elf32::from_bytes(include_bytes!("./data/elf32_be")).unwrap();
+    assert_eq!(le.shdr_num(), be.shdr_num());
+    for (le_shdr, be_shdr) in le.shdr_iter().zip(be.shdr_iter()) {
+        assert_eq!(le_shdr, be_shdr);
+    }
+}
@@ -0,0 +1,241 @@
+extern crate elf_parser;
+use elf_parser::{
+    types::{
+        AuxType, Class, Data, AT_BASE, AT_ENTRY, AT_PAGESZ, AT_PHDR, AT_PHENT, AT_PHNUM, AT_RANDOM,
+        AT_UID, ELFCLASS32, ELFCLASS64, ELFDATA2LSB, ELFDATA2MSB, EM_AARCH64, EM_RISCV, EM_X86_64,
+    },
+    AuxEntry, AuxvBuilder, AuxvIter, Elf32, Elf64, Error, InitialStack, AUXV_CAPACITY,
+};
+
+const TOP: u64 = 0x7fff_f000;
+const RANDOM: [u8; 16] = *b"0123456789abcdef";
+
+/// The parts of an initial stack.
+#[derive(Debug, PartialEq)]
+struct Parsed {
+    argv: Vec<Vec<u8>>,
+    envp: Vec<Vec<u8>>,
+    auxv: Vec<AuxEntry>,
+}
+
+/// Parse the initial stack in `stack`, which ends at TOP, from the stack pointer `sp`.
+fn parse(stack: &[u8], sp: u64, class: Class, data: Data) -> Parsed {
+    let word = if class == ELFCLASS64 { 8 } else { 4 };
+    let offset = |addr: u64| (addr - (TOP - stack.len() as u64)) as usize;
+    let read = |addr: u64| {
+        let bytes = &stack[offset(addr)..offset(addr) + word];
+        let mut value = 0;
+        for i in 0..word {
+            let byte = if data == ELFDATA2MSB {
+                bytes[i]
+            } else {
+                bytes[word - 1 - i]
+            };
+            value = (value << 8) | byte as u64;
+        }
+        value
+    };
+    let string = |addr: u64| {
+        let bytes = &stack[offset(addr)..];
+        bytes[..bytes.iter().position(|&b| b == 0).unwrap()].to_vec()
+    };
+
+    let argc = read(sp);
+    let mut cursor = sp + word as u64;
+    let mut vector = || {
+        let mut strings = Vec::new();
+        loop {
+            let pointer = read(cursor);
+            cursor += word as u64;
+            if pointer == 0 {
+                return strings;
+            }
+            strings.push(string(pointer));
+        }
+    };
+    let argv = vector();
+    let envp = vector();
+    assert_eq!(argv.len() as u64, argc);
+    let auxv = AuxvIter::new(&stack[offset(cursor)..], class, data).collect();
+    Parsed { argv, envp, auxv }
+}
+
+#[test]
+fn auxv() {
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/core_x86_64_exe")).unwrap();
+    let auxv = AuxvBuilder::from_file(&elf64, 0, 0x1000).unwrap();
+    let entries: Vec<_> = auxv
+        .entries()
+        .iter()
+        .map(|entry| (entry.a_type, entry.a_val))
+        .collect();
+    assert_eq!(
+        entries,
+        [
+            (AT_PHDR, 0x400040),
+            (AT_PHENT, 56),
+            (AT_PHNUM, elf64.phdr_num() as u64),
+            (AT_PAGESZ, 0x1000),
+            (AT_BASE, 0),
+            (AT_ENTRY, 0x401040),
+        ]
+    );
+
+    let elf32 = Elf32::from_bytes(include_bytes!("./data/elf32")).unwrap();
+    let mut auxv = AuxvBuilder::from_file(&elf32, 0x5655_5000, 0x1000).unwrap();
+    assert_eq!(auxv.get(AT_PHDR), Some(0x5655_5034));
+    assert_eq!(auxv.get(AT_PHENT), Some(32));
+    assert_eq!(auxv.get(AT_ENTRY), Some(0x5655_53b0));
+
+    // Setting an entry again replaces it.
+    auxv.set(AT_BASE, 0xf7f0_0000)
+        .unwrap()
+        .set(AT_UID, 1000)
+        .unwrap();
+    assert_eq!(auxv.get(AT_BASE), Some(0xf7f0_0000));
+    assert_eq!(auxv.entries().len(), 7);
+    assert_eq!(auxv.get(AT_RANDOM), None);
+
+    assert_eq!(
+        AuxvBuilder::from_file(&elf32, 0x5655_5800, 0x1000).err(),
+        Some(Error::InvalidAlignment)
+    );
+    assert_eq!(
+        AuxvBuilder::from_file(&elf64, 0x1000_0000, 0x1000).err(),
+        Some(Error::InvalidFileType)
+    );
+    let rel64 = Elf64::from_bytes(include_bytes!("./data/rel64")).unwrap();
+    assert_eq!(
+        AuxvBuilder::from_file(&rel64, 0, 0x1000).err(),
+        Some(Error::InvalidFileType)
+    );
+
+    // dyn64 has no PT_PHDR, and the first PT_LOAD, which holds the program headers at 0x40,
+    // now claims to be loaded at the very end of the address space.
+    let mut bytes = include_bytes!("./data/dyn64").to_vec();
+    bytes[0x40 + 16..0x40 + 24].copy_from_slice(&(u64::MAX - 0x10).to_le_bytes());
+    let dyn64 = Elf64::from_bytes(&bytes).unwrap();
+    assert_eq!(
+        AuxvBuilder::from_file(&dyn64, 0, 0x1000).err(),
+        Some(Error::Corrupted)
+    );
+}
+
+#[test]
+fn capacity() {
+    let mut auxv = AuxvBuilder::new();
+    for a_type in 0..AUXV_CAPACITY as u64 {
+        auxv.set(AuxType(100 + a_type), 0).unwrap();
+    }
+    assert_eq!(auxv.set(AT_UID, 0).err().unwrap(), Error::BufferTooSmall);
+    assert!(auxv.set(AuxType(100), 1).is_ok());
+
+    // AT_RANDOM does not fit either.
+    let initial = InitialStack::new(EM_X86_64, ELFCLASS64, ELFDATA2LSB).unwrap();
+    let mut stack = vec![0; 0x1000];
+    assert_eq!(
+        initial.build(&mut stack, TOP, &[], &[], &auxv, &RANDOM),
+        Err(Error::BufferTooSmall)
+    );
+}
+
+#[test]
+fn x86_64() {
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/core_x86_64_exe")).unwrap();
+    let auxv = AuxvBuilder::from_file(&elf64, 0, 0x1000).unwrap();
+    let initial = InitialStack::for_file(&elf64).unwrap();
+    let mut stack = vec![0; 0x1000];
+    let argv: [&[u8]; 3] = [b"/bin/prog", b"-v", b""];
+    let envp: [&[u8]; 2] = [b"HOME=/root", b"PATH=/bin"];
+    let sp = initial
+        .build(&mut stack, TOP, &argv, &envp, &auxv, &RANDOM)
+        .unwrap();
+    assert_eq!(sp % 16, 0);
+    assert!(sp < TOP - 0x60);
+
+    let parsed = parse(&stack, sp, ELFCLASS64, ELFDATA2LSB);
+    assert_eq!(parsed.argv, argv);
+    assert_eq!(parsed.envp, envp);
+    assert_eq!(parsed.auxv[..6], auxv.entries()[..]);
+    assert_eq!(parsed.auxv.len(), 7);
+    assert_eq!(parsed.auxv[6].a_type, AT_RANDOM);
+    let random = parsed.auxv[6].a_val;
+    assert!(random > sp && random.is_multiple_of(16));
+    let offset = (random - (TOP - 0x1000)) as usize;
+    assert_eq!(stack[offset..offset + 16], RANDOM);
+
+    // The strings end at the top of the stack.
+    assert_eq!(&stack[0x1000 - 10..], b"PATH=/bin\0");
+}
+
+#[test]
+fn aarch64_big_endian() {
+    let mut auxv = AuxvBuilder::new();
+    auxv.set(AT_PAGESZ, 0x10000).unwrap();
+    let initial = InitialStack::new(EM_AARCH64, ELFCLASS64, ELFDATA2MSB).unwrap();
+    let mut stack = vec![0; 0x200];
+    let sp = initial
+        .build(&mut stack, TOP, &[b"init"], &[], &auxv, &RANDOM)
+        .unwrap();
+    assert_eq!(sp % 16, 0);
+    let parsed = parse(&stack, sp, ELFCLASS64, ELFDATA2MSB);
+    assert_eq!(parsed.argv, [b"init"]);
+    assert!(parsed.envp.is_empty());
+    assert_eq!(parsed.auxv[0].a_val, 0x10000);
+    assert_eq!(parsed.auxv[1].a_type, AT_RANDOM);
+}
+
+#[test]
+fn riscv32() {
+    let initial = InitialStack::new(EM_RISCV, ELFCLASS32, ELFDATA2LSB).unwrap();
+    let mut stack = vec![0; 0x100];
+    let argv: [&[u8]; 2] = [b"sh", b"-c"];
+    let sp = initial
+        .build(
+            &mut stack,
+            TOP,
+            &argv,
+            &[b"A=1"],
+            &AuxvBuilder::new(),
+            &RANDOM,
+        )
+        .unwrap();
+    assert_eq!(sp % 16, 0);
+    // 10 bytes of strings and 16 random bytes, aligned down to TOP - 32. Below them argc,
+    // 3 argv words, 2 envp words, AT_RANDOM and AT_NULL take 40 bytes, aligned down to 48.
+    assert_eq!(sp, TOP - 32 - 48);
+    let parsed = parse(&stack, sp, ELFCLASS32, ELFDATA2LSB);
+    assert_eq!(parsed.argv, argv);
+    assert_eq!(parsed.envp, [b"A=1"]);
+    assert_eq!(parsed.auxv.len(), 1);
+}
+
+#[test]
+fn errors() {
+    // i386 is not supported.
+    let elf32 = Elf32::from_bytes(include_bytes!("./data/elf32")).unwrap();
+    assert_eq!(
+        InitialStack::for_file(&elf32).err(),
+        Some(Error::UnsupportedMachine)
+    );
+    assert_eq!(
+        InitialStack::new(EM_X86_64, ELFCLASS32, ELFDATA2LSB).err(),
+        Some(Error::UnsupportedMachine)
+    );
+
+    let initial = InitialStack::new(EM_RISCV, ELFCLASS64, ELFDATA2LSB).unwrap();
+    let auxv = AuxvBuilder::new();
+    let mut stack = vec![0; 0x40];
+    assert_eq!(
+        initial.build(&mut stack, TOP, &[b"prog"], &[], &auxv, &RANDOM),
+        Err(Error::BufferTooSmall)
+    );
+    assert_eq!(
+        initial.build(&mut stack, TOP - 8, &[], &[], &auxv, &RANDOM),
+        Err(Error::InvalidAlignment)
+    );
+    assert_eq!(
+        initial.build(&mut stack, 0x20, &[], &[], &auxv, &RANDOM),
+        Err(Error::BufferTooSmall)
+    );
+}
@@ -0,0 +1,70 @@
+extern crate elf_parser;
+use elf_parser::{Elf, Elf32, Elf64, Error, StrTab};
+
+#[test]
+fn strtab() {
+    let strtab = StrTab::new(b"\0.text\0.data\0\xff\0unterminated");
+    assert_eq!(strtab.get(0), Ok(&b""[..]));
+    assert_eq!(strtab.get(1), Ok(&b".text"[..]));
+    assert_eq!(strtab.get_str(3), Ok("ext"));
+    assert_eq!(strtab.get_str(7), Ok(".data"));
+    assert_eq!(strtab.get_str(13), Err(Error::InvalidUtf8));
+    assert_eq!(strtab.get(15), Err(Error::Corrupted));
+    assert_eq!(strtab.get(100), Err(Error::Corrupted));
+}
+
+#[test]
+fn elf64_section_name() {
+    let bytes = include_bytes!("./data/elf64");
+    let elf64 = Elf64::from_bytes(bytes).unwrap();
+    let shdr1 = elf64.shdr_nth(1).unwrap();
+    assert_eq!(elf64.section_name(&shdr1), Ok(".interp"));
+    let shdr17 = elf64.shdr_nth(17).unwrap();
+    assert_eq!(elf64.section_name(&shdr17), Ok(".init_array"));
+    assert_eq!(elf64.section_name(&elf64.shdr_nth(0).unwrap()), Ok(""));
+
+    let text = elf64.section_by_name(".text").unwrap();
+    assert_eq!(text.sh_addr, 0x401020);
+    assert_eq!(text.sh_size, 0xf8);
+    assert_eq!(elf64.section_by_name(".shstrtab"), elf64.shdr_nth(28).ok());
+    assert_eq!(elf64.section_by_name(".tex"), None);
+}
+
+#[test]
+fn elf32_section_name() {
+    let bytes = include_bytes!("./data/elf32");
+    let elf32 = Elf32::from_bytes(bytes).unwrap();
+    let shdr10 = elf32.shdr_nth(10).unwrap();
+    assert_eq!(elf32.section_name(&shdr10), Ok(".rel.plt"));
+    assert_eq!(elf32.section_by_name(".rel.plt"), Some(shdr10));
+
+    let be = Elf32::from_bytes(include_bytes!("./data/elf32_be")).unwrap();
+    assert_eq!(be.section_by_name(".rel.plt"), Some(shdr10));
+}
+
+#[test]
+fn elf_section_name() {
+    // Elf gets section_name from ElfFile.
+    use elf_parser::ElfFile;
+
+    let bytes = include_bytes!("./data/elf64");
+    let elf = Elf::from_bytes(bytes).unwrap();
+    let names = elf
+        .shdr_iter()
+        .map(|shdr| elf.section_name(&shdr).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(names.len(), 29);
+    assert_eq!(names[12], ".text");
+    assert_eq!(names[26], ".symtab");
+    assert_eq!(names[28], ".shstrtab");
+}
+
+#[test]
+fn no_shstrtab() {
+    let mut bytes = *include_bytes!("./data/elf64");
+    bytes[62..64].copy_from_slice(&0u16.to_le_bytes());
+    let elf64 = Elf64::from_bytes(&bytes).unwrap();
+    let shdr1 = elf64.shdr_nth(1).unwrap();
+    assert_eq!(elf64.section_name(&shdr1), Err(Error::NotFound));
+    assert_eq!(elf64.section_by_name(".interp"), None);
+}
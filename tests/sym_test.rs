@@ -0,0 +1,107 @@
+extern crate elf_parser;
+use elf_parser::{
+    types::{
+        SHN_ABS, SHN_COMMON, SHN_UNDEF, STB_GLOBAL, STB_LOCAL, STB_WEAK, STT_FILE, STT_FUNC,
+        STT_NOTYPE, STT_OBJECT, STV_DEFAULT, STV_HIDDEN,
+    },
+    Elf, Elf32, Elf64, ElfFile, Error, Symbol,
+};
+
+#[test]
+fn elf64_symtab() {
+    let bytes = include_bytes!("./data/elf64");
+    let elf64 = Elf64::from_bytes(bytes).unwrap();
+    let symtab = elf64.symtab().unwrap();
+    assert_eq!(symtab.len(), 65);
+
+    let sym0 = symtab.get(0).unwrap();
+    assert_eq!(symtab.name(&sym0), Ok(""));
+    assert!(sym0.section_index() == SHN_UNDEF);
+
+    let sym1 = symtab.get(1).unwrap();
+    assert_eq!(symtab.name(&sym1), Ok("crt1.o"));
+    assert!(sym1.sym_type() == STT_FILE);
+    assert!(sym1.section_index() == SHN_ABS);
+
+    let main = symtab.get(62).unwrap();
+    assert_eq!(symtab.name(&main), Ok("main"));
+    assert_eq!(main.st_value, 0x401106);
+    assert_eq!(main.st_size, 18);
+    assert!(main.bind() == STB_GLOBAL);
+    assert!(main.sym_type() == STT_FUNC);
+    assert!(main.visibility() == STV_DEFAULT);
+    assert_eq!(main.st_shndx, 12);
+
+    let (completed, _) = symtab
+        .iter()
+        .map(Result::unwrap)
+        .find(|(_, name)| *name == "completed.0")
+        .unwrap();
+    assert!(completed.bind() == STB_LOCAL);
+    assert!(completed.sym_type() == STT_OBJECT);
+    assert_eq!(completed.st_value, 0x40401c);
+    assert_eq!(symtab.get(65), Err(Error::InvalidIndex));
+}
+
+#[test]
+fn elf64_dynsym() {
+    let bytes = include_bytes!("./data/elf64");
+    let elf64 = Elf64::from_bytes(bytes).unwrap();
+    let dynsym = elf64.dynsym().unwrap();
+    let names = dynsym.iter().map(|sym| sym.unwrap().1).collect::<Vec<_>>();
+    assert_eq!(names, ["", "__libc_start_main", "__gmon_start__"]);
+    let gmon = dynsym.get(2).unwrap();
+    assert!(gmon.bind() == STB_WEAK);
+    assert!(gmon.sym_type() == STT_NOTYPE);
+}
+
+#[test]
+fn elf32_symtab() {
+    let bytes = include_bytes!("./data/elf32");
+    let elf32 = Elf32::from_bytes(bytes).unwrap();
+    let symtab = elf32.symtab().unwrap();
+    assert_eq!(symtab.len(), 66);
+    let main = symtab.get(61).unwrap();
+    assert_eq!(symtab.name(&main), Ok("main"));
+    assert_eq!(main.st_value, 0x4ed);
+    assert_eq!(main.st_size, 30);
+
+    let dynsym = elf32.dynsym().unwrap();
+    let stdin_used = dynsym.get(6).unwrap();
+    assert_eq!(dynsym.name(&stdin_used), Ok("_IO_stdin_used"));
+    assert!(stdin_used.sym_type() == STT_OBJECT);
+    assert_eq!(stdin_used.st_shndx, 16);
+}
+
+#[test]
+fn be_symtab() {
+    let le = Elf64::from_bytes(include_bytes!("./data/elf64")).unwrap();
+    let be = Elf64::from_bytes(include_bytes!("./data/elf64_be")).unwrap();
+    let le_symtab = le.symtab().unwrap();
+    let be_symtab = be.symtab().unwrap();
+    assert!(le_symtab.iter().eq(be_symtab.iter()));
+
+    let le = Elf32::from_bytes(include_bytes!("./data/elf32")).unwrap();
+    let be = Elf32::from_bytes(include_bytes!("./data/elf32_be")).unwrap();
+    assert!(le.dynsym().unwrap().iter().eq(be.dynsym().unwrap().iter()));
+}
+
+#[test]
+fn rel_symtab() {
+    let elf = Elf::from_bytes(include_bytes!("./data/rel64")).unwrap();
+    let symtab = elf.symtab().unwrap();
+    let common_var = symtab.get(9).unwrap();
+    assert_eq!(symtab.name(&common_var), Ok("common_var"));
+    assert!(common_var.section_index() == SHN_COMMON);
+    let hidden_func = symtab.get(4).unwrap();
+    assert!(hidden_func.visibility() == STV_HIDDEN);
+    assert_eq!(hidden_func.st_size, 16);
+
+    let elf = Elf::from_bytes(include_bytes!("./data/rel32")).unwrap();
+    let symtab = elf.symtab().unwrap();
+    let call_external = symtab.get(5).unwrap();
+    assert_eq!(symtab.name(&call_external), Ok("call_external"));
+    assert_eq!(call_external.st_size, 45);
+    assert!(symtab.get(9).unwrap().section_index() == SHN_COMMON);
+    assert_eq!(elf.dynsym().err(), Some(Error::NotFound));
+}
@@ -0,0 +1,130 @@
+extern crate elf_parser;
+use elf_parser::{
+    types::{ELFCLASS64, ELFDATA2LSB},
+    Elf32, Elf64, Elf64Sym, ElfFile, Error, StrTab, SymbolRange, SymbolTable, Symbolizer, Table,
+};
+
+#[test]
+fn elf64_symbolize() {
+    let bytes = include_bytes!("./data/elf64");
+    let elf64 = Elf64::from_bytes(bytes).unwrap();
+    let symtab = elf64.symtab().unwrap();
+    let mut buf = vec![SymbolRange::default(); Symbolizer::required_len(&symtab)];
+    let symbolizer = Symbolizer::new(&symtab, &mut buf).unwrap();
+
+    assert_eq!(symbolizer.symbolize(0x401106), Some(("main", 0x0)));
+    assert_eq!(symbolizer.symbolize(0x401110), Some(("main", 0xa)));
+    // _start overlaps a crowd of zero-sized labels at the same address.
+    assert_eq!(symbolizer.symbolize(0x401025), Some(("_start", 0x5)));
+    assert_eq!(
+        symbolizer.symbolize(0x401052),
+        Some(("_dl_relocate_static_pie", 0x2))
+    );
+    // Zero-sized functions cover the addresses up to the next symbol.
+    assert_eq!(
+        symbolizer.symbolize(0x401070),
+        Some(("deregister_tm_clones", 0x10))
+    );
+    assert_eq!(
+        symbolizer.symbolize(0x4010e0),
+        Some(("__do_global_dtors_aux", 0x10))
+    );
+    assert_eq!(symbolizer.symbolize(0x40401c), Some(("completed.0", 0x0)));
+    assert_eq!(symbolizer.symbolize(0x3fffff), None);
+}
+
+#[test]
+fn elf32_symbolize() {
+    let bytes = include_bytes!("./data/elf32");
+    let elf32 = Elf32::from_bytes(bytes).unwrap();
+    let symtab = elf32.symtab().unwrap();
+    let mut buf = [SymbolRange::default(); 64];
+    let symbolizer = Symbolizer::new(&symtab, &mut buf).unwrap();
+    assert_eq!(symbolizer.symbolize(0x4ed + 0x1d), Some(("main", 0x1d)));
+    assert_eq!(symbolizer.symbolize(0x3b0), Some(("_start", 0x0)));
+}
+
+#[test]
+fn buffer_too_small() {
+    let bytes = include_bytes!("./data/elf64");
+    let elf64 = Elf64::from_bytes(bytes).unwrap();
+    let symtab = elf64.symtab().unwrap();
+    let mut buf = [SymbolRange::default(); 4];
+    assert_eq!(
+        Symbolizer::new(&symtab, &mut buf).err(),
+        Some(Error::BufferTooSmall)
+    );
+}
+
+fn push_sym(bytes: &mut Vec<u8>, name: u32, info: u8, value: u64, size: u64) {
+    bytes.extend_from_slice(&name.to_le_bytes());
+    bytes.push(info);
+    bytes.push(0);
+    bytes.extend_from_slice(&1u16.to_le_bytes());
+    bytes.extend_from_slice(&value.to_le_bytes());
+    bytes.extend_from_slice(&size.to_le_bytes());
+}
+
+#[test]
+fn overlapping_symbols() {
+    let strtab = b"\0outer\0inner\0label\0alias\0after\0";
+    let (outer, inner, label, alias, after) = (1, 7, 13, 19, 25);
+    let global_func = 0x12;
+    let local_func = 0x02;
+    let global_notype = 0x10;
+
+    let mut syms = vec![0; 24];
+    push_sym(&mut syms, outer, global_func, 0x1000, 0x100);
+    push_sym(&mut syms, inner, local_func, 0x1040, 0x10);
+    push_sym(&mut syms, alias, global_func, 0x1040, 0x10);
+    push_sym(&mut syms, label, global_notype, 0x1080, 0);
+    push_sym(&mut syms, after, global_notype, 0x2000, 0);
+
+    let table = Table::<Elf64Sym>::new(&syms, 24, ELFCLASS64, ELFDATA2LSB).unwrap();
+    let symtab = SymbolTable::new(table, StrTab::new(strtab));
+    let mut buf = [SymbolRange::default(); 8];
+    let symbolizer = Symbolizer::new(&symtab, &mut buf).unwrap();
+    assert_eq!(symbolizer.len(), 5);
+
+    // The innermost symbol wins, and a global alias is preferred over a local one.
+    assert_eq!(symbolizer.symbolize(0x1044), Some(("alias", 0x4)));
+    // The outer function still covers addresses past the nested one, even past a label.
+    assert_eq!(symbolizer.symbolize(0x1050), Some(("outer", 0x50)));
+    assert_eq!(symbolizer.symbolize(0x1090), Some(("outer", 0x90)));
+    // Once the outer function ends, the zero-sized label covers up to the next symbol.
+    assert_eq!(symbolizer.symbolize(0x1100), Some(("label", 0x80)));
+    assert_eq!(symbolizer.symbolize(0xfff), None);
+
+    // Nothing bounds the last label, so it names only its own address.
+    assert_eq!(symbolizer.symbolize(0x2000), Some(("after", 0)));
+    assert_eq!(symbolizer.symbolize(0x2010), None);
+}
+
+#[test]
+fn end() {
+    let strtab = b"\0func\0label\0last\0";
+    let (func, label, last) = (1, 6, 12);
+    let mut syms = vec![0; 24];
+    push_sym(&mut syms, func, 0x12, 0x1000, 0x10);
+    push_sym(&mut syms, label, 0x10, 0x1010, 0);
+    push_sym(&mut syms, last, 0x10, 0x2000, 0);
+
+    let table = Table::<Elf64Sym>::new(&syms, 24, ELFCLASS64, ELFDATA2LSB).unwrap();
+    let symtab = SymbolTable::new(table, StrTab::new(strtab));
+    let mut buf = [SymbolRange::default(); 3];
+    let mut symbolizer = Symbolizer::new(&symtab, &mut buf).unwrap();
+    symbolizer.set_end(0x1800);
+
+    // The end of .text bounds a label even when the next symbol is further away.
+    assert_eq!(symbolizer.symbolize(0x17ff), Some(("label", 0x7ef)));
+    assert_eq!(symbolizer.symbolize(0x1800), None);
+    assert_eq!(symbolizer.symbolize(0x2000), None);
+
+    let mut symbolizer = Symbolizer::new(&symtab, &mut buf).unwrap();
+    symbolizer.set_end(0x2100);
+    assert_eq!(symbolizer.symbolize(0x1900), Some(("label", 0x8f0)));
+    assert_eq!(symbolizer.symbolize(0x20ff), Some(("last", 0xff)));
+    assert_eq!(symbolizer.symbolize(0x2100), None);
+    // Sized symbols are not affected.
+    assert_eq!(symbolizer.symbolize(0x1008), Some(("func", 0x8)));
+}
@@ -0,0 +1,146 @@
+extern crate elf_parser;
+use elf_parser::{
+    types::{
+        ELFCLASS32, ELFCLASS64, ELFDATA2LSB, ELFDATA2MSB, EM_AARCH64, EM_ARM, EM_RISCV, EM_X86_64,
+    },
+    Elf32, Elf64, ElfFile, Error, TlsLayout, TlsTemplate, TlsVariant,
+};
+
+#[test]
+fn x86_64() {
+    let bytes = include_bytes!("./data/tls64");
+    let elf64 = Elf64::from_bytes(bytes).unwrap();
+    let template = elf64.tls_template().unwrap();
+    assert_eq!(template.image(), &bytes[0x2fc0..0x2fd8]);
+    assert_eq!(template.size(), 0x84);
+    assert_eq!(template.align(), 0x40);
+
+    let layout = template.layout(0x20).unwrap();
+    assert_eq!(
+        layout,
+        TlsLayout {
+            variant: TlsVariant::II,
+            size: 0xe0,
+            align: 0x40,
+            tp_offset: 0xc0,
+            tls_offset: 0,
+            tcb_offset: 0xc0,
+        }
+    );
+
+    let mut block = vec![0xff; 0x100];
+    let addr = 0x7fff_f7d8_0000;
+    let tp = template.initialize(&layout, &mut block, addr).unwrap();
+    assert_eq!(tp, addr + 0xc0);
+    // The code reads aligned_var at %fs:-0xc0, name at -0xbc and counter at -0xb0, and buf
+    // in .tbss at -0xa0.
+    let at = |offset: u64| (tp - offset - addr) as usize;
+    assert_eq!(block[at(0xc0)..at(0xc0) + 4], 7u32.to_le_bytes());
+    assert_eq!(&block[at(0xbc)..at(0xbc) + 5], b"tls!\0");
+    assert_eq!(
+        block[at(0xb0)..at(0xb0) + 8],
+        0x1122_3344_5566_7788u64.to_le_bytes()
+    );
+    assert!(block[at(0xa0)..at(0xa0) + 100].iter().all(|&b| b == 0));
+    // %fs:0 holds the thread pointer, and the rest is left alone.
+    assert_eq!(block[0xc0..0xc8], tp.to_le_bytes());
+    assert!(block[0xc8..].iter().all(|&b| b == 0xff));
+}
+
+#[test]
+fn i386() {
+    let elf32 = Elf32::from_bytes(include_bytes!("./data/tls32")).unwrap();
+    let template = elf32.tls_template().unwrap();
+    assert_eq!((template.size(), template.align()), (0x7c, 0x40));
+
+    // The TCB is at least the word that points to itself.
+    let layout = template.layout(0).unwrap();
+    assert_eq!((layout.tp_offset, layout.tls_offset), (0x80, 0));
+    assert_eq!(layout.size, 0x84);
+
+    let mut block = vec![0xff; 0x84];
+    let tp = template
+        .initialize(&layout, &mut block, 0xf7f0_0000)
+        .unwrap();
+    assert_eq!(tp, 0xf7f0_0080);
+    // aligned_var is at %gs:-0x80 and counter at -0x70.
+    assert_eq!(block[..4], 7u32.to_le_bytes());
+    assert_eq!(block[0x10..0x14], 0x5566_7788u32.to_le_bytes());
+    assert_eq!(block[0x80..], 0xf7f0_0080u32.to_le_bytes());
+}
+
+#[test]
+fn variant_i() {
+    let image = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    // AArch64 keeps 16 bytes at the thread pointer before the TLS block.
+    let template =
+        TlsTemplate::new(&image, 0x84, 0x40, EM_AARCH64, ELFCLASS64, ELFDATA2LSB).unwrap();
+    let layout = template.layout(0x30).unwrap();
+    assert_eq!(
+        layout,
+        TlsLayout {
+            variant: TlsVariant::I,
+            size: 0x104,
+            align: 0x40,
+            tp_offset: 0x40,
+            tls_offset: 0x80,
+            tcb_offset: 0x10,
+        }
+    );
+    let mut block = vec![0xff; 0x104];
+    let tp = template.initialize(&layout, &mut block, 0x1000).unwrap();
+    assert_eq!(tp, 0x1040);
+    assert!(block[..0x40].iter().all(|&b| b == 0xff));
+    assert!(block[0x40..0x80].iter().all(|&b| b == 0));
+    assert_eq!(block[0x80..0x88], image);
+    assert!(block[0x88..].iter().all(|&b| b == 0));
+
+    // RISC-V starts the TLS block at the thread pointer.
+    let template = TlsTemplate::new(&image, 0x10, 8, EM_RISCV, ELFCLASS32, ELFDATA2MSB).unwrap();
+    let layout = template.layout(0).unwrap();
+    assert_eq!(
+        (
+            layout.tp_offset,
+            layout.tls_offset,
+            layout.size,
+            layout.align
+        ),
+        (0, 0, 0x10, 8)
+    );
+    let mut block = [0xff; 0x10];
+    assert_eq!(template.initialize(&layout, &mut block, 0x2000), Ok(0x2000));
+    assert_eq!(block[..8], image);
+    assert_eq!(block[8..], [0; 8]);
+}
+
+#[test]
+fn errors() {
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/elf64")).unwrap();
+    assert_eq!(elf64.tls_template().err(), Some(Error::NotFound));
+
+    let image = [0; 4];
+    assert_eq!(
+        TlsTemplate::new(&image, 8, 3, EM_X86_64, ELFCLASS64, ELFDATA2LSB).err(),
+        Some(Error::InvalidAlignment)
+    );
+    assert_eq!(
+        TlsTemplate::new(&image, 2, 4, EM_X86_64, ELFCLASS64, ELFDATA2LSB).err(),
+        Some(Error::Corrupted)
+    );
+    let template = TlsTemplate::new(&image, 8, 0, EM_ARM, ELFCLASS32, ELFDATA2LSB).unwrap();
+    assert_eq!(template.align(), 1);
+    assert_eq!(template.layout(0).err(), Some(Error::UnsupportedMachine));
+
+    let template = TlsTemplate::new(&image, 8, 4, EM_X86_64, ELFCLASS64, ELFDATA2LSB).unwrap();
+    let layout = template.layout(0).unwrap();
+    let mut block = [0; 0x20];
+    assert_eq!(
+        template.initialize(&layout, &mut block[..8], 0x1000),
+        Err(Error::BufferTooSmall)
+    );
+    assert_eq!(
+        template.initialize(&layout, &mut block, 0x1008),
+        Err(Error::InvalidAlignment)
+    );
+}
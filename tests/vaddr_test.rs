@@ -0,0 +1,69 @@
+extern crate elf_parser;
+use elf_parser::{Elf, Elf32, Elf64, ElfFile, Error};
+
+fn check_elf64<'a, F: ElfFile<'a>>(file: &F) {
+    assert_eq!(file.vaddr_to_offset(file.entry_point()), Some(0x1020));
+    assert_eq!(file.vaddr_to_offset(0x400000), Some(0));
+    assert_eq!(file.vaddr_to_offset(0x404018), Some(0x3018));
+    // .bss and unmapped addresses have no file offset.
+    assert_eq!(file.vaddr_to_offset(0x40401c), None);
+    assert_eq!(file.vaddr_to_offset(0x500000), None);
+
+    assert_eq!(file.offset_to_vaddr(0x1020), Some(0x401020));
+    assert_eq!(file.offset_to_vaddr(0x3018), Some(0x404018));
+    // Between the third and fourth PT_LOAD.
+    assert_eq!(file.offset_to_vaddr(0x2800), None);
+
+    let text = file.read_at_vaddr(0x401020, 4).unwrap();
+    assert_eq!(text, &file.bytes()[0x1020..0x1024]);
+    assert_eq!(file.read_at_vaddr(0x404018, 4), Ok(&[0u8; 4][..]));
+    assert_eq!(file.read_at_vaddr(0x40401c, 4), Err(Error::NotFileBacked));
+    assert_eq!(file.read_at_vaddr(0x404018, 8), Err(Error::NotFileBacked));
+    assert_eq!(file.read_at_vaddr(0x40401c, 8), Err(Error::NotFound));
+    assert_eq!(file.read_at_vaddr(0x500000, 1), Err(Error::NotFound));
+}
+
+#[test]
+fn elf64() {
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/elf64")).unwrap();
+    check_elf64(&elf64);
+}
+
+#[test]
+fn elf64_be() {
+    let elf = Elf::from_bytes(include_bytes!("./data/elf64_be")).unwrap();
+    check_elf64(&elf);
+}
+
+#[test]
+fn elf32() {
+    let elf32 = Elf32::from_bytes(include_bytes!("./data/elf32")).unwrap();
+    assert_eq!(elf32.vaddr_to_offset(0x3b0), Some(0x3b0));
+    assert_eq!(elf32.vaddr_to_offset(0x2000), Some(0x1000));
+    assert_eq!(elf32.offset_to_vaddr(0x1000), Some(0x2000));
+    assert_eq!(elf32.offset_to_vaddr(0x800), None);
+
+    assert_eq!(
+        elf32.read_at_vaddr(0x2000, 8),
+        Ok(&[0x00, 0x00, 0x00, 0x00, 0x04, 0x20, 0x00, 0x00][..])
+    );
+    assert_eq!(elf32.read_at_vaddr(0x2008, 4), Err(Error::NotFileBacked));
+    assert_eq!(elf32.read_at_vaddr(0x2000, 0xc), Err(Error::NotFileBacked));
+    assert_eq!(elf32.read_at_vaddr(0x2000, 0xd), Err(Error::NotFound));
+}
+
+#[test]
+fn corrupted() {
+    // The fourth PT_LOAD, which maps 0x403e50 from 0x2e50, is the sixth program header.
+    let phdr = 0x40 + 56 * 5;
+    let mut bytes = include_bytes!("./data/elf64").to_vec();
+    bytes[phdr + 8..phdr + 16].copy_from_slice(&(u64::MAX - 0x10).to_le_bytes());
+    let elf64 = Elf64::from_bytes(&bytes).unwrap();
+    assert_eq!(elf64.vaddr_to_offset(0x404018), None);
+    assert_eq!(elf64.read_at_vaddr(0x404018, 4), Err(Error::Corrupted));
+
+    let mut bytes = include_bytes!("./data/elf64").to_vec();
+    bytes[phdr + 16..phdr + 24].copy_from_slice(&(u64::MAX - 0x10).to_le_bytes());
+    let elf64 = Elf64::from_bytes(&bytes).unwrap();
+    assert_eq!(elf64.offset_to_vaddr(0x3018), None);
+}
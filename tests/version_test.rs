@@ -0,0 +1,125 @@
+extern crate elf_parser;
+use core::cmp::Ordering;
+use elf_parser::{
+    compare_versions,
+    types::{ShType, VerFlags, SHT_GNU_VERSYM, VER_FLG_BASE},
+    Elf, Elf64, ElfFile, Error, SymbolVersion, Versym,
+};
+
+fn check_versions<'a, F: ElfFile<'a>>(file: &F) {
+    let versions = file.symbol_versions().unwrap();
+    let dynsym = file.dynsym().unwrap();
+    let version_of = |name: &str| {
+        let index = dynsym
+            .iter()
+            .position(|entry| entry.unwrap().1 == name)
+            .unwrap();
+        versions.get(index)
+    };
+
+    // Imports name the library that provides the version.
+    assert_eq!(
+        version_of("memcpy"),
+        Ok(SymbolVersion {
+            name: "GLIBC_2.14",
+            hidden: false,
+            file: Some("libc.so.6"),
+        })
+    );
+    assert_eq!(version_of("exp").unwrap().file, Some("libm.so.6"));
+    assert_eq!(version_of("exp").unwrap().name, "GLIBC_2.29");
+    assert_eq!(version_of("puts").unwrap().name, "GLIBC_2.2.5");
+    assert_eq!(
+        version_of("_ITM_deregisterTMCloneTable"),
+        Err(Error::NotFound)
+    );
+    assert_eq!(versions.get(0), Err(Error::NotFound));
+    assert_eq!(versions.get(16), Err(Error::InvalidIndex));
+
+    // api@VERS_1.0 is hidden behind the default api@@VERS_2.0.
+    assert_eq!(versions.versym(9), Ok(Versym(0x8002)));
+    assert_eq!(
+        versions.get(9),
+        Ok(SymbolVersion {
+            name: "VERS_1.0",
+            hidden: true,
+            file: None,
+        })
+    );
+    assert_eq!(
+        versions.get(11),
+        Ok(SymbolVersion {
+            name: "VERS_2.0",
+            hidden: false,
+            file: None,
+        })
+    );
+    assert_eq!(version_of("helper").unwrap().name, "VERS_1.0");
+
+    let definitions: Vec<_> = versions.definitions().collect();
+    let names: Vec<_> = definitions.iter().map(|verdef| verdef.name).collect();
+    assert_eq!(names, ["ver64", "VERS_1.0", "VERS_2.0"]);
+    assert!(definitions[0].flags.contains(VER_FLG_BASE));
+    assert_eq!(definitions[2].index, 3);
+    assert_eq!(definitions[2].parents().collect::<Vec<_>>(), ["VERS_1.0"]);
+    assert_eq!(definitions[1].parents().count(), 0);
+
+    let requirements: Vec<_> = versions.requirements().collect();
+    assert_eq!(requirements.len(), 2);
+    assert_eq!(requirements[0].file, "libm.so.6");
+    let libm: Vec<_> = requirements[0]
+        .versions()
+        .map(|aux| (aux.name, aux.index))
+        .collect();
+    assert_eq!(libm, [("GLIBC_2.29", 7), ("GLIBC_2.2.5", 6)]);
+    assert_eq!(
+        requirements[1].versions().next().unwrap().flags,
+        VerFlags(0)
+    );
+
+    let highest: Vec<_> = versions.highest_required().collect();
+    assert_eq!(
+        highest,
+        [("libm.so.6", "GLIBC_2.29"), ("libc.so.6", "GLIBC_2.14")]
+    );
+}
+
+#[test]
+fn ver64() {
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/ver64")).unwrap();
+    check_versions(&elf64);
+    let shdr = elf64.section_by_name(".gnu.version").unwrap();
+    assert_eq!(format!("{:?}", ShType(shdr.sh_type)), "SHT_GNU_VERSYM");
+    assert_eq!(ShType(shdr.sh_type), SHT_GNU_VERSYM);
+}
+
+#[test]
+fn ver64_be() {
+    let elf = Elf::from_bytes(include_bytes!("./data/ver64_be")).unwrap();
+    check_versions(&elf);
+}
+
+#[test]
+fn unversioned() {
+    let elf64 = Elf64::from_bytes(include_bytes!("./data/rel64")).unwrap();
+    assert_eq!(elf64.symbol_versions().err(), Some(Error::NotFound));
+}
+
+#[test]
+fn compare() {
+    assert_eq!(
+        compare_versions("GLIBC_2.14", "GLIBC_2.2.5"),
+        Some(Ordering::Greater)
+    );
+    assert_eq!(
+        compare_versions("GLIBC_2.2", "GLIBC_2.2.5"),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        compare_versions("GLIBC_2.2.5", "GLIBC_2.2.5"),
+        Some(Ordering::Equal)
+    );
+    assert_eq!(compare_versions("GLIBC_PRIVATE", "GLIBC_2.2.5"), None);
+    assert_eq!(compare_versions("GLIBC_2.", "GLIBC_2.2.5"), None);
+    assert_eq!(format!("{:?}", VerFlags(3)), "VER_FLG_BASE VER_FLG_WEAK ");
+}